@@ -1,16 +1,21 @@
 use std::fmt;
 
-use super::Jpeg;
+use super::{Gif, Heif, Jpeg, Png, Tiff, WebP};
 use crate::{Exif, MetaResult};
 
 #[derive(Debug)]
 pub enum Container {
     Jpeg(Jpeg),
+    Tiff(Tiff),
+    Png(Png),
+    WebP(WebP),
+    Gif(Gif),
+    Heif(Heif),
     None,
 }
 
 impl Container {
-    /// Get the Exif meta data if it exists from the JPEG source and cache it
+    /// Get the Exif meta data if it exists from the container source
     pub(crate) fn parse_exif(&self) -> Option<MetaResult<Exif>> {
         match self {
             Container::Jpeg(jpeg) => match jpeg.exif() {
@@ -20,6 +25,66 @@ impl Container {
                 },
                 _ => None,
             },
+            Container::Tiff(tiff) => Some(tiff.exif().map_err(|e| e.into())),
+            Container::Png(png) => match png.exif() {
+                Some(exif) => match exif {
+                    Ok(exif) => Some(Ok(exif)),
+                    Err(e) => Some(Err(e.into())),
+                },
+                _ => None,
+            },
+            Container::WebP(webp) => match webp.exif() {
+                Some(exif) => match exif {
+                    Ok(exif) => Some(Ok(exif)),
+                    Err(e) => Some(Err(e.into())),
+                },
+                _ => None,
+            },
+            Container::Heif(heif) => match heif.exif() {
+                Some(exif) => match exif {
+                    Ok(exif) => Some(Ok(exif)),
+                    Err(e) => Some(Err(e.into())),
+                },
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Get the raw ICC profile if the container embeds one
+    pub(crate) fn icc_profile(&self) -> Option<MetaResult<Vec<u8>>> {
+        match self {
+            Container::Jpeg(jpeg) => jpeg.icc_profile().map(|x| x.map_err(|e| e.into())),
+            Container::Png(png) => png.icc_profile().map(|x| x.map_err(|e| e.into())),
+            Container::Tiff(tiff) => tiff.icc_profile().map(|x| Ok(x.to_vec())),
+            Container::WebP(webp) => webp.icc_profile().map(|x| Ok(x.to_vec())),
+            Container::Heif(heif) => heif.icc_profile().map(|x| Ok(x.to_vec())),
+            _ => None,
+        }
+    }
+
+    /// Get the raw XMP packet if the container stores one directly
+    pub(crate) fn xmp_packet(&self) -> Option<&[u8]> {
+        match self {
+            Container::Jpeg(jpeg) => jpeg.xmp(),
+            Container::WebP(webp) => webp.xmp(),
+            Container::Gif(gif) => gif.xmp(),
+            Container::Heif(heif) => heif.xmp(),
+            _ => None,
+        }
+    }
+
+    /// Get the image dimensions i.e. (width, height) if the container records them directly
+    pub(crate) fn dimensions(&self) -> Option<(u32, u32)> {
+        match self {
+            Container::Jpeg(jpeg) => jpeg
+                .frame
+                .as_ref()
+                .map(|x| (x.width as u32, x.height as u32)),
+            Container::Png(png) => Some((png.header.width, png.header.height)),
+            Container::WebP(webp) => Some((webp.width, webp.height)),
+            Container::Gif(gif) => Some((gif.width as u32, gif.height as u32)),
+            Container::Heif(heif) => heif.dimensions,
             _ => None,
         }
     }
@@ -35,6 +100,11 @@ impl fmt::Display for Container {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Container::Jpeg(_) => write!(f, "Jpeg"),
+            Container::Tiff(_) => write!(f, "Tiff"),
+            Container::Png(_) => write!(f, "Png"),
+            Container::WebP(_) => write!(f, "WebP"),
+            Container::Gif(_) => write!(f, "Gif"),
+            Container::Heif(_) => write!(f, "Heif"),
             Container::None => write!(f, "None"),
         }
     }
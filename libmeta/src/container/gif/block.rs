@@ -0,0 +1,98 @@
+use std::io;
+
+use crate::{errors::GifError, slice};
+
+pub(crate) const GIF87A: [u8; 6] = *b"GIF87a";
+pub(crate) const GIF89A: [u8; 6] = *b"GIF89a";
+
+pub(crate) const EXTENSION: u8 = 0x21; // Extension introducer
+pub(crate) const IMAGE: u8 = 0x2C; // Image descriptor
+pub(crate) const TRAILER: u8 = 0x3B; // End of the GIF data stream
+
+pub(crate) const GRAPHIC_CONTROL: u8 = 0xF9; // Graphic Control Extension i.e. frame delay
+pub(crate) const COMMENT: u8 = 0xFE; // Comment Extension
+pub(crate) const APPLICATION: u8 = 0xFF; // Application Extension e.g. NETSCAPE2.0 or XMP
+
+pub(crate) const NETSCAPE: &[u8] = b"NETSCAPE2.0";
+pub(crate) const XMP: &[u8] = b"XMP DataXMP";
+
+/// Color table flag in the packed field of the screen and image descriptors
+pub(crate) const COLOR_TABLE_FLAG: u8 = 0x80;
+
+/// Size in bytes of the color table described by the given packed field
+pub(crate) fn color_table_len(packed: u8) -> usize {
+    match packed & COLOR_TABLE_FLAG {
+        0 => 0,
+        _ => 3 * (1 << ((packed & 0x07) + 1)),
+    }
+}
+
+/// Read out all the data sub-blocks up to and including the block terminator
+/// * **raw** keeps the sub-block length bytes e.g. for XMP which is written without sub-blocks
+pub(crate) fn read_sub_blocks(reader: &mut impl io::Read, raw: bool) -> Result<Vec<u8>, GifError> {
+    let mut data = Vec::new();
+    loop {
+        let len = slice::read_u8(reader)
+            .map_err(|e| GifError::read_failed(": sub-block length").with_io_source(e))?;
+        if len == 0 {
+            break;
+        }
+        if raw {
+            data.push(len);
+        }
+        let block = slice::read_bytes(reader, len as usize)
+            .map_err(|e| GifError::read_failed(": sub-block data").with_io_source(e))?;
+        data.extend(block);
+    }
+    Ok(data)
+}
+
+/// Skip over all the data sub-blocks up to and including the block terminator without buffering
+pub(crate) fn skip_sub_blocks(reader: &mut impl io::Read) -> Result<(), GifError> {
+    loop {
+        let len = slice::read_u8(reader)
+            .map_err(|e| GifError::read_failed(": sub-block length").with_io_source(e))?;
+        if len == 0 {
+            return Ok(());
+        }
+        slice::skip_bytes(reader, len as u64)
+            .map_err(|e| GifError::read_failed(": sub-block data").with_io_source(e))?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_table_len() {
+        assert_eq!(color_table_len(0x00), 0);
+        assert_eq!(color_table_len(0x80), 6);
+        assert_eq!(color_table_len(0x87), 768);
+    }
+
+    #[test]
+    fn test_read_sub_blocks() {
+        let data = [0x02, 0x41, 0x42, 0x01, 0x43, 0x00, 0xFF];
+        assert_eq!(read_sub_blocks(&mut &data[..], false).unwrap(), b"ABC");
+        assert_eq!(read_sub_blocks(&mut &data[..], true).unwrap(), b"\x02AB\x01C");
+        assert_eq!(
+            read_sub_blocks(&mut &data[..2], false)
+                .unwrap_err()
+                .to_string(),
+            "GIF read failed: sub-block data"
+        );
+    }
+
+    #[test]
+    fn test_skip_sub_blocks() {
+        let data = [0x02, 0x41, 0x42, 0x01, 0x43, 0x00, 0xFF];
+        let mut reader = &data[..];
+        skip_sub_blocks(&mut reader).unwrap();
+        assert_eq!(reader, &[0xFF]);
+        assert_eq!(
+            skip_sub_blocks(&mut &data[..2]).unwrap_err().to_string(),
+            "GIF read failed: sub-block data"
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use std::{
+    fmt::Display,
+    io::{self, prelude::*},
+    time::Duration,
+};
+
+use super::block;
+use crate::{container::Text, errors::GifError, slice};
+
+/// Simplify the Gif return type slightly
+pub type GifResult<T> = Result<T, GifError>;
+
+#[derive(Debug)]
+pub struct Gif {
+    pub(crate) version: String,
+    pub(crate) width: u16,
+    pub(crate) height: u16,
+    pub(crate) frames: usize,
+    pub(crate) delay: u64, // total frame delay in hundredths of a second
+    pub(crate) loop_count: Option<u16>,
+    pub(crate) comments: Vec<Text>,
+    pub(crate) xmp: Option<Vec<u8>>,
+}
+
+impl Gif {
+    /// Parse all meta data from the given GIF source.
+    pub fn parse<T: io::BufRead>(mut reader: T) -> GifResult<Self> {
+        // Check the header to determine the media type
+        let mut header = Vec::new();
+        reader
+            .by_ref()
+            .take(6)
+            .read_to_end(&mut header)
+            .map_err(|x| GifError::read_failed(": invalid header").with_io_source(x))?;
+        if !Self::is_gif(&header) {
+            return Err(GifError::parse(": invalid header").with_data(&header));
+        }
+
+        // Logical screen descriptor
+        // * (2 bytes) Width in Little Endian
+        // * (2 bytes) Height in Little Endian
+        // * (1 byte)  Packed global color table flag, color resolution, sort flag and table size
+        // * (1 byte)  Background color index
+        // * (1 byte)  Pixel aspect ratio
+        let lsd = slice::read_bytes(&mut reader, 7)
+            .map_err(|e| GifError::read_failed(": logical screen descriptor").with_io_source(e))?;
+        let mut gif = Gif {
+            version: String::from_utf8_lossy(&header[3..6]).into_owned(),
+            width: u16::from_le_bytes([lsd[0], lsd[1]]),
+            height: u16::from_le_bytes([lsd[2], lsd[3]]),
+            frames: 0,
+            delay: 0,
+            loop_count: None,
+            comments: Vec::new(),
+            xmp: None,
+        };
+        slice::skip_bytes(&mut reader, block::color_table_len(lsd[4]) as u64)
+            .map_err(|e| GifError::read_failed(": global color table").with_io_source(e))?;
+
+        gif.parse_blocks(&mut reader)?;
+        Ok(gif)
+    }
+
+    // Determine if the given header is from a gif source
+    pub(crate) fn is_gif(header: &[u8]) -> bool {
+        header.starts_with(&block::GIF87A) || header.starts_with(&block::GIF89A)
+    }
+
+    /// GIF version i.e. `87a` or `89a`
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Logical screen width in pixels
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Logical screen height in pixels
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Number of image frames
+    pub fn frame_count(&self) -> usize {
+        self.frames
+    }
+
+    /// Total animation duration from the Graphic Control Extension frame delays
+    pub fn duration(&self) -> Duration {
+        Duration::from_millis(self.delay * 10)
+    }
+
+    /// Number of times the animation repeats, 0 means forever, from the NETSCAPE2.0 extension
+    pub fn loop_count(&self) -> Option<u16> {
+        self.loop_count
+    }
+
+    /// Get the Comment Extensions as text with the `Comment` keyword in file order
+    pub fn text(&self) -> &[Text] {
+        &self.comments
+    }
+
+    /// Get the raw XMP packet from the `XMP DataXMP` Application Extension if it exists
+    pub fn xmp(&self) -> Option<&[u8]> {
+        self.xmp.as_deref()
+    }
+
+    /// Walk the blocks up to the trailer counting frames and collecting extension data
+    fn parse_blocks(&mut self, reader: &mut impl io::BufRead) -> GifResult<()> {
+        loop {
+            let kind = slice::read_u8(reader)
+                .map_err(|e| GifError::read_failed(": block type").with_io_source(e))?;
+            match kind {
+                block::IMAGE => {
+                    // Image descriptor: left, top, width, height and packed local color table
+                    let desc = slice::read_bytes(reader, 9).map_err(|e| {
+                        GifError::read_failed(": image descriptor").with_io_source(e)
+                    })?;
+                    slice::skip_bytes(reader, block::color_table_len(desc[8]) as u64).map_err(
+                        |e| GifError::read_failed(": local color table").with_io_source(e),
+                    )?;
+                    slice::skip_bytes(reader, 1).map_err(|e| {
+                        GifError::read_failed(": LZW minimum code size").with_io_source(e)
+                    })?;
+                    block::skip_sub_blocks(reader)?;
+                    self.frames += 1;
+                }
+                block::EXTENSION => self.parse_extension(reader)?,
+                block::TRAILER => break,
+                _ => return Err(GifError::parse(": unknown block type").with_data(&[kind])),
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse an extension after its introducer
+    fn parse_extension(&mut self, reader: &mut impl io::BufRead) -> GifResult<()> {
+        let label = slice::read_u8(reader)
+            .map_err(|e| GifError::read_failed(": extension label").with_io_source(e))?;
+        match label {
+            // Packed disposal flags, 2 byte Little Endian delay and transparent color index
+            block::GRAPHIC_CONTROL => {
+                let data = block::read_sub_blocks(reader, false)?;
+                if data.len() < 3 {
+                    return Err(GifError::parse(": graphic control extension too short"));
+                }
+                self.delay += u16::from_le_bytes([data[1], data[2]]) as u64;
+            }
+            block::COMMENT => {
+                let data = block::read_sub_blocks(reader, false)?;
+                self.comments.push(Text {
+                    keyword: "Comment".into(),
+                    language: None,
+                    translated_keyword: None,
+                    text: String::from_utf8_lossy(&data).into_owned(),
+                });
+            }
+
+            // Identifier and auth code sub-block followed by the application data sub-blocks
+            block::APPLICATION => {
+                let len = slice::read_u8(reader).map_err(|e| {
+                    GifError::read_failed(": application id length").with_io_source(e)
+                })?;
+                let id = slice::read_bytes(reader, len as usize)
+                    .map_err(|e| GifError::read_failed(": application id").with_io_source(e))?;
+                match id.as_slice() {
+                    // Sub-block id 1 followed by the 2 byte Little Endian loop count
+                    block::NETSCAPE => {
+                        let data = block::read_sub_blocks(reader, false)?;
+                        if data.len() >= 3 && data[0] == 0x01 {
+                            self.loop_count = Some(u16::from_le_bytes([data[1], data[2]]));
+                        }
+                    }
+
+                    // XMP is written as is rather than in sub-blocks followed by a magic trailer
+                    // that walks any sub-block reader to the terminator i.e. `01 FF FE .. 01 00`
+                    block::XMP => {
+                        let mut data = block::read_sub_blocks(reader, true)?;
+                        if let Some(i) = data.windows(3).position(|x| x == [0x01, 0xFF, 0xFE]) {
+                            data.truncate(i);
+                        }
+                        self.xmp = Some(data);
+                    }
+                    _ => block::skip_sub_blocks(reader)?,
+                }
+            }
+            _ => block::skip_sub_blocks(reader)?,
+        }
+        Ok(())
+    }
+}
+
+impl Display for Gif {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "GIF{}: {}x{}, {} frames", self.version, self.width, self.height, self.frames)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{container::GIF_TEST_DATA, errors::BaseError};
+
+    #[test]
+    fn test_parse() {
+        let gif = Gif::parse(io::Cursor::new(&GIF_TEST_DATA)).unwrap();
+        assert_eq!(gif.version(), "89a");
+        assert_eq!((gif.width(), gif.height()), (3, 2));
+        assert_eq!(gif.frame_count(), 2);
+        assert_eq!(gif.duration(), Duration::from_millis(350));
+        assert_eq!(gif.loop_count(), Some(0));
+        assert_eq!(gif.text().len(), 1);
+        assert_eq!(gif.text()[0].text, "Hello GIF");
+        assert_eq!(gif.xmp(), Some(&br#"<x:xmpmeta xmlns:x="adobe:ns:meta/"/>"#[..]));
+    }
+
+    #[test]
+    fn test_parse_unknown_block() {
+        let mut data = GIF_TEST_DATA.to_vec();
+        data[19] = 0x00;
+        let err = Gif::parse(io::Cursor::new(&data)).unwrap_err();
+        assert_eq!(err.to_string(), "GIF parse failed: unknown block type [00]");
+    }
+
+    #[test]
+    fn test_parse_missing_trailer() {
+        let err =
+            Gif::parse(io::Cursor::new(&GIF_TEST_DATA[..GIF_TEST_DATA.len() - 1])).unwrap_err();
+        assert_eq!(
+            err.all_to_string(),
+            "GIF read failed: block type ==> io::Error: failed to fill whole buffer"
+        );
+    }
+
+    #[test]
+    fn test_parse_truncated_color_table() {
+        let err = Gif::parse(io::Cursor::new(&GIF_TEST_DATA[..15])).unwrap_err();
+        assert_eq!(
+            err.all_to_string(),
+            "GIF read failed: global color table ==> io::Error: failed to skip whole buffer"
+        );
+    }
+}
@@ -0,0 +1,12 @@
+// GIF's are constructed from a 6 byte header, the logical screen descriptor and an optional global
+// color table followed by `Blocks`. Blocks are either image descriptors or extensions each of which
+// carries its data in length prefixed sub-blocks ending with a zero length block terminator.
+mod block;
+mod gif;
+mod test_data;
+
+pub use gif::*;
+
+// Expose testing data to other modules
+#[cfg(test)]
+pub(crate) use test_data::GIF_TEST_DATA;
@@ -0,0 +1,83 @@
+// Two frame animated GIF with comment and XMP extensions
+#[cfg(test)]
+pub(crate) const GIF_TEST_DATA: [u8; 407] = [
+    /* 000-005 */ 0x47, 0x49, 0x46, 0x38, 0x39, 0x61, // GIF header
+    /* 006-009 */ 0x03, 0x00, 0x02, 0x00, // LSD: width (3), height (2)
+    /* 010-012 */ 0x80, 0x00,
+    0x00, // LSD: packed global color table of 2, background, aspect
+    /* 013-018 */ 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, // Global color table: black, white
+    //
+    /* 019-021 */ 0x21, 0xff, 0x0b, // Application: introducer, label, block size
+    /* 022-032 */ 0x4e, 0x45, 0x54, 0x53, 0x43, 0x41, 0x50, 0x45, 0x32, 0x2e,
+    0x30, // Application: identifier and auth code
+    /* 033-037 */ 0x03, 0x01, 0x00, 0x00,
+    0x00, // Application: loop count (0) and terminator
+    //
+    /* 038-039 */ 0x21, 0xfe, // Comment: introducer, label
+    /* 040-049 */ 0x09, 0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x20, 0x47, 0x49,
+    0x46, // Comment: sub-block
+    /* 050-050 */ 0x00, // Comment: terminator
+    //
+    /* 051-053 */ 0x21, 0xff, 0x0b, // XMP: introducer, label, block size
+    /* 054-064 */ 0x58, 0x4d, 0x50, 0x20, 0x44, 0x61, 0x74, 0x61, 0x58, 0x4d,
+    0x50, // XMP: identifier and auth code
+    /* 065-076 */ 0x3c, 0x78, 0x3a, 0x78, 0x6d, 0x70, 0x6d, 0x65, 0x74, 0x61, 0x20,
+    0x78, // XMP: packet written without sub-block sizes
+    /* 077-088 */ 0x6d, 0x6c, 0x6e, 0x73, 0x3a, 0x78, 0x3d, 0x22, 0x61, 0x64, 0x6f,
+    0x62, // XMP: ...
+    /* 089-100 */ 0x65, 0x3a, 0x6e, 0x73, 0x3a, 0x6d, 0x65, 0x74, 0x61, 0x2f, 0x22,
+    0x2f, // XMP: ...
+    /* 101-101 */ 0x3e, // XMP: ...
+    /* 102-117 */ 0x01, 0xff, 0xfe, 0xfd, 0xfc, 0xfb, 0xfa, 0xf9, 0xf8, 0xf7, 0xf6, 0xf5,
+    0xf4, 0xf3, 0xf2, 0xf1, // XMP: magic trailer
+    /* 118-133 */ 0xf0, 0xef, 0xee, 0xed, 0xec, 0xeb, 0xea, 0xe9, 0xe8, 0xe7, 0xe6, 0xe5,
+    0xe4, 0xe3, 0xe2, 0xe1, // XMP: ...
+    /* 134-149 */ 0xe0, 0xdf, 0xde, 0xdd, 0xdc, 0xdb, 0xda, 0xd9, 0xd8, 0xd7, 0xd6, 0xd5,
+    0xd4, 0xd3, 0xd2, 0xd1, // XMP: ...
+    /* 150-165 */ 0xd0, 0xcf, 0xce, 0xcd, 0xcc, 0xcb, 0xca, 0xc9, 0xc8, 0xc7, 0xc6, 0xc5,
+    0xc4, 0xc3, 0xc2, 0xc1, // XMP: ...
+    /* 166-181 */ 0xc0, 0xbf, 0xbe, 0xbd, 0xbc, 0xbb, 0xba, 0xb9, 0xb8, 0xb7, 0xb6, 0xb5,
+    0xb4, 0xb3, 0xb2, 0xb1, // XMP: ...
+    /* 182-197 */ 0xb0, 0xaf, 0xae, 0xad, 0xac, 0xab, 0xaa, 0xa9, 0xa8, 0xa7, 0xa6, 0xa5,
+    0xa4, 0xa3, 0xa2, 0xa1, // XMP: ...
+    /* 198-213 */ 0xa0, 0x9f, 0x9e, 0x9d, 0x9c, 0x9b, 0x9a, 0x99, 0x98, 0x97, 0x96, 0x95,
+    0x94, 0x93, 0x92, 0x91, // XMP: ...
+    /* 214-229 */ 0x90, 0x8f, 0x8e, 0x8d, 0x8c, 0x8b, 0x8a, 0x89, 0x88, 0x87, 0x86, 0x85,
+    0x84, 0x83, 0x82, 0x81, // XMP: ...
+    /* 230-245 */ 0x80, 0x7f, 0x7e, 0x7d, 0x7c, 0x7b, 0x7a, 0x79, 0x78, 0x77, 0x76, 0x75,
+    0x74, 0x73, 0x72, 0x71, // XMP: ...
+    /* 246-261 */ 0x70, 0x6f, 0x6e, 0x6d, 0x6c, 0x6b, 0x6a, 0x69, 0x68, 0x67, 0x66, 0x65,
+    0x64, 0x63, 0x62, 0x61, // XMP: ...
+    /* 262-277 */ 0x60, 0x5f, 0x5e, 0x5d, 0x5c, 0x5b, 0x5a, 0x59, 0x58, 0x57, 0x56, 0x55,
+    0x54, 0x53, 0x52, 0x51, // XMP: ...
+    /* 278-293 */ 0x50, 0x4f, 0x4e, 0x4d, 0x4c, 0x4b, 0x4a, 0x49, 0x48, 0x47, 0x46, 0x45,
+    0x44, 0x43, 0x42, 0x41, // XMP: ...
+    /* 294-309 */ 0x40, 0x3f, 0x3e, 0x3d, 0x3c, 0x3b, 0x3a, 0x39, 0x38, 0x37, 0x36, 0x35,
+    0x34, 0x33, 0x32, 0x31, // XMP: ...
+    /* 310-325 */ 0x30, 0x2f, 0x2e, 0x2d, 0x2c, 0x2b, 0x2a, 0x29, 0x28, 0x27, 0x26, 0x25,
+    0x24, 0x23, 0x22, 0x21, // XMP: ...
+    /* 326-341 */ 0x20, 0x1f, 0x1e, 0x1d, 0x1c, 0x1b, 0x1a, 0x19, 0x18, 0x17, 0x16, 0x15,
+    0x14, 0x13, 0x12, 0x11, // XMP: ...
+    /* 342-357 */ 0x10, 0x0f, 0x0e, 0x0d, 0x0c, 0x0b, 0x0a, 0x09, 0x08, 0x07, 0x06, 0x05,
+    0x04, 0x03, 0x02, 0x01, // XMP: ...
+    /* 358-358 */ 0x00, // XMP: ...
+    /* 359-359 */ 0x00, // XMP: terminator
+    //
+    /* 360-362 */ 0x21, 0xf9, 0x04, // Frame 0 GCE: introducer, label, block size
+    /* 363-367 */ 0x04, 0x0a, 0x00, 0x00,
+    0x00, // Frame 0 GCE: packed, delay (10), transparent index, terminator
+    /* 368-377 */ 0x2c, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x02, 0x00,
+    0x00, // Frame 0: image descriptor
+    /* 378-382 */ 0x02, 0x02, 0x44, 0x01,
+    0x00, // Frame 0: LZW code size, image data and terminator
+    //
+    /* 383-385 */ 0x21, 0xf9, 0x04, // Frame 1 GCE: introducer, label, block size
+    /* 386-390 */ 0x04, 0x19, 0x00, 0x00,
+    0x00, // Frame 1 GCE: packed, delay (25), transparent index, terminator
+    /* 391-400 */ 0x2c, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x02, 0x00,
+    0x00, // Frame 1: image descriptor
+    /* 401-405 */ 0x02, 0x02, 0x44, 0x01,
+    0x00, // Frame 1: LZW code size, image data and terminator
+    //
+    /* 406-406 */ 0x3b, // GIF trailer
+];
@@ -0,0 +1,290 @@
+use std::io::{self, Read};
+
+use crate::{errors::HeifError, slice};
+
+pub(crate) const FTYP: [u8; 4] = *b"ftyp"; // File type and compatible brands
+pub(crate) const META: [u8; 4] = *b"meta"; // Item meta data
+pub(crate) const PITM: [u8; 4] = *b"pitm"; // Primary item
+pub(crate) const IINF: [u8; 4] = *b"iinf"; // Item information
+pub(crate) const INFE: [u8; 4] = *b"infe"; // Item information entry
+pub(crate) const ILOC: [u8; 4] = *b"iloc"; // Item locations
+pub(crate) const IPRP: [u8; 4] = *b"iprp"; // Item properties
+pub(crate) const IPCO: [u8; 4] = *b"ipco"; // Item property container
+pub(crate) const IPMA: [u8; 4] = *b"ipma"; // Item property associations
+pub(crate) const IDAT: [u8; 4] = *b"idat"; // Item data stored in the meta box
+pub(crate) const ISPE: [u8; 4] = *b"ispe"; // Image spatial extents
+pub(crate) const IROT: [u8; 4] = *b"irot"; // Image rotation
+pub(crate) const IMIR: [u8; 4] = *b"imir"; // Image mirroring
+pub(crate) const COLR: [u8; 4] = *b"colr"; // Colour information
+
+/// Simple Big Endian reader over box data
+pub(crate) struct Cursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    /// Bytes not yet read
+    pub(crate) fn remaining(&self) -> &'a [u8] {
+        &self.data[self.pos..]
+    }
+
+    /// Read out the given number of bytes
+    pub(crate) fn take(&mut self, len: usize) -> Result<&'a [u8], HeifError> {
+        match self.pos.checked_add(len) {
+            Some(end) if end <= self.data.len() => {
+                let bytes = &self.data[self.pos..end];
+                self.pos = end;
+                Ok(bytes)
+            }
+            _ => Err(HeifError::parse(": box data truncated")),
+        }
+    }
+
+    pub(crate) fn u8(&mut self) -> Result<u8, HeifError> {
+        self.take(1).map(|x| x[0])
+    }
+
+    pub(crate) fn u16(&mut self) -> Result<u16, HeifError> {
+        self.take(2)
+            .map(|x| u16::from_be_bytes(x.try_into().unwrap()))
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<u32, HeifError> {
+        self.take(4)
+            .map(|x| u32::from_be_bytes(x.try_into().unwrap()))
+    }
+
+    pub(crate) fn u64(&mut self) -> Result<u64, HeifError> {
+        self.take(8)
+            .map(|x| u64::from_be_bytes(x.try_into().unwrap()))
+    }
+
+    /// Read out a value stored in 0, 4 or 8 bytes as used by `iloc`
+    pub(crate) fn uint(&mut self, size: u8) -> Result<u64, HeifError> {
+        match size {
+            0 => Ok(0),
+            4 => self.u32().map(|x| x as u64),
+            8 => self.u64(),
+            _ => Err(HeifError::parse(": field size invalid").with_data(&[size])),
+        }
+    }
+
+    pub(crate) fn fourcc(&mut self) -> Result<[u8; 4], HeifError> {
+        self.take(4).map(|x| x.try_into().unwrap())
+    }
+
+    /// Read out a null terminated UTF-8 string, a missing terminator ends at the data end
+    pub(crate) fn string(&mut self) -> Result<String, HeifError> {
+        let remain = self.remaining();
+        let len = remain
+            .iter()
+            .position(|x| *x == 0x00)
+            .unwrap_or(remain.len());
+        let value = String::from_utf8_lossy(&remain[..len]).into_owned();
+        self.pos += (len + 1).min(remain.len());
+        Ok(value)
+    }
+}
+
+// ISO-BMFF boxes are defined by their type and the data they contain
+#[derive(Debug, PartialEq)]
+pub(crate) struct BmffBox<'a> {
+    pub(crate) kind: [u8; 4],  // Box type
+    pub(crate) data: &'a [u8], // Box data without the size and type header
+}
+
+impl<'a> BmffBox<'a> {
+    /// Split out the FullBox header
+    /// * Returns: (version, flags, cursor over the remaining data)
+    pub(crate) fn full(&self) -> Result<(u8, u32, Cursor<'a>), HeifError> {
+        let mut cursor = Cursor::new(self.data);
+        let header = cursor.u32()?;
+        Ok(((header >> 24) as u8, header & 0x00FF_FFFF, cursor))
+    }
+}
+
+/// Parse out the sequence of boxes in the given data
+/// * (4 bytes) Box size in Big Endian including the header, 1 = 64 bit size follows, 0 = to the end
+/// * (4 bytes) Box type e.g. `ftyp`
+/// * (8 bytes) Optional 64 bit box size in Big Endian
+pub(crate) fn parse_boxes(data: &[u8]) -> Result<Vec<BmffBox<'_>>, HeifError> {
+    let mut boxes = Vec::new();
+    let mut cursor = Cursor::new(data);
+
+    while !cursor.remaining().is_empty() {
+        let size = cursor.u32()? as u64;
+        let kind = cursor.fourcc()?;
+        let (size, header) = match size {
+            0 => (cursor.remaining().len() as u64 + 8, 8),
+            1 => (cursor.u64()?, 16),
+            _ => (size, 8),
+        };
+        if size < header {
+            return Err(HeifError::parse(": box size too small").with_data(&kind));
+        }
+        let data = cursor
+            .take((size - header) as usize)
+            .map_err(|e| e.with_data(&kind))?;
+        boxes.push(BmffBox { kind, data });
+    }
+
+    Ok(boxes)
+}
+
+/// Top level box type and data read from the source
+pub(crate) type SourceBox = ([u8; 4], Vec<u8>);
+
+/// Read the top level boxes of the given types from the source
+/// * Other boxes e.g. `mdat` are seeked over without being read
+/// * Reading stops once every type has been found so the image data is never visited
+pub(crate) fn read_boxes<T: io::Read + io::Seek>(
+    reader: &mut T,
+    kinds: &[[u8; 4]],
+) -> Result<Vec<SourceBox>, HeifError> {
+    let mut boxes: Vec<SourceBox> = Vec::new();
+
+    while !kinds
+        .iter()
+        .all(|x| boxes.iter().any(|(kind, _)| kind == x))
+    {
+        let mut header = Vec::new();
+        reader
+            .by_ref()
+            .take(8)
+            .read_to_end(&mut header)
+            .map_err(|e| HeifError::read_failed(": box header").with_io_source(e))?;
+        match header.len() {
+            0 => break,
+            8 => (),
+            _ => return Err(HeifError::parse(": box header truncated").with_data(&header)),
+        }
+        let kind: [u8; 4] = header[4..8].try_into().unwrap();
+        let size = u32::from_be_bytes(header[..4].try_into().unwrap()) as u64;
+        let size = match size {
+            0 => None,
+            1 => {
+                let size = slice::read_bytes(reader, 8)
+                    .map_err(|e| HeifError::read_failed(": box size").with_io_source(e))?;
+                Some(u64::from_be_bytes(size.try_into().unwrap()).checked_sub(16))
+            }
+            _ => Some(size.checked_sub(8)),
+        };
+        let keep = kinds.contains(&kind);
+        match size {
+            // Box runs to the end of the source
+            None if keep => {
+                let mut data = Vec::new();
+                reader
+                    .read_to_end(&mut data)
+                    .map_err(|e| HeifError::read_failed(": box data").with_io_source(e))?;
+                boxes.push((kind, data));
+                break;
+            }
+            None => break,
+            Some(None) => return Err(HeifError::parse(": box size too small").with_data(&kind)),
+            Some(Some(len)) if keep => {
+                let data = slice::read_bytes(reader, len as usize)
+                    .map_err(|_| HeifError::parse(": box data truncated").with_data(&kind))?;
+                boxes.push((kind, data));
+            }
+            Some(Some(len)) => {
+                let len = i64::try_from(len)
+                    .map_err(|_| HeifError::parse(": box size too large").with_data(&kind))?;
+                reader
+                    .seek(io::SeekFrom::Current(len))
+                    .map_err(|e| HeifError::read_failed(": box data").with_io_source(e))?;
+            }
+        }
+    }
+
+    Ok(boxes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_boxes() {
+        let data = [
+            0x00, 0x00, 0x00, 0x09, 0x66, 0x72, 0x65, 0x65, 0xAA, // free box with 1 byte
+            0x00, 0x00, 0x00, 0x01, 0x73, 0x6B, 0x69, 0x70, // skip box with 64 bit size
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x11, 0xBB, // 17 bytes
+            0x00, 0x00, 0x00, 0x00, 0x6D, 0x64, 0x61, 0x74, 0xCC, 0xDD, // mdat to the end
+        ];
+        let boxes = parse_boxes(&data).unwrap();
+        assert_eq!(boxes.len(), 3);
+        assert_eq!(boxes[0], BmffBox { kind: *b"free", data: &[0xAA] });
+        assert_eq!(boxes[1], BmffBox { kind: *b"skip", data: &[0xBB] });
+        assert_eq!(boxes[2], BmffBox { kind: *b"mdat", data: &[0xCC, 0xDD] });
+    }
+
+    #[test]
+    fn test_read_boxes() {
+        let data = [
+            0x00, 0x00, 0x00, 0x09, 0x66, 0x74, 0x79, 0x70, 0xAA, // ftyp box with 1 byte
+            0x00, 0x00, 0x00, 0x01, 0x6D, 0x64, 0x61, 0x74, // mdat box with 64 bit size
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x11, 0xBB, // 17 bytes
+            0x00, 0x00, 0x00, 0x00, 0x6D, 0x65, 0x74, 0x61, 0xCC, 0xDD, // meta to the end
+        ];
+        let boxes = read_boxes(&mut io::Cursor::new(&data), &[FTYP, META]).unwrap();
+        assert_eq!(boxes, vec![(FTYP, vec![0xAA]), (META, vec![0xCC, 0xDD])]);
+
+        // Stops at the end of the source or once everything is found
+        let mut reader = io::Cursor::new(&data);
+        let boxes = read_boxes(&mut reader, &[FTYP]).unwrap();
+        assert_eq!(boxes, vec![(FTYP, vec![0xAA])]);
+        assert_eq!(reader.position(), 9);
+        let boxes = read_boxes(&mut io::Cursor::new(&data[..25]), &[FTYP, META]).unwrap();
+        assert_eq!(boxes, vec![(FTYP, vec![0xAA])]);
+
+        // Skipped boxes may run past the end of the source
+        let mut data = data[..9].to_vec();
+        data.extend([0x00, 0x00, 0x01, 0x00, 0x6D, 0x64, 0x61, 0x74, 0xBB]);
+        let boxes = read_boxes(&mut io::Cursor::new(&data), &[FTYP, META]).unwrap();
+        assert_eq!(boxes, vec![(FTYP, vec![0xAA])]);
+    }
+
+    #[test]
+    fn test_read_boxes_truncated() {
+        let data = [0x00, 0x00, 0x00, 0x10, 0x66, 0x74, 0x79, 0x70, 0xAA];
+        assert_eq!(
+            read_boxes(&mut io::Cursor::new(&data), &[FTYP])
+                .unwrap_err()
+                .to_string(),
+            "HEIF parse failed: box data truncated [66, 74, 79, 70]"
+        );
+        assert_eq!(
+            read_boxes(&mut io::Cursor::new(&data[..5]), &[FTYP])
+                .unwrap_err()
+                .to_string(),
+            "HEIF parse failed: box header truncated [00, 00, 00, 10, 66]"
+        );
+        let data = [0x00, 0x00, 0x00, 0x04, 0x66, 0x74, 0x79, 0x70];
+        assert_eq!(
+            read_boxes(&mut io::Cursor::new(&data), &[FTYP])
+                .unwrap_err()
+                .to_string(),
+            "HEIF parse failed: box size too small [66, 74, 79, 70]"
+        );
+    }
+
+    #[test]
+    fn test_parse_boxes_truncated() {
+        let data = [0x00, 0x00, 0x00, 0x10, 0x66, 0x72, 0x65, 0x65, 0xAA];
+        assert_eq!(
+            parse_boxes(&data).unwrap_err().to_string(),
+            "HEIF parse failed: box data truncated [66, 72, 65, 65]"
+        );
+        let data = [0x00, 0x00, 0x00, 0x04, 0x66, 0x72, 0x65, 0x65];
+        assert_eq!(
+            parse_boxes(&data).unwrap_err().to_string(),
+            "HEIF parse failed: box size too small [66, 72, 65, 65]"
+        );
+    }
+}
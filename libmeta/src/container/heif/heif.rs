@@ -0,0 +1,268 @@
+use std::{
+    fmt::Display,
+    io::{self, prelude::*},
+};
+
+use super::{
+    bmff::{self, BmffBox, Cursor},
+    item::{Items, MirrorAxis, Property},
+};
+use crate::{errors::HeifError, meta::Exif};
+
+/// Simplify the Heif return type slightly
+pub type HeifResult<T> = Result<T, HeifError>;
+
+/// Brands identifying HEIF, HEIC and AVIF images or image sequences
+const BRANDS: [[u8; 4]; 10] = [
+    *b"heic", *b"heix", *b"heim", *b"heis", *b"hevc", *b"hevx", *b"mif1", *b"msf1", *b"avif",
+    *b"avis",
+];
+const AVIF_BRANDS: [[u8; 4]; 2] = [*b"avif", *b"avis"];
+
+/// Exif item type
+const EXIF: [u8; 4] = *b"Exif";
+
+/// XMP is stored as a `mime` item with this content type
+const XMP_CONTENT_TYPE: &str = "application/rdf+xml";
+
+#[derive(Debug)]
+pub struct Heif {
+    pub(crate) brand: [u8; 4],
+    pub(crate) compatible: Vec<[u8; 4]>,
+    pub(crate) dimensions: Option<(u32, u32)>,
+    pub(crate) rotation: Option<u16>,
+    pub(crate) mirror: Option<MirrorAxis>,
+    pub(crate) icc_profile: Option<Vec<u8>>,
+    pub(crate) exif: Option<Vec<u8>>,
+    pub(crate) xmp: Option<Vec<u8>>,
+}
+
+impl Heif {
+    /// Parse all meta data from the given HEIF or AVIF source.
+    /// * Only the `ftyp` and `meta` boxes are read, item data is then seeked to directly so that
+    ///   the image data in `mdat` is never buffered
+    /// * Item locations are relative to the reader's position on entry i.e. the start of the file
+    pub fn parse<T: io::Read + io::Seek>(mut reader: T) -> HeifResult<Self> {
+        let base = reader
+            .stream_position()
+            .map_err(|x| HeifError::read_failed(": source position").with_io_source(x))?;
+
+        // Check the header to determine the media type
+        let mut header = Vec::new();
+        reader
+            .by_ref()
+            .take(12)
+            .read_to_end(&mut header)
+            .map_err(|x| HeifError::read_failed(": invalid header").with_io_source(x))?;
+        if !Self::is_heif(&header) {
+            return Err(HeifError::parse(": invalid header").with_data(&header));
+        }
+        reader
+            .seek(io::SeekFrom::Start(base))
+            .map_err(|x| HeifError::read_failed(": source position").with_io_source(x))?;
+        let boxes = bmff::read_boxes(&mut reader, &[bmff::FTYP, bmff::META])?;
+
+        // File type box: major brand, minor version and compatible brands
+        let mut cursor = Cursor::new(&boxes[0].1);
+        let brand = cursor.fourcc()?;
+        cursor.u32()?;
+        let mut compatible = Vec::new();
+        while !cursor.remaining().is_empty() {
+            compatible.push(cursor.fourcc()?);
+        }
+
+        // Item meta data
+        let items = match boxes.iter().find(|(kind, _)| *kind == bmff::META) {
+            Some((kind, data)) => Items::parse(&BmffBox { kind: *kind, data })?,
+            None => return Err(HeifError::parse(": meta box not found")),
+        };
+        let mut heif = Heif {
+            brand,
+            compatible,
+            dimensions: None,
+            rotation: None,
+            mirror: None,
+            icc_profile: None,
+            exif: None,
+            xmp: None,
+        };
+
+        // Primary item properties
+        if let Some(primary) = items.primary {
+            for property in items.properties(primary) {
+                match property {
+                    Property::Extents(width, height) => heif.dimensions = Some((*width, *height)),
+                    Property::Rotation(angle) => heif.rotation = Some(*angle),
+                    Property::Mirror(axis) => heif.mirror = Some(*axis),
+                    Property::Icc(profile) => heif.icc_profile = Some(profile.clone()),
+                    Property::Other => (),
+                }
+            }
+        }
+
+        // Exif and XMP item payloads, a damaged item is dropped keeping the primary item details
+        if let Some(item) = items.items.iter().find(|x| x.kind == EXIF) {
+            heif.exif = items.data(item.id, &mut reader, base).ok();
+        }
+        if let Some(item) = items
+            .items
+            .iter()
+            .find(|x| x.content_type == XMP_CONTENT_TYPE)
+        {
+            heif.xmp = items.data(item.id, &mut reader, base).ok();
+        }
+
+        Ok(heif)
+    }
+
+    // Determine if the given header is from a heif or avif source i.e. `ftyp` with a known brand
+    pub(crate) fn is_heif(header: &[u8]) -> bool {
+        header.len() >= 12
+            && header[4..8] == bmff::FTYP
+            && BRANDS.iter().any(|x| header[8..12] == *x)
+    }
+
+    /// Major brand e.g. `heic` or `avif`
+    pub fn brand(&self) -> &str {
+        std::str::from_utf8(&self.brand).unwrap_or_default()
+    }
+
+    /// Is this an AVIF i.e. AV1 encoded image
+    pub fn is_avif(&self) -> bool {
+        AVIF_BRANDS
+            .iter()
+            .any(|x| self.brand == *x || self.compatible.contains(x))
+    }
+
+    /// Primary image width and height in pixels before rotation and mirroring
+    pub fn dimensions(&self) -> Option<(u32, u32)> {
+        self.dimensions
+    }
+
+    /// Primary image rotation in degrees anti-clockwise to apply for display
+    pub fn rotation(&self) -> Option<u16> {
+        self.rotation
+    }
+
+    /// Primary image mirroring to apply for display, applied before rotation
+    pub fn mirror(&self) -> Option<MirrorAxis> {
+        self.mirror
+    }
+
+    /// Get the raw ICC profile from the primary item's `colr` property if it exists
+    pub(crate) fn icc_profile(&self) -> Option<&[u8]> {
+        self.icc_profile.as_deref()
+    }
+
+    /// Get the raw XMP packet from the `application/rdf+xml` item if it exists
+    pub fn xmp(&self) -> Option<&[u8]> {
+        self.xmp.as_deref()
+    }
+
+    /// Get the Exif meta data from the parsed HEIF.
+    /// * Exif item data starts with a 4 byte Big Endian offset to the TIFF header which skips any
+    ///   prefix such as the JPEG APP1 `Exif\0\0` identifier
+    pub(crate) fn exif(&self) -> Option<HeifResult<Exif>> {
+        self.exif.as_ref().map(|data| {
+            let mut cursor = Cursor::new(data);
+            let offset = cursor.u32()?;
+            let tiff = cursor
+                .take(offset as usize)
+                .map(|_| cursor.remaining())
+                .map_err(|_| HeifError::parse(": exif TIFF header offset out of bounds"))?;
+            Exif::parse_tiff(tiff).map_err(|e| HeifError::parse(": exif parsing").wrap(e))
+        })
+    }
+}
+
+impl Display for Heif {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}: {:?}", self.brand(), self.dimensions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{container::HEIF_TEST_DATA, Field, Tag};
+
+    #[test]
+    fn test_parse() {
+        let heif = Heif::parse(io::Cursor::new(&HEIF_TEST_DATA)).unwrap();
+        assert_eq!(heif.brand(), "heic");
+        assert!(!heif.is_avif());
+        assert_eq!(heif.dimensions(), Some((4032, 3024)));
+        assert_eq!(heif.rotation(), Some(90));
+        assert_eq!(heif.mirror(), Some(MirrorAxis::Horizontal));
+        assert_eq!(heif.xmp(), Some(&br#"<x:xmpmeta xmlns:x="adobe:ns:meta/"/>"#[..]));
+
+        let exif = heif.exif().unwrap().unwrap();
+        assert_eq!(exif.get_field(Tag::Make), Some(Field::Make("Apple".into())));
+    }
+
+    #[test]
+    fn test_parse_avif_brand() {
+        let mut data = HEIF_TEST_DATA.to_vec();
+        data[8..12].copy_from_slice(b"avif");
+        let heif = Heif::parse(io::Cursor::new(&data)).unwrap();
+        assert!(heif.is_avif());
+        assert_eq!(heif.brand(), "avif");
+    }
+
+    #[test]
+    fn test_is_heif() {
+        assert!(Heif::is_heif(&HEIF_TEST_DATA[..12]));
+        assert!(!Heif::is_heif(b"\x00\x00\x00\x18ftypisom"));
+        assert!(!Heif::is_heif(b"\x00\x00\x00\x18ftyp"));
+    }
+
+    #[test]
+    fn test_parse_bad_items() {
+        // Exif extent past the end of the source and XMP stored with an unsupported method
+        let mut data = HEIF_TEST_DATA.to_vec();
+        data[220..224].copy_from_slice(&[0x00, 0x00, 0xFF, 0xFF]);
+        data[231] = 0x02;
+        let heif = Heif::parse(io::Cursor::new(&data)).unwrap();
+        assert_eq!(heif.dimensions(), Some((4032, 3024)));
+        assert_eq!(heif.rotation(), Some(90));
+        assert!(heif.exif().is_none());
+        assert!(heif.xmp().is_none());
+    }
+
+    #[test]
+    fn test_parse_offset_source() {
+        // Item locations are relative to where the source starts
+        let mut data = vec![0xAA; 5];
+        data.extend(HEIF_TEST_DATA);
+        let mut reader = io::Cursor::new(&data);
+        reader.set_position(5);
+        let heif = Heif::parse(reader).unwrap();
+        let exif = heif.exif().unwrap().unwrap();
+        assert_eq!(exif.get_field(Tag::Make), Some(Field::Make("Apple".into())));
+        assert_eq!(heif.xmp().map(|x| x.len()), Some(37));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let err = Heif::parse(io::Cursor::new(&HEIF_TEST_DATA[..8])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "HEIF parse failed: invalid header [00, 00, 00, 18, 66, 74, 79, 70]"
+        );
+        let err = Heif::parse(io::Cursor::new(&HEIF_TEST_DATA[..40])).unwrap_err();
+        assert_eq!(err.to_string(), "HEIF parse failed: box data truncated [6d, 65, 74, 61]");
+        let err = Heif::parse(io::Cursor::new(&HEIF_TEST_DATA[..24])).unwrap_err();
+        assert_eq!(err.to_string(), "HEIF parse failed: meta box not found");
+    }
+
+    #[test]
+    fn test_exif_offset_out_of_bounds() {
+        let mut data = HEIF_TEST_DATA.to_vec();
+        data[380] = 0xFF; // Exif TIFF header offset
+        let heif = Heif::parse(io::Cursor::new(&data)).unwrap();
+        assert_eq!(
+            heif.exif().unwrap().unwrap_err().to_string(),
+            "HEIF parse failed: exif TIFF header offset out of bounds"
+        );
+    }
+}
@@ -0,0 +1,342 @@
+use std::io::{self, Read};
+
+use super::bmff::{self, BmffBox, Cursor};
+use crate::errors::HeifError;
+
+/// Axis an image is mirrored about from the `imir` property
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MirrorAxis {
+    Vertical,   // 0, flipped left to right
+    Horizontal, // 1, flipped top to bottom
+}
+
+/// Item information from an `infe` box
+#[derive(Debug, PartialEq)]
+pub(crate) struct Item {
+    pub(crate) id: u32,
+    pub(crate) kind: [u8; 4], // e.g. `hvc1`, `av01`, `grid`, `Exif` or `mime`
+    pub(crate) content_type: String, // `mime` items only e.g. `application/rdf+xml`
+}
+
+/// Item location from the `iloc` box
+#[derive(Debug, PartialEq)]
+pub(crate) struct Location {
+    pub(crate) id: u32,
+    pub(crate) method: u8, // 0 = file offset, 1 = idat offset, 2 = item offset
+    pub(crate) extents: Vec<(u64, u64)>, // (offset including the base offset, length)
+}
+
+/// Item properties from the `ipco` box that we care about
+#[derive(Debug, PartialEq)]
+pub(crate) enum Property {
+    Extents(u32, u32),  // ispe width and height
+    Rotation(u16),      // irot degrees anti-clockwise
+    Mirror(MirrorAxis), // imir axis
+    Icc(Vec<u8>),       // colr restricted or unrestricted ICC profile
+    Other,
+}
+
+/// All the item tables of the `meta` box
+#[derive(Debug, Default)]
+pub(crate) struct Items<'a> {
+    pub(crate) primary: Option<u32>,
+    pub(crate) items: Vec<Item>,
+    pub(crate) locations: Vec<Location>,
+    pub(crate) properties: Vec<Property>,
+    pub(crate) associations: Vec<(u32, Vec<u16>)>, // item id to 1 based property indexes
+    pub(crate) idat: &'a [u8],
+}
+
+impl<'a> Items<'a> {
+    /// Parse the `meta` box's children
+    pub(crate) fn parse(meta: &BmffBox<'a>) -> Result<Self, HeifError> {
+        let (_, _, cursor) = meta.full()?;
+        let mut items = Items::default();
+
+        for child in bmff::parse_boxes(cursor.remaining())? {
+            match child.kind {
+                bmff::PITM => items.primary = Some(parse_pitm(&child)?),
+                bmff::IINF => items.items = parse_iinf(&child)?,
+                bmff::ILOC => items.locations = parse_iloc(&child)?,
+                bmff::IPRP => {
+                    for child in bmff::parse_boxes(child.data)? {
+                        match child.kind {
+                            bmff::IPCO => items.properties = parse_ipco(&child)?,
+                            bmff::IPMA => items.associations = parse_ipma(&child)?,
+                            _ => (),
+                        }
+                    }
+                }
+                bmff::IDAT => items.idat = child.data,
+                _ => (),
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Get the properties associated with the given item
+    pub(crate) fn properties(&self, id: u32) -> impl Iterator<Item = &Property> {
+        self.associations
+            .iter()
+            .filter(move |(item, _)| *item == id)
+            .flat_map(|(_, indexes)| indexes.iter())
+            .filter_map(|index| self.properties.get((*index as usize).checked_sub(1)?))
+    }
+
+    /// Get the data for the given item by concatenating its extents
+    /// * **reader** is the source that file offsets are relative to starting at **base**
+    pub(crate) fn data<T: io::Read + io::Seek>(
+        &self,
+        id: u32,
+        reader: &mut T,
+        base: u64,
+    ) -> Result<Vec<u8>, HeifError> {
+        let location = match self.locations.iter().find(|x| x.id == id) {
+            Some(location) => location,
+            None => return Err(HeifError::parse(": item location not found")),
+        };
+        match location.method {
+            0 => read_extents(&location.extents, reader, base),
+            1 => read_extents(&location.extents, &mut io::Cursor::new(self.idat), 0),
+            _ => Err(HeifError::parse(": item construction method unsupported")
+                .with_data(&[location.method])),
+        }
+    }
+}
+
+/// Read and concatenate the given extents from the source
+/// * A zero length extent runs to the end of the source
+fn read_extents<T: io::Read + io::Seek>(
+    extents: &[(u64, u64)],
+    reader: &mut T,
+    base: u64,
+) -> Result<Vec<u8>, HeifError> {
+    let out_of_bounds = || HeifError::parse(": item extent out of bounds");
+    let mut data = Vec::new();
+    for (offset, length) in extents.iter() {
+        let start = base.checked_add(*offset).ok_or_else(out_of_bounds)?;
+        reader
+            .seek(io::SeekFrom::Start(start))
+            .map_err(|e| HeifError::read_failed(": item extent").with_io_source(e))?;
+        let limit = match length {
+            0 => u64::MAX,
+            _ => *length,
+        };
+        let read = reader
+            .by_ref()
+            .take(limit)
+            .read_to_end(&mut data)
+            .map_err(|e| HeifError::read_failed(": item extent").with_io_source(e))?;
+        if *length != 0 && read as u64 != *length {
+            return Err(out_of_bounds());
+        }
+    }
+    Ok(data)
+}
+
+/// Primary item id, 2 bytes for version 0 otherwise 4 bytes
+fn parse_pitm(pitm: &BmffBox) -> Result<u32, HeifError> {
+    let (version, _, mut cursor) = pitm.full()?;
+    match version {
+        0 => cursor.u16().map(|x| x as u32),
+        _ => cursor.u32(),
+    }
+}
+
+/// Item information entries, the count is 2 bytes for version 0 otherwise 4 bytes
+fn parse_iinf(iinf: &BmffBox) -> Result<Vec<Item>, HeifError> {
+    let (version, _, mut cursor) = iinf.full()?;
+    match version {
+        0 => cursor.u16().map(|x| x as u32)?,
+        _ => cursor.u32()?,
+    };
+
+    let mut items = Vec::new();
+    for infe in bmff::parse_boxes(cursor.remaining())?
+        .iter()
+        .filter(|x| x.kind == bmff::INFE)
+    {
+        // Versions 0 and 1 predate item types and only describe protected or legacy items
+        let (version, _, mut cursor) = infe.full()?;
+        if version < 2 {
+            continue;
+        }
+        let id = match version {
+            2 => cursor.u16()? as u32,
+            _ => cursor.u32()?,
+        };
+        cursor.u16()?; // protection index
+        let kind = cursor.fourcc()?;
+        cursor.string()?; // item name
+        let content_type = match kind {
+            [b'm', b'i', b'm', b'e'] => cursor.string()?,
+            _ => String::new(),
+        };
+        items.push(Item { id, kind, content_type });
+    }
+    Ok(items)
+}
+
+/// Item locations
+/// * (4 bits) offset size, (4 bits) length size, (4 bits) base offset size, (4 bits) index size
+/// * (2 or 4 bytes) item count then per item the id, construction method (versions 1 and 2),
+///   data reference index, base offset and extents of offset and length
+fn parse_iloc(iloc: &BmffBox) -> Result<Vec<Location>, HeifError> {
+    let (version, _, mut cursor) = iloc.full()?;
+    let sizes = cursor.u16()?;
+    let offset_size = (sizes >> 12) as u8;
+    let length_size = ((sizes >> 8) & 0x0F) as u8;
+    let base_offset_size = ((sizes >> 4) & 0x0F) as u8;
+    let index_size = match version {
+        1 | 2 => (sizes & 0x0F) as u8,
+        _ => 0,
+    };
+    let count = match version {
+        0 | 1 => cursor.u16()? as u32,
+        _ => cursor.u32()?,
+    };
+
+    let mut locations = Vec::new();
+    for _ in 0..count {
+        let id = match version {
+            0 | 1 => cursor.u16()? as u32,
+            _ => cursor.u32()?,
+        };
+        let method = match version {
+            1 | 2 => (cursor.u16()? & 0x0F) as u8,
+            _ => 0,
+        };
+        cursor.u16()?; // data reference index
+        let base = cursor.uint(base_offset_size)?;
+        let mut extents = Vec::new();
+        for _ in 0..cursor.u16()? {
+            cursor.uint(index_size)?;
+            let offset = cursor.uint(offset_size)?;
+            let length = cursor.uint(length_size)?;
+            extents.push((base.saturating_add(offset), length));
+        }
+        locations.push(Location { id, method, extents });
+    }
+    Ok(locations)
+}
+
+/// Item properties in index order
+fn parse_ipco(ipco: &BmffBox) -> Result<Vec<Property>, HeifError> {
+    let mut properties = Vec::new();
+    for property in bmff::parse_boxes(ipco.data)? {
+        properties.push(match property.kind {
+            // FullBox with 4 byte width and height
+            bmff::ISPE => {
+                let (_, _, mut cursor) = property.full()?;
+                Property::Extents(cursor.u32()?, cursor.u32()?)
+            }
+
+            // Lower 2 bits are the number of 90 degree anti-clockwise rotations
+            bmff::IROT => {
+                let angle = Cursor::new(property.data).u8()?;
+                Property::Rotation((angle & 0x03) as u16 * 90)
+            }
+
+            // Lowest bit is the mirror axis
+            bmff::IMIR => match Cursor::new(property.data).u8()? & 0x01 {
+                0 => Property::Mirror(MirrorAxis::Vertical),
+                _ => Property::Mirror(MirrorAxis::Horizontal),
+            },
+
+            // Colour type then an ICC profile or for `nclx` the colour primaries which are ignored
+            bmff::COLR => {
+                let mut cursor = Cursor::new(property.data);
+                match &cursor.fourcc()? {
+                    b"prof" | b"rICC" => Property::Icc(cursor.remaining().to_vec()),
+                    _ => Property::Other,
+                }
+            }
+            _ => Property::Other,
+        });
+    }
+    Ok(properties)
+}
+
+/// Item property associations
+/// * (4 bytes) entry count then per entry the item id (2 bytes for version 0 otherwise 4 bytes),
+///   1 byte association count and the associations with the high bit marking essential properties
+///   and the index stored in 7 bits or 15 bits when flag bit 0 is set
+fn parse_ipma(ipma: &BmffBox) -> Result<Vec<(u32, Vec<u16>)>, HeifError> {
+    let (version, flags, mut cursor) = ipma.full()?;
+
+    let mut associations = Vec::new();
+    for _ in 0..cursor.u32()? {
+        let id = match version {
+            0 => cursor.u16()? as u32,
+            _ => cursor.u32()?,
+        };
+        let mut indexes = Vec::new();
+        for _ in 0..cursor.u8()? {
+            indexes.push(match flags & 0x01 {
+                0 => (cursor.u8()? & 0x7F) as u16,
+                _ => cursor.u16()? & 0x7FFF,
+            });
+        }
+        associations.push((id, indexes));
+    }
+    Ok(associations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container::HEIF_TEST_DATA;
+
+    #[test]
+    fn test_parse_items() {
+        let boxes = bmff::parse_boxes(&HEIF_TEST_DATA).unwrap();
+        let items = Items::parse(&boxes[1]).unwrap();
+        assert_eq!(items.primary, Some(1));
+        assert_eq!(items.items.len(), 3);
+        assert_eq!(items.items[1], Item { id: 2, kind: *b"Exif", content_type: String::new() });
+        assert_eq!(items.items[2].content_type, "application/rdf+xml");
+        assert_eq!(items.locations[1], Location { id: 2, method: 0, extents: vec![(377, 42)] });
+        assert_eq!(items.locations[2].method, 1);
+        assert_eq!(
+            items.properties(1).collect::<Vec<_>>(),
+            vec![
+                &Property::Extents(4032, 3024),
+                &Property::Rotation(90),
+                &Property::Mirror(MirrorAxis::Horizontal)
+            ]
+        );
+        let mut reader = io::Cursor::new(&HEIF_TEST_DATA);
+        assert_eq!(items.data(1, &mut reader, 0).unwrap(), vec![0x00, 0x00, 0x00, 0x01]);
+        assert_eq!(items.data(3, &mut reader, 0).unwrap().len(), 37);
+        assert_eq!(
+            items.data(4, &mut reader, 0).unwrap_err().to_string(),
+            "HEIF parse failed: item location not found"
+        );
+
+        // Extents past the end of the source
+        let mut reader = io::Cursor::new(&HEIF_TEST_DATA[..400]);
+        assert_eq!(
+            items.data(2, &mut reader, 0).unwrap_err().to_string(),
+            "HEIF parse failed: item extent out of bounds"
+        );
+        assert_eq!(
+            items
+                .data(2, &mut reader, u64::MAX)
+                .unwrap_err()
+                .to_string(),
+            "HEIF parse failed: item extent out of bounds"
+        );
+    }
+
+    #[test]
+    fn test_parse_ipco_colr() {
+        let mut data = vec![0x00, 0x00, 0x00, 0x10];
+        data.extend_from_slice(b"colrproficc!");
+        data.extend_from_slice(&[0x00, 0x00, 0x00, 0x13]);
+        data.extend_from_slice(b"colrnclx");
+        data.extend_from_slice(&[0x00, 0x01, 0x00, 0x0D, 0x00, 0x06, 0x80]);
+        let properties = parse_ipco(&BmffBox { kind: bmff::IPCO, data: &data }).unwrap();
+        assert_eq!(properties, vec![Property::Icc(b"icc!".to_vec()), Property::Other]);
+    }
+}
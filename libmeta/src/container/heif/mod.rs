@@ -0,0 +1,14 @@
+// HEIF and AVIF are ISO Base Media File Format (ISO-BMFF) files constructed from nested `Boxes`. Each
+// box is made up of a 4 byte Big Endian size, a 4 byte type and its data which may contain further
+// boxes. Images and meta data are stored as items described by the boxes of the `meta` box.
+mod bmff;
+mod heif;
+mod item;
+mod test_data;
+
+pub use heif::*;
+pub use item::MirrorAxis;
+
+// Expose testing data to other modules
+#[cfg(test)]
+pub(crate) use test_data::HEIF_TEST_DATA;
@@ -0,0 +1,117 @@
+// HEIC with a primary image item, rotation and mirror properties, an Exif item and an XMP item
+#[cfg(test)]
+pub(crate) const HEIF_TEST_DATA: [u8; 419] = [
+    /* 000-003 */ 0x00, 0x00, 0x00, 0x18, // ftyp: size (24)
+    /* 004-007 */ 0x66, 0x74, 0x79, 0x70, // ftyp: type
+    /* 008-011 */ 0x68, 0x65, 0x69, 0x63, // ftyp: major brand heic
+    /* 012-015 */ 0x00, 0x00, 0x00, 0x00, // ftyp: minor version
+    /* 016-023 */ 0x6d, 0x69, 0x66, 0x31, 0x68, 0x65, 0x69,
+    0x63, // ftyp: compatible brands mif1, heic
+    //
+    /* 024-027 */ 0x00, 0x00, 0x01, 0x55, // meta: size (341)
+    /* 028-031 */ 0x6d, 0x65, 0x74, 0x61, // meta: type
+    /* 032-035 */ 0x00, 0x00, 0x00, 0x00, // meta: version and flags
+    //
+    /* 036-039 */ 0x00, 0x00, 0x00, 0x21, // hdlr: size (33)
+    /* 040-043 */ 0x68, 0x64, 0x6c, 0x72, // hdlr: type
+    /* 044-047 */ 0x00, 0x00, 0x00, 0x00, // hdlr: version and flags
+    /* 048-051 */ 0x00, 0x00, 0x00, 0x00, // hdlr: pre defined
+    /* 052-055 */ 0x70, 0x69, 0x63, 0x74, // hdlr: handler type pict
+    /* 056-067 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, // hdlr: reserved and empty name
+    /* 068-068 */ 0x00, // hdlr: ...
+    //
+    /* 069-072 */ 0x00, 0x00, 0x00, 0x0e, // pitm: size (14)
+    /* 073-076 */ 0x70, 0x69, 0x74, 0x6d, // pitm: type
+    /* 077-080 */ 0x00, 0x00, 0x00, 0x00, // pitm: version and flags
+    /* 081-082 */ 0x00, 0x01, // pitm: primary item id (1)
+    //
+    /* 083-086 */ 0x00, 0x00, 0x00, 0x61, // iinf: size (97)
+    /* 087-090 */ 0x69, 0x69, 0x6e, 0x66, // iinf: type
+    /* 091-094 */ 0x00, 0x00, 0x00, 0x00, // iinf: version and flags
+    /* 095-096 */ 0x00, 0x03, // iinf: entry count (3)
+    /* 097-100 */ 0x00, 0x00, 0x00, 0x15, // infe 1: size (21)
+    /* 101-104 */ 0x69, 0x6e, 0x66, 0x65, // infe 1: type
+    /* 105-108 */ 0x02, 0x00, 0x00, 0x00, // infe 1: version and flags
+    /* 109-112 */ 0x00, 0x01, 0x00, 0x00, // infe 1: item id, protection index
+    /* 113-116 */ 0x68, 0x76, 0x63, 0x31, // infe 1: item type hvc1 image
+    /* 117-117 */ 0x00, // infe 1: item name
+    /* 118-121 */ 0x00, 0x00, 0x00, 0x15, // infe 2: size (21)
+    /* 122-125 */ 0x69, 0x6e, 0x66, 0x65, // infe 2: type
+    /* 126-129 */ 0x02, 0x00, 0x00, 0x00, // infe 2: version and flags
+    /* 130-133 */ 0x00, 0x02, 0x00, 0x00, // infe 2: item id, protection index
+    /* 134-137 */ 0x45, 0x78, 0x69, 0x66, // infe 2: item type Exif
+    /* 138-138 */ 0x00, // infe 2: item name
+    /* 139-142 */ 0x00, 0x00, 0x00, 0x29, // infe 3: size (41)
+    /* 143-146 */ 0x69, 0x6e, 0x66, 0x65, // infe 3: type
+    /* 147-150 */ 0x02, 0x00, 0x00, 0x00, // infe 3: version and flags
+    /* 151-154 */ 0x00, 0x03, 0x00, 0x00, // infe 3: item id, protection index
+    /* 155-158 */ 0x6d, 0x69, 0x6d, 0x65, // infe 3: item type mime XMP
+    /* 159-170 */ 0x00, 0x61, 0x70, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, // infe 3: item name and content type
+    /* 171-179 */ 0x2f, 0x72, 0x64, 0x66, 0x2b, 0x78, 0x6d, 0x6c, 0x00, // infe 3: ...
+    //
+    /* 180-183 */ 0x00, 0x00, 0x00, 0x40, // iloc: size (64)
+    /* 184-187 */ 0x69, 0x6c, 0x6f, 0x63, // iloc: type
+    /* 188-191 */ 0x01, 0x00, 0x00, 0x00, // iloc: version and flags
+    /* 192-193 */ 0x44,
+    0x00, // iloc: offset size 4, length size 4, base offset size 0, index size 0
+    /* 194-195 */ 0x00, 0x03, // iloc: item count (3)
+    /* 196-203 */ 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x01, // iloc item 1: id, construction method, data reference, extent count
+    /* 204-211 */ 0x00, 0x00, 0x01, 0x75, 0x00, 0x00, 0x00,
+    0x04, // iloc item 1: extent offset (373), length (4)
+    /* 212-219 */ 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x01, // iloc item 2: id, construction method, data reference, extent count
+    /* 220-227 */ 0x00, 0x00, 0x01, 0x79, 0x00, 0x00, 0x00,
+    0x2a, // iloc item 2: extent offset (377), length (42)
+    /* 228-235 */ 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00,
+    0x01, // iloc item 3: id, construction method, data reference, extent count
+    /* 236-243 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x25, // iloc item 3: extent offset (0), length (37)
+    //
+    /* 244-247 */ 0x00, 0x00, 0x00, 0x4c, // iprp: size (76)
+    /* 248-251 */ 0x69, 0x70, 0x72, 0x70, // iprp: type
+    /* 252-255 */ 0x00, 0x00, 0x00, 0x2e, // ipco: size (46)
+    /* 256-259 */ 0x69, 0x70, 0x63, 0x6f, // ipco: type
+    /* 260-263 */ 0x00, 0x00, 0x00, 0x14, // ispe: size (20)
+    /* 264-267 */ 0x69, 0x73, 0x70, 0x65, // ispe: type
+    /* 268-271 */ 0x00, 0x00, 0x00, 0x00, // ispe: version and flags
+    /* 272-279 */ 0x00, 0x00, 0x0f, 0xc0, 0x00, 0x00, 0x0b,
+    0xd0, // ispe: width (4032), height (3024)
+    /* 280-283 */ 0x00, 0x00, 0x00, 0x09, // irot: size (9)
+    /* 284-287 */ 0x69, 0x72, 0x6f, 0x74, // irot: type
+    /* 288-288 */ 0x01, // irot: angle (1) i.e. 90 degrees anti-clockwise
+    /* 289-292 */ 0x00, 0x00, 0x00, 0x09, // imir: size (9)
+    /* 293-296 */ 0x69, 0x6d, 0x69, 0x72, // imir: type
+    /* 297-297 */ 0x01, // imir: axis (1) i.e. horizontal
+    /* 298-301 */ 0x00, 0x00, 0x00, 0x16, // ipma: size (22)
+    /* 302-305 */ 0x69, 0x70, 0x6d, 0x61, // ipma: type
+    /* 306-309 */ 0x00, 0x00, 0x00, 0x00, // ipma: version and flags
+    /* 310-313 */ 0x00, 0x00, 0x00, 0x01, // ipma: entry count (1)
+    /* 314-315 */ 0x00, 0x01, // ipma: item id (1)
+    /* 316-319 */ 0x03, 0x81, 0x02,
+    0x83, // ipma: association count (3), essential ispe, irot, essential imir
+    //
+    /* 320-323 */ 0x00, 0x00, 0x00, 0x2d, // idat: size (45)
+    /* 324-327 */ 0x69, 0x64, 0x61, 0x74, // idat: type
+    /* 328-339 */ 0x3c, 0x78, 0x3a, 0x78, 0x6d, 0x70, 0x6d, 0x65, 0x74, 0x61, 0x20,
+    0x78, // idat: XMP packet
+    /* 340-351 */ 0x6d, 0x6c, 0x6e, 0x73, 0x3a, 0x78, 0x3d, 0x22, 0x61, 0x64, 0x6f,
+    0x62, // idat: ...
+    /* 352-363 */ 0x65, 0x3a, 0x6e, 0x73, 0x3a, 0x6d, 0x65, 0x74, 0x61, 0x2f, 0x22,
+    0x2f, // idat: ...
+    /* 364-364 */ 0x3e, // idat: ...
+    //
+    /* 365-368 */ 0x00, 0x00, 0x00, 0x36, // mdat: size (54)
+    /* 369-372 */ 0x6d, 0x64, 0x61, 0x74, // mdat: type
+    /* 373-376 */ 0x00, 0x00, 0x00, 0x01, // mdat: image data
+    /* 377-380 */ 0x00, 0x00, 0x00, 0x06, // mdat: Exif TIFF header offset (6)
+    /* 381-386 */ 0x45, 0x78, 0x69, 0x66, 0x00, 0x00, // mdat: Exif identifier
+    /* 387-394 */ 0x49, 0x49, 0x2a, 0x00, 0x08, 0x00, 0x00, 0x00, // mdat: TIFF header
+    /* 395-396 */ 0x01, 0x00, // mdat: IFD 0 field count
+    /* 397-408 */ 0x0f, 0x01, 0x02, 0x00, 0x06, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00,
+    0x00, // mdat: Make ASCII (6) offset (26)
+    /* 409-412 */ 0x00, 0x00, 0x00, 0x00, // mdat: IFD 0 next IFD offset i.e. none
+    /* 413-418 */ 0x41, 0x70, 0x70, 0x6c, 0x65, 0x00, // mdat: Make data
+];
@@ -0,0 +1,16 @@
+// Photoshop image resource blocks (IRB) are a list of `8BIM` tagged resources each holding a single
+// kind of data e.g. resolution, IPTC-IIM or a thumbnail. They are found in JPEG APP13 segments and
+// the image resources section of PSD files.
+mod resolution;
+mod resource;
+mod thumbnail;
+
+pub use resolution::ResolutionInfo;
+pub(crate) use resource::*;
+pub use resource::{Resource, ResourceId};
+pub use thumbnail::ResourceThumbnail;
+
+use crate::errors::IrbError;
+
+/// Simplify the IRB return type slightly
+pub(crate) type IrbResult<T> = Result<T, IrbError>;
@@ -0,0 +1,79 @@
+use std::fmt::Display;
+
+use super::IrbResult;
+use crate::{errors::IrbError, meta::DensityUnit};
+
+/// Resolution from the `ResolutionInfo` image resource
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolutionInfo {
+    pub x_density: f64,      // horizontal pixel density
+    pub x_unit: DensityUnit, // horizontal density unit
+    pub y_density: f64,      // vertical pixel density
+    pub y_unit: DensityUnit, // vertical density unit
+}
+
+impl ResolutionInfo {
+    /// Parse the given `ResolutionInfo` resource data
+    /// * **Field**        | **Bytes** | **Description**
+    /// * *hRes*           | 4         | Big Endian 16.16 fixed point horizontal density
+    /// * *hResUnit*       | 2         | `1` = pixels per inch, `2` = pixels per centimeter
+    /// * *widthUnit*      | 2         | Display unit for the width, ignored
+    /// * *vRes*           | 4         | Big Endian 16.16 fixed point vertical density
+    /// * *vResUnit*       | 2         | `1` = pixels per inch, `2` = pixels per centimeter
+    /// * *heightUnit*     | 2         | Display unit for the height, ignored
+    pub(crate) fn parse(input: &[u8]) -> IrbResult<Self> {
+        let data: &[u8; 16] = input
+            .get(..16)
+            .and_then(|x| x.try_into().ok())
+            .ok_or_else(|| IrbError::parse(": resolution info truncated"))?;
+        let fixed = |x: &[u8]| u32::from_be_bytes([x[0], x[1], x[2], x[3]]) as f64 / 65536.0;
+
+        Ok(Self {
+            x_density: fixed(&data[0..4]),
+            x_unit: DensityUnit::from(u16::from_be_bytes([data[4], data[5]]) as usize),
+            y_density: fixed(&data[8..12]),
+            y_unit: DensityUnit::from(u16::from_be_bytes([data[12], data[13]]) as usize),
+        })
+    }
+}
+
+impl Display for ResolutionInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.x_unit == self.y_unit {
+            true => write!(f, "{}x{} pixels per {}", self.x_density, self.y_density, self.x_unit),
+            false => write!(
+                f,
+                "{} pixels per {} x {} pixels per {}",
+                self.x_density, self.x_unit, self.y_density, self.y_unit
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let data = [
+            0x01, 0x2C, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x96, 0x80, 0x00, 0x00, 0x02,
+            0x00, 0x01,
+        ];
+        let info = ResolutionInfo::parse(&data).unwrap();
+        assert_eq!(info.x_density, 300.0);
+        assert_eq!(info.x_unit, DensityUnit::PixelsPerInch);
+        assert_eq!(info.y_density, 150.5);
+        assert_eq!(info.y_unit, DensityUnit::PixelsPerCm);
+        assert_eq!(info.to_string(), "300 pixels per inch x 150.5 pixels per cm");
+
+        // Matching units are only shown once
+        let mut data = data;
+        data[13] = 0x01;
+        let info = ResolutionInfo::parse(&data).unwrap();
+        assert_eq!(info.to_string(), "300x150.5 pixels per inch");
+
+        let err = ResolutionInfo::parse(&data[..15]).unwrap_err();
+        assert_eq!(err.to_string(), "Photoshop resource parse failed: resolution info truncated");
+    }
+}
@@ -0,0 +1,239 @@
+use std::fmt::Display;
+
+use super::{ResolutionInfo, ResourceThumbnail};
+use crate::meta::{Iptc, IptcResult};
+
+/// Identifier prefixing the Photoshop image resource blocks in a JPEG APP13 segment
+pub(crate) const PHOTOSHOP_IDENTIFIER: &[u8] = b"Photoshop 3.0\0";
+
+/// Signatures starting an image resource block
+/// * `8BIM` is used by Photoshop while the others come from older third party software
+const SIGNATURES: [&[u8]; 5] = [b"8BIM", b"PHUT", b"DCSR", b"AgHg", b"MeSa"];
+
+/// Image resource identifier
+/// * Only the commonly used identifiers are named, everything else is kept as `Raw`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ResourceId {
+    /// Horizontal and vertical resolution with their display units
+    ResolutionInfo,
+
+    /// IPTC-IIM record
+    Iptc,
+
+    /// Photoshop 4.0 JPEG thumbnail with the color channels stored as BGR
+    ThumbnailBgr,
+
+    /// Photoshop 5.0+ JPEG thumbnail
+    Thumbnail,
+
+    /// MD5 digest of the IPTC-IIM record used to detect edits by other software
+    IptcDigest,
+
+    /// Unknown resource identifier
+    Raw(u16),
+}
+
+impl From<u16> for ResourceId {
+    fn from(val: u16) -> Self {
+        match val {
+            0x03ED => ResourceId::ResolutionInfo,
+            0x0404 => ResourceId::Iptc,
+            0x0409 => ResourceId::ThumbnailBgr,
+            0x040C => ResourceId::Thumbnail,
+            0x0425 => ResourceId::IptcDigest,
+            _ => ResourceId::Raw(val),
+        }
+    }
+}
+
+impl From<ResourceId> for u16 {
+    fn from(val: ResourceId) -> Self {
+        match val {
+            ResourceId::ResolutionInfo => 0x03ED,
+            ResourceId::Iptc => 0x0404,
+            ResourceId::ThumbnailBgr => 0x0409,
+            ResourceId::Thumbnail => 0x040C,
+            ResourceId::IptcDigest => 0x0425,
+            ResourceId::Raw(val) => val,
+        }
+    }
+}
+
+impl Display for ResourceId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResourceId::ResolutionInfo => write!(f, "Resolution Info"),
+            ResourceId::Iptc => write!(f, "IPTC-NAA"),
+            ResourceId::ThumbnailBgr => write!(f, "Photoshop BGR Thumbnail"),
+            ResourceId::Thumbnail => write!(f, "Photoshop Thumbnail"),
+            ResourceId::IptcDigest => write!(f, "IPTC Digest"),
+            ResourceId::Raw(val) => write!(f, "Unknown({:#06x})", val),
+        }
+    }
+}
+
+/// Photoshop image resource block
+/// * **Field**     | **Bytes** | **Description**
+/// * *Signature*   | 4         | `8BIM`
+/// * *ID*          | 2         | Big Endian resource identifier e.g. `0x0404` for IPTC-IIM
+/// * *Name*        | n         | Pascal string padded to an even length, usually empty
+/// * *Size*        | 4         | Big Endian size of the resource data
+/// * *Data*        | n         | Resource data padded to an even length
+#[derive(Debug, Clone, PartialEq)]
+pub struct Resource {
+    pub id: ResourceId,
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+impl Resource {
+    /// Get the resolution info if this is a `ResolutionInfo` resource
+    pub fn resolution(&self) -> Option<ResolutionInfo> {
+        match self.id {
+            ResourceId::ResolutionInfo => ResolutionInfo::parse(&self.data).ok(),
+            _ => None,
+        }
+    }
+
+    /// Get the MD5 digest of the IPTC-IIM record if this is an `IptcDigest` resource
+    pub fn iptc_digest(&self) -> Option<[u8; 16]> {
+        match self.id {
+            ResourceId::IptcDigest => self.data.as_slice().try_into().ok(),
+            _ => None,
+        }
+    }
+
+    /// Get the JPEG thumbnail if this is a `Thumbnail` or `ThumbnailBgr` resource
+    pub fn thumbnail(&self) -> Option<ResourceThumbnail> {
+        match self.id {
+            ResourceId::Thumbnail | ResourceId::ThumbnailBgr => {
+                ResourceThumbnail::parse(&self.data, self.id == ResourceId::ThumbnailBgr).ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the IPTC-IIM meta data if this is an `Iptc` resource
+    pub(crate) fn iptc(&self) -> Option<IptcResult<Iptc>> {
+        match self.id {
+            ResourceId::Iptc => Some(Iptc::parse(&self.data)),
+            _ => None,
+        }
+    }
+}
+
+/// Parse the given image resource blocks
+/// * Any trailing null padding after the last block is ignored
+/// * Parsing stops at the first invalid or truncated block keeping the blocks before it
+pub(crate) fn parse_resources(input: &[u8]) -> Vec<Resource> {
+    let mut resources = Vec::new();
+    let mut remain = input;
+    while !remain.iter().all(|x| *x == 0x00) {
+        match parse_resource(remain) {
+            Some((resource, next)) => {
+                resources.push(resource);
+                remain = next;
+            }
+            None => break,
+        }
+    }
+    resources
+}
+
+/// Parse the resource block at the start of the input returning it with the input that follows
+fn parse_resource(input: &[u8]) -> Option<(Resource, &[u8])> {
+    if !SIGNATURES.iter().any(|x| input.starts_with(x)) {
+        return None;
+    }
+    let id = input.get(4..6)?;
+    let id = u16::from_be_bytes([id[0], id[1]]);
+
+    // Name length byte plus the name padded to an even length
+    let length = *input.get(6)? as usize;
+    let name = input.get(7..7 + length)?;
+    let start = 6 + (length + 2) / 2 * 2;
+    let size = input.get(start..start + 4)?;
+    let size = u32::from_be_bytes([size[0], size[1], size[2], size[3]]) as usize;
+    let end = (start + 4).checked_add(size)?;
+    let data = input.get(start + 4..end)?;
+
+    let resource = Resource {
+        id: ResourceId::from(id),
+        name: name.iter().map(|x| *x as char).collect(),
+        data: data.to_vec(),
+    };
+
+    // Data is padded to an even length which writers occasionally drop on the last block
+    let next = input.get(end + size % 2..).unwrap_or_default();
+    Some((resource, next))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meta::{IptcField, IptcTag};
+
+    // Resources with an odd length name, an IPTC block and an unknown odd sized block
+    const RESOURCES: [u8; 68] = [
+        /* 000-003 */ 0x38, 0x42, 0x49, 0x4d, // Resource 0: signature
+        /* 004-005 */ 0x04, 0x25, // Resource 0: id (IPTC digest)
+        /* 006-009 */ 0x03, 0x6f, 0x64, 0x64, // Resource 0: name (odd)
+        /* 010-013 */ 0x00, 0x00, 0x00, 0x10, // Resource 0: size (16)
+        /* 014-021 */ 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, // Resource 0: data
+        /* 022-029 */ 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, //
+        //
+        /* 030-033 */ 0x38, 0x42, 0x49, 0x4d, // Resource 1: signature
+        /* 034-035 */ 0x04, 0x04, // Resource 1: id (IPTC)
+        /* 036-037 */ 0x00, 0x00, // Resource 1: name (empty), padding
+        /* 038-041 */ 0x00, 0x00, 0x00, 0x08, // Resource 1: size (8)
+        /* 042-046 */ 0x1c, 0x02, 0x78, 0x00, 0x03, // Resource 1: IPTC caption header
+        /* 047-049 */ 0x46, 0x6f, 0x67, // Resource 1: IPTC caption (Fog)
+        //
+        /* 050-053 */ 0x38, 0x42, 0x49, 0x4d, // Resource 2: signature
+        /* 054-055 */ 0x27, 0x10, // Resource 2: id (unknown)
+        /* 056-057 */ 0x00, 0x00, // Resource 2: name (empty), padding
+        /* 058-061 */ 0x00, 0x00, 0x00, 0x03, // Resource 2: size (3)
+        /* 062-064 */ 0x02, 0x02, 0x02, // Resource 2: data
+        /*     065 */ 0x00, // Resource 2: padding
+        //
+        /* 066-067 */ 0x00, 0x00, // Trailing padding
+    ];
+
+    #[test]
+    fn test_parse_resources() {
+        let resources = parse_resources(&RESOURCES);
+        assert_eq!(resources.len(), 3);
+        assert_eq!(resources[0].id, ResourceId::IptcDigest);
+        assert_eq!(resources[0].name, "odd");
+        assert_eq!(resources[0].iptc_digest(), Some([0x01; 16]));
+        assert_eq!(resources[1].iptc_digest(), None);
+        let iptc = resources[1].iptc().unwrap().unwrap();
+        assert_eq!(iptc.get_field(IptcTag::Caption), Some(IptcField::Caption("Fog".into())));
+        assert_eq!(resources[2].id, ResourceId::Raw(0x2710));
+        assert_eq!(resources[2].data, vec![0x02; 3]);
+        assert_eq!(resources[2].id.to_string(), "Unknown(0x2710)");
+        assert_eq!(u16::from(resources[1].id), 0x0404);
+        assert!(parse_resources(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_parse_resources_invalid() {
+        assert!(parse_resources(b"8BPS").is_empty());
+
+        // Blocks ahead of a truncated or invalid block are kept
+        let ids = |x: &[u8]| parse_resources(x).iter().map(|x| x.id).collect::<Vec<_>>();
+        assert_eq!(ids(&RESOURCES[..43]), vec![ResourceId::IptcDigest]);
+        assert_eq!(ids(&RESOURCES[..38]), vec![ResourceId::IptcDigest]);
+        let mut data = RESOURCES;
+        data[50] = b'X';
+        assert_eq!(ids(&data), vec![ResourceId::IptcDigest, ResourceId::Iptc]);
+
+        // Size far beyond the input
+        let mut data = RESOURCES;
+        data[58..62].copy_from_slice(&[0xFF; 4]);
+        assert_eq!(ids(&data[30..]), vec![ResourceId::Iptc]);
+
+        // Missing padding after the last block
+        assert_eq!(parse_resources(&RESOURCES[..65]).len(), 3);
+    }
+}
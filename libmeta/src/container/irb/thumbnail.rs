@@ -0,0 +1,94 @@
+use super::IrbResult;
+use crate::errors::IrbError;
+
+/// Thumbnail format of a JPEG compressed RGB thumbnail
+const JPEG_RGB: u32 = 1;
+
+/// Size of the thumbnail header ahead of the JPEG stream
+const HEADER_SIZE: usize = 28;
+
+/// JPEG thumbnail from the `Thumbnail` or `ThumbnailBgr` image resource
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceThumbnail {
+    pub width: u32,    // thumbnail width in pixels
+    pub height: u32,   // thumbnail height in pixels
+    pub bgr: bool,     // decoded color channels are stored as BGR rather than RGB
+    pub data: Vec<u8>, // complete JPEG stream
+}
+
+impl ResourceThumbnail {
+    /// Parse the given thumbnail resource data
+    /// * **bgr** flags the Photoshop 4.0 resource which stores the color channels as BGR
+    /// * **Field**          | **Bytes** | **Description**
+    /// * *Format*           | 4         | `1` = JPEG compressed RGB, `0` = raw RGB which is unsupported
+    /// * *Width*            | 4         | Width in pixels
+    /// * *Height*           | 4         | Height in pixels
+    /// * *Width bytes*      | 4         | Padded row bytes i.e. `(width * bits + 31) / 32 * 4`
+    /// * *Total size*       | 4         | Decompressed size i.e. `width bytes * height * planes`
+    /// * *Compressed size*  | 4         | Size of the JPEG stream
+    /// * *Bits per pixel*   | 2         | `24`
+    /// * *Planes*           | 2         | `1`
+    /// * *Data*             | n         | JPEG stream
+    pub(crate) fn parse(input: &[u8], bgr: bool) -> IrbResult<Self> {
+        let header = input
+            .get(..HEADER_SIZE)
+            .ok_or_else(|| IrbError::parse(": thumbnail header truncated"))?;
+        let u32 =
+            |i: usize| u32::from_be_bytes([header[i], header[i + 1], header[i + 2], header[i + 3]]);
+        if u32(0) != JPEG_RGB {
+            return Err(IrbError::parse(": thumbnail format unsupported").with_data(&header[..4]));
+        }
+        let data = HEADER_SIZE
+            .checked_add(u32(20) as usize)
+            .and_then(|end| input.get(HEADER_SIZE..end))
+            .ok_or_else(|| IrbError::parse(": thumbnail data truncated"))?;
+
+        Ok(Self { width: u32(4), height: u32(8), bgr, data: data.to_vec() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Thumbnail header ahead of a minimal JPEG stream
+    const THUMBNAIL: [u8; 32] = [
+        /* 000-003 */ 0x00, 0x00, 0x00, 0x01, // Format: JPEG compressed RGB
+        /* 004-007 */ 0x00, 0x00, 0x00, 0xa0, // Width (160)
+        /* 008-011 */ 0x00, 0x00, 0x00, 0x78, // Height (120)
+        /* 012-015 */ 0x00, 0x00, 0x01, 0xe0, // Width bytes (480)
+        /* 016-019 */ 0x00, 0x00, 0xe1, 0x00, // Total size (57600)
+        /* 020-023 */ 0x00, 0x00, 0x00, 0x04, // Compressed size (4)
+        /* 024-025 */ 0x00, 0x18, // Bits per pixel (24)
+        /* 026-027 */ 0x00, 0x01, // Planes (1)
+        /* 028-031 */ 0xff, 0xd8, 0xff, 0xd9, // JPEG stream
+    ];
+
+    #[test]
+    fn test_parse() {
+        let thumbnail = ResourceThumbnail::parse(&THUMBNAIL, true).unwrap();
+        assert_eq!(thumbnail.width, 160);
+        assert_eq!(thumbnail.height, 120);
+        assert!(thumbnail.bgr);
+        assert_eq!(thumbnail.data, vec![0xFF, 0xD8, 0xFF, 0xD9]);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let mut data = THUMBNAIL;
+        data[3] = 0x00;
+        let err = ResourceThumbnail::parse(&data, false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Photoshop resource parse failed: thumbnail format unsupported [00, 00, 00, 00]"
+        );
+        let err = ResourceThumbnail::parse(&THUMBNAIL[..30], false).unwrap_err();
+        assert_eq!(err.to_string(), "Photoshop resource parse failed: thumbnail data truncated");
+        let mut data = THUMBNAIL;
+        data[20..24].copy_from_slice(&[0xFF; 4]);
+        let err = ResourceThumbnail::parse(&data, false).unwrap_err();
+        assert_eq!(err.to_string(), "Photoshop resource parse failed: thumbnail data truncated");
+        let err = ResourceThumbnail::parse(&THUMBNAIL[..27], false).unwrap_err();
+        assert_eq!(err.to_string(), "Photoshop resource parse failed: thumbnail header truncated");
+    }
+}
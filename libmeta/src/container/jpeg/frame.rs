@@ -0,0 +1,134 @@
+use std::fmt::Display;
+
+use crate::errors::JpegError;
+
+/// JPEG encoding process signaled by the SOFn marker
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Encoding {
+    Baseline,    // SOF0 Baseline DCT
+    Extended,    // SOF1, SOF5, SOF9, SOF13 Extended sequential DCT
+    Progressive, // SOF2, SOF6, SOF10, SOF14 Progressive DCT
+    Lossless,    // SOF3, SOF7, SOF11, SOF15 Lossless sequential
+}
+
+impl Display for Encoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Encoding::Baseline => write!(f, "Baseline DCT"),
+            Encoding::Extended => write!(f, "Extended sequential DCT"),
+            Encoding::Progressive => write!(f, "Progressive DCT"),
+            Encoding::Lossless => write!(f, "Lossless"),
+        }
+    }
+}
+
+/// Frame component specification
+/// * **Field**           | **Bytes** | **Description**
+/// * *Id*                | 1     | Component identifier e.g. 1 = Y, 2 = Cb, 3 = Cr
+/// * *Sampling factors*  | 1     | Horizontal in the high nibble, vertical in the low nibble
+/// * *Quantization table*| 1     | Quantization table destination selector
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Component {
+    pub id: u8,
+    pub horizontal: u8,
+    pub vertical: u8,
+    pub quantization_table: u8,
+}
+
+/// Frame header from the JPEG SOFn segment
+/// * **Field**        | **Bytes** | **Description**
+/// * *Precision*      | 1     | Bits per sample
+/// * *Height*         | 2     | Number of lines, Big Endian
+/// * *Width*          | 2     | Samples per line, Big Endian
+/// * *Components*     | 1     | Number of image components in the frame
+/// * *Specifications* | 3 * N | Component specifications
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    pub encoding: Encoding,
+    pub arithmetic: bool,
+    pub differential: bool,
+    pub precision: u8,
+    pub height: u16,
+    pub width: u16,
+    pub components: Vec<Component>,
+}
+
+impl Frame {
+    /// Parse the given SOFn segment data using the marker to classify the encoding
+    /// * `0xC4`, `0xC8` and `0xCC` share the range but are not frame markers
+    pub(crate) fn parse(marker: &[u8; 2], data: &[u8]) -> Result<Self, JpegError> {
+        let encoding = match marker[1] & 0x03 {
+            0 if marker[1] == 0xC0 => Encoding::Baseline,
+            0 | 1 => Encoding::Extended,
+            2 => Encoding::Progressive,
+            _ => Encoding::Lossless,
+        };
+        if data.len() < 6 {
+            return Err(JpegError::parse(": frame header too short").with_data(data));
+        }
+        let count = data[5] as usize;
+        if data.len() < 6 + count * 3 {
+            return Err(JpegError::parse(": frame components truncated").with_data(data));
+        }
+        let components = data[6..6 + count * 3]
+            .chunks(3)
+            .map(|x| Component {
+                id: x[0],
+                horizontal: x[1] >> 4,
+                vertical: x[1] & 0x0F,
+                quantization_table: x[2],
+            })
+            .collect();
+
+        Ok(Self {
+            encoding,
+            arithmetic: marker[1] >= 0xC9,
+            differential: marker[1] & 0x04 != 0,
+            precision: data[0],
+            height: u16::from_be_bytes([data[1], data[2]]),
+            width: u16::from_be_bytes([data[3], data[4]]),
+            components,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_encoding() {
+        let data = [0x08, 0x00, 0x01, 0x00, 0x02, 0x01, 0x01, 0x21, 0x00];
+        let encoding = |x: u8| Frame::parse(&[0xFF, x], &data).unwrap();
+        assert_eq!(encoding(0xC0).encoding, Encoding::Baseline);
+        assert_eq!(encoding(0xC1).encoding, Encoding::Extended);
+        assert_eq!(encoding(0xC2).encoding, Encoding::Progressive);
+        assert_eq!(encoding(0xC3).encoding, Encoding::Lossless);
+        assert!(encoding(0xC6).differential);
+        assert!(!encoding(0xC2).arithmetic);
+        assert!(encoding(0xC9).arithmetic);
+        assert_eq!(encoding(0xC9).encoding, Encoding::Extended);
+        assert_eq!(encoding(0xCA).encoding, Encoding::Progressive);
+        assert_eq!(encoding(0xCF).encoding, Encoding::Lossless);
+        assert_eq!(
+            encoding(0xC0).components,
+            vec![Component { id: 1, horizontal: 2, vertical: 1, quantization_table: 0 }]
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            Frame::parse(&[0xFF, 0xC0], &[0x08, 0x00])
+                .unwrap_err()
+                .to_string(),
+            "JPEG parse failed: frame header too short [08, 00]"
+        );
+        assert_eq!(
+            Frame::parse(&[0xFF, 0xC0], &[0x08, 0x00, 0x01, 0x00, 0x01, 0x01, 0x01])
+                .unwrap_err()
+                .to_string(),
+            "JPEG parse failed: frame components truncated [08, 00, 01, 00, 01, 01, 01]"
+        );
+    }
+}
@@ -3,10 +3,11 @@ use std::{
     io::{self, prelude::*},
 };
 
-use super::{marker, segment::Segment};
+use super::{frame::Frame, marker, segment::Segment};
 use crate::{
+    container::irb::{self, Resource},
     errors::JpegError,
-    meta::{Exif, Jfif},
+    meta::{self, Exif, Iptc, Jfif, JfifThumbnail},
     slice,
 };
 
@@ -15,6 +16,7 @@ pub type JpegResult<T> = Result<T, JpegError>;
 
 #[derive(Debug)]
 pub struct Jpeg {
+    pub(crate) frame: Option<Frame>,
     pub(crate) segments: Vec<Segment>,
 }
 
@@ -35,7 +37,19 @@ impl Jpeg {
         // Parse out the segments
         let segments = parse_segments(&mut reader)?;
 
-        Ok(Jpeg { segments })
+        // Parse out the frame header from the first SOFn segment, a malformed one is dropped rather
+        // than failing the rest of the meta data
+        let frame = segments
+            .iter()
+            .find(|x| marker::is_sof(&x.marker))
+            .and_then(|x| Frame::parse(&x.marker, x.data.as_deref().unwrap_or_default()).ok());
+
+        Ok(Jpeg { frame, segments })
+    }
+
+    /// Get the frame header e.g. true dimensions, precision, sampling factors and encoding
+    pub fn frame(&self) -> Option<&Frame> {
+        self.frame.as_ref()
     }
 
     // /// Dump meta data segments from the given JPEG source for debugging purposes.
@@ -52,22 +66,76 @@ impl Jpeg {
     }
 
     /// Get the JFIF meta data from the parsed JPEG.
+    /// * APP0 is shared with JFXX extensions which are attached to the JFIF meta data
     pub(crate) fn jfif(&self) -> Option<JpegResult<Jfif>> {
-        match self.segments.iter().find(|x| x.marker == marker::APP0) {
-            Some(segment) => match segment.data.as_ref() {
-                Some(data) => Some(match Jfif::parse(data) {
-                    Ok(jfif) => Ok(jfif),
-                    Err(e) => Err(JpegError::parse(": jfif parsing").wrap(e)),
-                }),
-                _ => None,
+        let mut jfif = match self.app0_segments(false).next() {
+            Some(data) => match Jfif::parse(data) {
+                Ok(jfif) => jfif,
+                Err(e) => return Some(Err(JpegError::parse(": jfif parsing").wrap(e))),
             },
-            _ => None,
-        }
+            _ => return None,
+        };
+
+        // A malformed extension only loses its thumbnail rather than the JFIF meta data
+        jfif.extension = self
+            .app0_segments(true)
+            .next()
+            .and_then(|x| JfifThumbnail::parse(x).ok());
+        Some(Ok(jfif))
+    }
+
+    // Iterate over the APP0 segment data for either JFXX extensions or everything else
+    fn app0_segments(&self, jfxx: bool) -> impl Iterator<Item = &[u8]> {
+        self.segments
+            .iter()
+            .filter(|x| x.marker == marker::APP0)
+            .filter_map(|x| x.data.as_deref())
+            .filter(move |x| meta::is_jfxx(x) == jfxx)
+    }
+
+    /// Get the raw XMP packet from the APP1 segment with the XMP namespace identifier
+    /// * APP1 is shared with Exif so segments are recognized by identifier rather than order
+    pub fn xmp(&self) -> Option<&[u8]> {
+        self.segments
+            .iter()
+            .filter(|x| x.marker == marker::APP1)
+            .filter_map(|x| x.data.as_deref())
+            .find_map(|x| x.strip_prefix(meta::XMP_IDENTIFIER))
+    }
+
+    /// Iterate over the extended XMP chunks i.e. the APP1 payloads following the extension identifier
+    /// * Large packets are split across many segments which may arrive in any order
+    pub(crate) fn xmp_extension(&self) -> impl Iterator<Item = &[u8]> {
+        self.segments
+            .iter()
+            .filter(|x| x.marker == marker::APP1)
+            .filter_map(|x| x.data.as_deref())
+            .filter_map(|x| x.strip_prefix(meta::XMP_EXTENSION_IDENTIFIER))
+    }
+
+    /// Get the ICC profile reassembled from the APP2 chunks following the ICC identifier
+    /// * Large profiles are split across many segments which carry their sequence number
+    pub(crate) fn icc_profile(&self) -> Option<JpegResult<Vec<u8>>> {
+        let mut chunks = self
+            .segments
+            .iter()
+            .filter(|x| x.marker == marker::APP2)
+            .filter_map(|x| x.data.as_deref())
+            .filter_map(|x| x.strip_prefix(meta::ICC_IDENTIFIER))
+            .peekable();
+        chunks.peek()?;
+        Some(
+            meta::reassemble_profile(chunks)
+                .map_err(|e| JpegError::parse(": icc profile reassembly").wrap(e)),
+        )
     }
 
     /// Get the Exif meta data from the parsed JPEG.
+    /// * APP1 is shared with XMP so only segments with the Exif identifier are considered
     pub(crate) fn exif(&self) -> Option<JpegResult<Exif>> {
-        match self.segments.iter().find(|x| x.marker == marker::APP1) {
+        match self.segments.iter().find(|x| {
+            x.marker == marker::APP1 && x.data.as_ref().is_some_and(|x| x.starts_with(b"Exif\0\0"))
+        }) {
             Some(segment) => match segment.data.as_ref() {
                 Some(data) => Some(match Exif::parse(data) {
                     Ok(exif) => Ok(exif),
@@ -78,6 +146,31 @@ impl Jpeg {
             None => None,
         }
     }
+
+    /// Get the Photoshop image resource blocks from the APP13 segments
+    /// * APP13 is split into many segments when the resource blocks are large so the payloads
+    ///   following the Photoshop identifier are joined before parsing
+    /// * Blocks following a damaged block are dropped
+    pub fn resources(&self) -> Option<Vec<Resource>> {
+        let mut segments = self
+            .segments
+            .iter()
+            .filter(|x| x.marker == marker::APP13)
+            .filter_map(|x| x.data.as_deref())
+            .filter_map(|x| x.strip_prefix(irb::PHOTOSHOP_IDENTIFIER))
+            .peekable();
+        segments.peek()?;
+        let data: Vec<u8> = segments.flatten().copied().collect();
+        Some(irb::parse_resources(&data))
+    }
+
+    /// Get the IPTC-IIM meta data from the Photoshop image resource blocks
+    pub(crate) fn iptc(&self) -> Option<JpegResult<Iptc>> {
+        self.resources()?
+            .iter()
+            .find_map(|x| x.iptc())
+            .map(|x| x.map_err(|e| JpegError::parse(": iptc parsing").wrap(e)))
+    }
 }
 
 impl Display for Jpeg {
@@ -89,11 +182,15 @@ impl Display for Jpeg {
     }
 }
 
-/// Parse out all the meta data related segments for the given JPEG source.
+/// Parse out all the segments for the given JPEG source up to and including the start of scan.
 /// A segment has the following structure left to right:
 /// * (1 byte)  Marker prefix e.g `0xFF`
 /// * (1 byte)  Marker Number e.g. `0xE0`
 /// * (2 bytes) Data size, including 2 size bytes, in Big Endian e.g. e.g 0x00 0x10 = 14 bytes
+///
+/// Any number of `0xFF` fill bytes may precede a marker and standalone markers e.g. `RST0-7` carry
+/// no length or data so are skipped. Duplicate markers are all kept in order so that payloads split
+/// across multiple segments e.g. ICC profiles can be reassembled.
 fn parse_segments(mut reader: impl io::BufRead) -> JpegResult<Vec<Segment>> {
     let mut segments = Vec::new();
 
@@ -105,32 +202,40 @@ fn parse_segments(mut reader: impl io::BufRead) -> JpegResult<Vec<Segment>> {
             break;
         }
 
-        // Read out the segment marker
-        let marker = slice::read_u8(&mut reader)
-            .map(|v| [0xFF, v])
-            .map_err(|e| JpegError::read_failed(": segment marker").with_io_source(e))?;
-
-        match marker {
-            // Parse meta data related segments
-            marker::APP0 | marker::APP1 => {
-                // Parse out a JPEG segment length, 2 bytes in Big Endian format including
-                // 2 size bytes. Thus a length of `0x00 0x10` would be length 14 not 16.
-                let len = slice::read_be_u16(&mut reader)
-                    .map_err(|e| JpegError::read_failed(": segment length").with_io_source(e))?;
-                if len < 2 {
-                    return Err(JpegError::parse(": segment length too short"));
-                }
-                let len = len - 2;
-
-                // Parse out the segment data
-                let data = slice::read_bytes(&mut reader, len as usize)
-                    .map_err(|e| JpegError::read_failed(": segment data").with_io_source(e))?;
-
-                segments.push(Segment::new(marker, len, Some(data)));
+        // Read out the segment marker skipping any fill bytes
+        let mut value = marker::PREFIX;
+        while value == marker::PREFIX {
+            value = slice::read_u8(&mut reader)
+                .map_err(|e| JpegError::read_failed(": segment marker").with_io_source(e))?;
+        }
+        let marker = [marker::PREFIX, value];
+
+        // Stuffed bytes and standalone markers have no length or data
+        if value == marker::STUFFED || marker::is_standalone(&marker) {
+            if marker == marker::EOI {
+                break;
             }
+            continue;
+        }
+
+        // Parse out a JPEG segment length, 2 bytes in Big Endian format including
+        // 2 size bytes. Thus a length of `0x00 0x10` would be length 14 not 16.
+        let len = slice::read_be_u16(&mut reader)
+            .map_err(|e| JpegError::read_failed(": segment length").with_io_source(e))?;
+        if len < 2 {
+            return Err(JpegError::parse(": segment length too short"));
+        }
+        let len = len - 2;
+
+        // Parse out the segment data
+        let data = slice::read_bytes(&mut reader, len as usize)
+            .map_err(|e| JpegError::read_failed(": segment data").with_io_source(e))?;
+
+        segments.push(Segment::new(marker, len, Some(data)));
 
-            // Stop when we hit a non meta data marker
-            _ => break,
+        // Entropy coded image data follows the start of scan
+        if marker == marker::SOS {
+            break;
         }
     }
 
@@ -145,9 +250,10 @@ fn parse_segments(mut reader: impl io::BufRead) -> JpegResult<Vec<Segment>> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::container::JPEG_TEST_DATA;
+    use crate::container::{Encoding, ResourceId, JPEG_TEST_DATA};
     use crate::errors::BaseError;
     use crate::meta::jfif::DensityUnit;
+    use crate::meta::{IptcField, IptcTag};
 
     #[test]
     fn test_parse() {
@@ -164,6 +270,16 @@ mod tests {
         assert_eq!(jfif.x_dimension, 0);
         assert_eq!(jfif.y_dimension, 0);
 
+        // Validate frame header
+        let frame = jpeg.frame().unwrap();
+        assert_eq!(frame.encoding, Encoding::Baseline);
+        assert_eq!(frame.precision, 8);
+        assert_eq!(frame.width, 15);
+        assert_eq!(frame.height, 7);
+        assert_eq!(frame.components.len(), 3);
+        assert_eq!(frame.components[0].horizontal, 1);
+        assert_eq!(frame.components[2].quantization_table, 1);
+
         // Exif
         // let exif = exif.unwrap();
         // assert_eq!(exif.is_big_endian(), true);
@@ -172,10 +288,22 @@ mod tests {
         //assert_eq!(err_to_string(&err), "");
     }
 
+    #[test]
+    fn test_xmp_segment_ahead_of_exif() {
+        let mut data = vec![0xFF, 0xD8, 0xFF, 0xE1, 0x00, 0x2B];
+        data.extend(meta::XMP_IDENTIFIER);
+        data.extend(b"<x:xmpmeta/>");
+        data.extend(&JPEG_TEST_DATA[2..]);
+
+        let jpeg = Jpeg::parse(io::Cursor::new(data)).unwrap();
+        assert_eq!(jpeg.xmp(), Some(&b"<x:xmpmeta/>"[..]));
+        assert!(jpeg.exif().unwrap().is_ok());
+    }
+
     #[test]
     fn test_parse_exif_success() {
         let segments = parse_segments(&JPEG_TEST_DATA[20..]).unwrap();
-        assert_eq!(segments.len(), 1);
+        assert_eq!(segments.len(), 10);
         assert_eq!(segments[0].marker, marker::APP1);
         assert_eq!(segments[0].length, 860);
         assert_eq!(segments[0].data.as_ref().unwrap().len(), 860);
@@ -218,7 +346,162 @@ mod tests {
     #[test]
     fn test_parse_segments() {
         let segments = parse_segments(&mut &JPEG_TEST_DATA[2..]).unwrap();
-        assert_eq!(segments.len(), 2);
+        let markers = segments.iter().map(|x| x.marker).collect::<Vec<_>>();
+        assert_eq!(
+            markers,
+            vec![
+                marker::APP0,
+                marker::APP1,
+                marker::DQT,
+                marker::DQT,
+                marker::SOF,
+                marker::DHT,
+                marker::DHT,
+                marker::DHT,
+                marker::DHT,
+                marker::DRI,
+                marker::SOS,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_segments_fill_bytes_and_standalone_markers() {
+        let data = [
+            0xFF, 0xFF, 0xFF, 0xE2, 0x00, 0x04, 0x01, 0x02, // APP2 with fill bytes
+            0xFF, 0xD0, 0xFF, 0x01, // RST0 and TEM standalone markers
+            0xFF, 0xE1, 0x00, 0x03, 0x03, // APP1
+            0xFF, 0xE2, 0x00, 0x03, 0x04, // Duplicate APP2
+            0xFF, 0xDA, 0x00, 0x02, // SOS
+            0xFF, 0xE1, 0x00, 0x03, 0x05, // Entropy coded data is never parsed
+        ];
+        let segments = parse_segments(&mut &data[..]).unwrap();
+        assert_eq!(
+            segments,
+            vec![
+                Segment::new(marker::APP2, 2, Some(vec![0x01, 0x02])),
+                Segment::new(marker::APP1, 1, Some(vec![0x03])),
+                Segment::new(marker::APP2, 1, Some(vec![0x04])),
+                Segment::new(marker::SOS, 0, Some(vec![])),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_segments_stops_at_eoi() {
+        let data = [
+            0xFF, 0xFE, 0x00, 0x03, 0x41, 0xFF, 0xD9, 0xFF, 0xFE, 0x00, 0x03, 0x42,
+        ];
+        let segments = parse_segments(&mut &data[..]).unwrap();
+        assert_eq!(segments, vec![Segment::new(marker::COM, 1, Some(vec![0x41]))]);
+    }
+
+    #[test]
+    fn test_parse_malformed_frame_header() {
+        let mut data = marker::HEADER.to_vec();
+        data.extend_from_slice(&[0xFF, 0xC0, 0x00, 0x03, 0x08, 0xFF, 0xDA, 0x00, 0x02]);
+        let jpeg = Jpeg::parse(&mut &data[..]).unwrap();
+        assert_eq!(jpeg.frame(), None);
+        assert_eq!(jpeg.segments.len(), 2);
+    }
+
+    #[test]
+    fn test_jfif_with_jfxx_extension() {
+        let mut data = JPEG_TEST_DATA[..20].to_vec();
+        data.extend_from_slice(&[0xFF, 0xE0, 0x00, 0x0D, 0x4A, 0x46, 0x58, 0x58, 0x00, 0x13]);
+        data.extend_from_slice(&[0x01, 0x01, 0x0A, 0x0B, 0x0C]);
+        let jpeg = Jpeg::parse(&mut &data[..]).unwrap();
+        let jfif = jpeg.jfif().unwrap().unwrap();
+        assert_eq!(jfif.major, 1);
+        assert_eq!(
+            jfif.thumbnail(),
+            Some(JfifThumbnail::Rgb { width: 1, height: 1, data: vec![0x0A, 0x0B, 0x0C] })
+        );
+    }
+
+    #[test]
+    fn test_jfif_with_malformed_jfxx_extension() {
+        let mut data = JPEG_TEST_DATA[..20].to_vec();
+        data.extend_from_slice(&[0xFF, 0xE0, 0x00, 0x0D, 0x4A, 0x46, 0x58, 0x58, 0x00, 0x13]);
+        data.extend_from_slice(&[0x02, 0x02, 0x0A, 0x0B, 0x0C]);
+        let jpeg = Jpeg::parse(&mut &data[..]).unwrap();
+        let jfif = jpeg.jfif().unwrap().unwrap();
+        assert_eq!(jfif.major, 1);
+        assert_eq!(jfif.thumbnail(), None);
+    }
+
+    #[test]
+    fn test_exif_skips_non_exif_app1() {
+        let mut data = marker::HEADER.to_vec();
+        data.extend_from_slice(&[0xFF, 0xE1, 0x00, 0x05, b'x', b'm', b'p']);
+        data.extend_from_slice(&JPEG_TEST_DATA[20..]);
+        let jpeg = Jpeg::parse(&mut &data[..]).unwrap();
+        assert!(jpeg.exif().unwrap().is_ok());
+    }
+
+    #[test]
+    fn test_iptc_split_across_app13_segments() {
+        let iim = b"\x1C\x02\x78\x00\x03Fog\x1C\x02\x5A\x00\x04Oslo";
+        let mut resource = b"8BIM\x04\x04\x00\x00".to_vec();
+        resource.extend((iim.len() as u32).to_be_bytes());
+        resource.extend(iim);
+
+        let mut data = marker::HEADER.to_vec();
+        for part in resource.chunks(16) {
+            let mut payload = irb::PHOTOSHOP_IDENTIFIER.to_vec();
+            payload.extend(part);
+            data.extend(marker::APP13);
+            data.extend((payload.len() as u16 + 2).to_be_bytes());
+            data.extend(payload);
+        }
+        data.extend_from_slice(&JPEG_TEST_DATA[20..]);
+
+        let jpeg = Jpeg::parse(&mut &data[..]).unwrap();
+        let resources = jpeg.resources().unwrap();
+        assert_eq!(resources.len(), 1);
+        assert_eq!(resources[0].id, ResourceId::Iptc);
+        assert_eq!(resources[0].data, iim);
+        let iptc = jpeg.iptc().unwrap().unwrap();
+        assert_eq!(iptc.get_field(IptcTag::Caption), Some(IptcField::Caption("Fog".into())));
+        assert_eq!(iptc.get_field(IptcTag::City), Some(IptcField::City("Oslo".into())));
+
+        // Broken resource blocks are dropped
+        data[20] = b'X';
+        let jpeg = Jpeg::parse(&mut &data[..]).unwrap();
+        assert_eq!(jpeg.resources(), Some(vec![]));
+        assert!(jpeg.iptc().is_none());
+        let jpeg = Jpeg::parse(&mut &JPEG_TEST_DATA[..]).unwrap();
+        assert!(jpeg.resources().is_none());
+        assert!(jpeg.iptc().is_none());
+    }
+
+    #[test]
+    fn test_icc_profile_split_across_app2_segments() {
+        let app2 = |chunk: &[u8]| {
+            let mut segment = marker::APP2.to_vec();
+            segment
+                .extend((meta::ICC_IDENTIFIER.len() as u16 + chunk.len() as u16 + 2).to_be_bytes());
+            segment.extend(meta::ICC_IDENTIFIER);
+            segment.extend(chunk);
+            segment
+        };
+        let mut data = marker::HEADER.to_vec();
+        data.extend(app2(&[2, 2, 0x03, 0x04]));
+        data.extend(app2(&[1, 2, 0x01, 0x02]));
+        data.extend_from_slice(&JPEG_TEST_DATA[20..]);
+
+        let jpeg = Jpeg::parse(&mut &data[..]).unwrap();
+        assert_eq!(jpeg.icc_profile().unwrap().unwrap(), vec![0x01, 0x02, 0x03, 0x04]);
+
+        // Missing chunks fail rather than producing a partial profile
+        let mut data = marker::HEADER.to_vec();
+        data.extend(app2(&[2, 2, 0x03, 0x04]));
+        data.extend_from_slice(&JPEG_TEST_DATA[20..]);
+        let jpeg = Jpeg::parse(&mut &data[..]).unwrap();
+        let err = jpeg.icc_profile().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "JPEG parse failed: icc profile reassembly");
+        let jpeg = Jpeg::parse(&mut &JPEG_TEST_DATA[..]).unwrap();
+        assert!(jpeg.icc_profile().is_none());
     }
 
     #[test]
@@ -1,29 +1,51 @@
 // JPEG Markers
 pub(crate) const PREFIX: u8 = 0xFF; // JPEG marker prefix
+pub(crate) const STUFFED: u8 = 0x00; // Escaped 0xFF byte in entropy coded data
+pub(crate) const TEM: [u8; 2] = [0xFF, 0x01]; // Temporary private use in arithmetic coding
 pub(crate) const HEADER: [u8; 2] = [0xFF, 0xD8]; // Start of any JPEG file
 pub(crate) const SOF: [u8; 2] = [0xFF, 0xC0]; // Start of frame
 pub(crate) const DHT: [u8; 2] = [0xFF, 0xC4]; // Define Huffman Table
+pub(crate) const RST0: [u8; 2] = [0xFF, 0xD0]; // First restart marker
+pub(crate) const RST7: [u8; 2] = [0xFF, 0xD7]; // Last restart marker
 pub(crate) const EOI: [u8; 2] = [0xFF, 0xD9]; // End of image data
 pub(crate) const SOS: [u8; 2] = [0xFF, 0xDA]; // Start of scan i.e. start of image data
 pub(crate) const DQT: [u8; 2] = [0xFF, 0xDB]; // Define Quantinization Table
 pub(crate) const DRI: [u8; 2] = [0xFF, 0xDD]; // Define restart interval
 pub(crate) const APP0: [u8; 2] = [0xFF, 0xE0]; // JFIF marker segment
 pub(crate) const APP1: [u8; 2] = [0xFF, 0xE1]; // Exif marker segment
-pub(crate) const APP2: [u8; 2] = [0xFF, 0xE2]; // CIFF Canon Camera Image File Format
+pub(crate) const APP2: [u8; 2] = [0xFF, 0xE2]; // ICC profile chunks
 pub(crate) const APP8: [u8; 2] = [0xFF, 0xE8]; // SPIFF Still Picture Interchange File Format
+pub(crate) const APP13: [u8; 2] = [0xFF, 0xED]; // Photoshop Image Resource Blocks
+pub(crate) const COM: [u8; 2] = [0xFF, 0xFE]; // Comment
+
+/// Frame markers span `SOF0-15` excluding `DHT`, `JPG` and `DAC` which share the range
+pub(crate) fn is_sof(marker: &[u8; 2]) -> bool {
+    matches!(marker[1], 0xC0..=0xCF) && !matches!(marker[1], 0xC4 | 0xC8 | 0xCC)
+}
+
+/// Standalone markers carry no length or data e.g. `SOI`, `EOI`, `TEM` and `RST0-7`
+pub(crate) fn is_standalone(marker: &[u8; 2]) -> bool {
+    matches!(marker, &TEM | &HEADER | &EOI) || (RST0[1]..=RST7[1]).contains(&marker[1])
+}
 
 pub(crate) fn to_string(marker: &[u8; 2]) -> String {
     match marker {
+        &TEM => "Temporary Marker".to_string(),
+        &HEADER => "Start of Image".to_string(),
         &SOF => "Start of Frame".to_string(),
+        x if is_sof(x) => "Start of Frame".to_string(),
         &DHT => "Define Huffman Table".to_string(),
+        &[0xFF, 0xD0..=0xD7] => "Restart Marker".to_string(),
         &EOI => "End of Image Data".to_string(),
         &SOS => "Start of Scan".to_string(),
         &DQT => "Define Quantinization Table".to_string(),
         &DRI => "Define Restart Interval".to_string(),
         &APP0 => "JFIF Marker Segment".to_string(),
         &APP1 => "Exif Marker Segment".to_string(),
-        &APP2 => "CIFF Canon Camera Image File Format".to_string(),
+        &APP2 => "ICC Profile".to_string(),
         &APP8 => "SPIFF Still Picture Interchange File Format".to_string(),
+        &APP13 => "Photoshop Image Resource Blocks".to_string(),
+        &COM => "Comment".to_string(),
         _ => "Unknown marker".to_string(),
     }
 }
@@ -1,11 +1,13 @@
 // JPEG's are constructed using `Markers`. Markers are a binary formatted value used to mark a segment
 // of the file for a specific purpose e.g. start of the image data, end of the image data, app specific
 // segments etc...
+mod frame;
 mod jpeg;
 mod marker;
 mod segment;
 mod test_data;
 
+pub use frame::{Component, Encoding, Frame};
 pub use jpeg::*;
 
 // Expose testing data to other modules
@@ -1,9 +1,31 @@
 mod container;
+mod gif;
+mod heif;
+mod irb;
 mod jpeg;
+mod png;
+mod tiff;
+mod webp;
 
-pub(crate) use container::Container;
-pub use jpeg::Jpeg;
+pub use container::Container;
+pub use gif::Gif;
+pub use heif::{Heif, MirrorAxis};
+pub use irb::{ResolutionInfo, Resource, ResourceId, ResourceThumbnail};
+pub use jpeg::{Component, Encoding, Frame, Jpeg};
+pub use png::{Png, PngHeader, Text};
+pub use tiff::Tiff;
+pub use webp::WebP;
 
 // Expose testing data to other modules
 #[cfg(test)]
+pub(crate) use gif::GIF_TEST_DATA;
+#[cfg(test)]
+pub(crate) use heif::HEIF_TEST_DATA;
+#[cfg(test)]
 pub(crate) use jpeg::JPEG_TEST_DATA;
+#[cfg(test)]
+pub(crate) use png::PNG_TEST_DATA;
+#[cfg(test)]
+pub(crate) use tiff::TIFF_TEST_DATA;
+#[cfg(test)]
+pub(crate) use webp::WEBP_TEST_DATA;
@@ -0,0 +1,119 @@
+use miniz_oxide::inflate;
+
+use crate::errors::PngError;
+
+pub(crate) const SIGNATURE: [u8; 8] = [0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A];
+pub(crate) const IHDR: [u8; 4] = *b"IHDR"; // Image header, always the first chunk
+pub(crate) const IEND: [u8; 4] = *b"IEND"; // Image trailer, always the last chunk
+pub(crate) const EXIF: [u8; 4] = *b"eXIf"; // Exif data starting with the TIFF header
+pub(crate) const ICCP: [u8; 4] = *b"iCCP"; // Embedded ICC profile
+pub(crate) const TEXT: [u8; 4] = *b"tEXt"; // Latin-1 text
+pub(crate) const ZTXT: [u8; 4] = *b"zTXt"; // Compressed Latin-1 text
+pub(crate) const ITXT: [u8; 4] = *b"iTXt"; // International i.e. UTF-8 text, optionally compressed
+
+/// Largest chunk length allowed by the PNG specification i.e. 2^31 - 1
+pub(crate) const MAX_LENGTH: u32 = 0x7FFF_FFFF;
+
+/// Upper bound on decompressed chunk data to guard against decompression bombs
+const MAX_INFLATED: usize = 16 * 1024 * 1024;
+
+// PNG chunks are defined by their type and the data they contain
+#[derive(Debug, PartialEq)]
+pub(crate) struct Chunk {
+    pub(crate) kind: [u8; 4], // PNG chunk type
+    pub(crate) data: Vec<u8>, // PNG chunk data
+}
+
+impl Chunk {
+    pub(crate) fn new(kind: [u8; 4], data: Vec<u8>) -> Self {
+        Self { kind, data }
+    }
+
+    /// Is this a chunk that carries meta data
+    pub(crate) fn is_meta(kind: &[u8; 4]) -> bool {
+        matches!(*kind, IHDR | EXIF | ICCP | TEXT | ZTXT | ITXT)
+    }
+}
+
+/// CRC lookup table for the PNG polynomial 0xEDB88320
+const CRC_TABLE: [u32; 256] = crc_table();
+
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut n = 0;
+    while n < 256 {
+        let mut c = n as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 == 1 {
+                0xEDB8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[n] = c;
+        n += 1;
+    }
+    table
+}
+
+/// Calculate the CRC for the given chunk type and data
+pub(crate) fn crc32(kind: &[u8], data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for byte in kind.iter().chain(data) {
+        crc = CRC_TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8);
+    }
+    crc ^ 0xFFFF_FFFF
+}
+
+/// Split the given data at the first null separator
+/// * Returns: (bytes before the null, bytes after the null)
+pub(crate) fn split_null<'a>(data: &'a [u8], what: &str) -> Result<(&'a [u8], &'a [u8]), PngError> {
+    match data.iter().position(|x| *x == 0x00) {
+        Some(i) => Ok((&data[..i], &data[i + 1..])),
+        None => Err(PngError::parse(format!(": {} missing null separator", what))),
+    }
+}
+
+/// Convert the given Latin-1 bytes into a string
+pub(crate) fn latin1(data: &[u8]) -> String {
+    data.iter().map(|x| *x as char).collect()
+}
+
+/// Decompress the given zlib data, only compression method 0 i.e. deflate is defined
+pub(crate) fn inflate(method: u8, data: &[u8]) -> Result<Vec<u8>, PngError> {
+    if method != 0 {
+        return Err(PngError::parse(": unknown compression method").with_data(&[method]));
+    }
+    inflate::decompress_to_vec_zlib_with_limit(data, MAX_INFLATED)
+        .map_err(|e| PngError::parse(format!(": decompression failed {:?}", e.status)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(&IEND, &[]), 0xAE42_6082);
+        assert_eq!(crc32(b"", b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_split_null() {
+        assert_eq!(split_null(b"Title\0Text", "keyword").unwrap(), (&b"Title"[..], &b"Text"[..]));
+        assert_eq!(
+            split_null(b"Title", "keyword").unwrap_err().to_string(),
+            "PNG parse failed: keyword missing null separator"
+        );
+    }
+
+    #[test]
+    fn test_inflate_unknown_method() {
+        assert_eq!(
+            inflate(1, &[]).unwrap_err().to_string(),
+            "PNG parse failed: unknown compression method [01]"
+        );
+    }
+}
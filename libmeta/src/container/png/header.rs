@@ -0,0 +1,58 @@
+use crate::errors::PngError;
+
+/// Image header from the PNG IHDR chunk
+/// * **Field**        | **Bytes** | **Description**
+/// * *Width*          | 4     | Image width in pixels, Big Endian
+/// * *Height*         | 4     | Image height in pixels, Big Endian
+/// * *Bit depth*      | 1     | Bits per sample or per palette index
+/// * *Color type*     | 1     | 0 = Grayscale, 2 = RGB, 3 = Palette, 4 = Grayscale + Alpha, 6 = RGBA
+/// * *Compression*    | 1     | Always 0
+/// * *Filter*         | 1     | Always 0
+/// * *Interlace*      | 1     | 0 = None, 1 = Adam7
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PngHeader {
+    pub width: u32,
+    pub height: u32,
+    pub bit_depth: u8,
+    pub color_type: u8,
+    pub interlaced: bool,
+}
+
+impl PngHeader {
+    /// Parse the given IHDR chunk data
+    pub(crate) fn parse(data: &[u8]) -> Result<Self, PngError> {
+        if data.len() != 13 {
+            return Err(PngError::parse(": IHDR length invalid"));
+        }
+        let width = u32::from_be_bytes(data[0..4].try_into().unwrap());
+        let height = u32::from_be_bytes(data[4..8].try_into().unwrap());
+        if width == 0 || height == 0 {
+            return Err(PngError::parse(": IHDR dimensions are zero").with_data(&data[0..8]));
+        }
+
+        Ok(Self {
+            width,
+            height,
+            bit_depth: data[8],
+            color_type: data[9],
+            interlaced: data[12] == 1,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            PngHeader::parse(&[0x00; 12]).unwrap_err().to_string(),
+            "PNG parse failed: IHDR length invalid"
+        );
+        assert_eq!(
+            PngHeader::parse(&[0x00; 13]).unwrap_err().to_string(),
+            "PNG parse failed: IHDR dimensions are zero [00, 00, 00, 00, 00, 00, 00, 00]"
+        );
+    }
+}
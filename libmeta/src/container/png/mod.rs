@@ -0,0 +1,15 @@
+// PNG's are constructed from an 8 byte signature followed by `Chunks`. Each chunk is made up of a
+// 4 byte length, a 4 byte type, the chunk data and a CRC calculated over the type and data.
+mod chunk;
+mod header;
+mod png;
+mod test_data;
+mod text;
+
+pub use header::*;
+pub use png::*;
+pub use text::*;
+
+// Expose testing data to other modules
+#[cfg(test)]
+pub(crate) use test_data::PNG_TEST_DATA;
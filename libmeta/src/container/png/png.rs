@@ -0,0 +1,261 @@
+use std::{
+    fmt::Display,
+    io::{self, prelude::*},
+};
+
+use super::{
+    chunk::{self, Chunk},
+    PngHeader, Text,
+};
+use crate::{errors::PngError, meta::Exif, slice};
+
+/// Simplify the Png return type slightly
+pub type PngResult<T> = Result<T, PngError>;
+
+#[derive(Debug)]
+pub struct Png {
+    pub(crate) header: PngHeader,
+    pub(crate) text: Vec<Text>,
+    pub(crate) icc_profile_name: Option<String>,
+    pub(crate) chunks: Vec<Chunk>,
+}
+
+impl Png {
+    /// Parse all meta data from the given PNG source.
+    pub fn parse<T: io::BufRead>(mut reader: T) -> PngResult<Self> {
+        // Check the header to determine the media type
+        let mut header = Vec::new();
+        reader
+            .by_ref()
+            .take(chunk::SIGNATURE.len() as u64)
+            .read_to_end(&mut header)
+            .map_err(|x| PngError::read_failed(": invalid header").with_io_source(x))?;
+        if !Self::is_png(&header) {
+            return Err(PngError::parse(": invalid header").with_data(&header));
+        }
+
+        // Parse out the chunks
+        let chunks = parse_chunks(&mut reader)?;
+
+        // IHDR is required to be the first chunk
+        let header = match chunks.first() {
+            Some(chunk) if chunk.kind == chunk::IHDR => PngHeader::parse(&chunk.data)?,
+            _ => return Err(PngError::parse(": IHDR must be the first chunk")),
+        };
+
+        // Decode text chunks, skipping any that are malformed rather than losing the rest
+        let text = chunks
+            .iter()
+            .filter(|x| matches!(x.kind, chunk::TEXT | chunk::ZTXT | chunk::ITXT))
+            .filter_map(|x| Text::parse(x.kind, &x.data).ok())
+            .collect();
+
+        // ICC profile name i.e. the Latin-1 keyword before the compressed profile, a malformed
+        // iCCP chunk is left to fail when the profile itself is decoded
+        let icc_profile_name = chunks
+            .iter()
+            .find(|x| x.kind == chunk::ICCP)
+            .and_then(|x| chunk::split_null(&x.data, "profile name").ok())
+            .map(|(name, _)| chunk::latin1(name));
+
+        Ok(Png { header, text, icc_profile_name, chunks })
+    }
+
+    // Determine if the given header is from a png source
+    pub(crate) fn is_png(header: &[u8]) -> bool {
+        header.starts_with(&chunk::SIGNATURE)
+    }
+
+    /// Get the image header i.e. dimensions, bit depth and color type
+    pub fn header(&self) -> &PngHeader {
+        &self.header
+    }
+
+    /// Get the text meta data from the tEXt, zTXt and iTXt chunks in file order
+    pub fn text(&self) -> &[Text] {
+        &self.text
+    }
+
+    /// Get the name of the embedded ICC profile if it exists
+    pub fn icc_profile_name(&self) -> Option<&str> {
+        self.icc_profile_name.as_deref()
+    }
+
+    /// Get the ICC profile decompressed from the iCCP chunk
+    pub(crate) fn icc_profile(&self) -> Option<PngResult<Vec<u8>>> {
+        let chunk = self.chunks.iter().find(|x| x.kind == chunk::ICCP)?;
+        let profile = chunk::split_null(&chunk.data, "profile name").and_then(|(_, x)| match x {
+            [method, data @ ..] => chunk::inflate(*method, data),
+            _ => Err(PngError::parse(": compression method missing")),
+        });
+        Some(profile)
+    }
+
+    /// Get the Exif meta data from the parsed PNG.
+    /// * eXIf data starts directly with the TIFF header, however some writers include the JPEG
+    ///   APP1 `Exif\0\0` identifier as well so it is skipped when found.
+    pub(crate) fn exif(&self) -> Option<PngResult<Exif>> {
+        self.chunks
+            .iter()
+            .find(|x| x.kind == chunk::EXIF)
+            .map(|chunk| {
+                match chunk.data.starts_with(b"Exif\0\0") {
+                    true => Exif::parse(&chunk.data),
+                    false => Exif::parse_tiff(&chunk.data),
+                }
+                .map_err(|e| PngError::parse(": exif parsing").wrap(e))
+            })
+    }
+}
+
+impl Display for Png {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for chunk in self.chunks.iter() {
+            writeln!(f, "{}: {} bytes", chunk::latin1(&chunk.kind), chunk.data.len())?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse out all the meta data related chunks for the given PNG source.
+/// Only meta data chunks are read and CRC checked, image data is skipped without buffering.
+/// A chunk has the following structure left to right:
+/// * (4 bytes) Data length in Big Endian, not including the length, type or CRC
+/// * (4 bytes) Chunk type e.g. `IHDR`
+/// * (n bytes) Chunk data
+/// * (4 bytes) CRC of the chunk type and data in Big Endian
+fn parse_chunks(mut reader: impl io::BufRead) -> PngResult<Vec<Chunk>> {
+    let mut chunks = Vec::new();
+
+    loop {
+        let len = slice::read_be_u32(&mut reader)
+            .map_err(|e| PngError::read_failed(": chunk length").with_io_source(e))?;
+        if len > chunk::MAX_LENGTH {
+            return Err(PngError::parse(": chunk length too long").with_data(&len.to_be_bytes()));
+        }
+        let kind: [u8; 4] = slice::read_bytes(&mut reader, 4)
+            .map_err(|e| PngError::read_failed(": chunk type").with_io_source(e))?
+            .try_into()
+            .unwrap();
+        if kind == chunk::IEND {
+            break;
+        }
+        if !Chunk::is_meta(&kind) {
+            slice::skip_bytes(&mut reader, len as u64 + 4)
+                .map_err(|e| PngError::read_failed(": chunk data").with_io_source(e))?;
+            continue;
+        }
+
+        let data = slice::read_bytes(&mut reader, len as usize)
+            .map_err(|e| PngError::read_failed(": chunk data").with_io_source(e))?;
+        let crc = slice::read_be_u32(&mut reader)
+            .map_err(|e| PngError::read_failed(": chunk crc").with_io_source(e))?;
+        if crc != chunk::crc32(&kind, &data) {
+            return Err(PngError::checksum_mismatch(": chunk crc").with_data(&kind));
+        }
+        chunks.push(Chunk::new(kind, data));
+    }
+
+    Ok(chunks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{container::PNG_TEST_DATA, errors::BaseError, Field, Orientation, Tag};
+
+    #[test]
+    fn test_parse() {
+        let png = Png::parse(io::Cursor::new(&PNG_TEST_DATA)).unwrap();
+        assert_eq!(png.chunks.len(), 6);
+        assert_eq!(
+            png.header(),
+            &PngHeader { width: 2, height: 1, bit_depth: 8, color_type: 2, interlaced: false }
+        );
+        assert_eq!(png.icc_profile_name(), Some("ICC Profile"));
+
+        let text = png.text();
+        assert_eq!(text.len(), 3);
+        assert_eq!((text[0].keyword.as_str(), text[0].text.as_str()), ("Title", "Screenshot"));
+        assert_eq!(
+            (text[1].keyword.as_str(), text[1].text.as_str()),
+            ("Comment", "Compressed comment")
+        );
+        assert_eq!(text[2].keyword, "Description");
+        assert_eq!(text[2].language, Some("de".into()));
+        assert_eq!(text[2].translated_keyword, Some("Beschreibung".into()));
+        assert_eq!(text[2].text, "Grüße");
+    }
+
+    #[test]
+    fn test_exif() {
+        let png = Png::parse(io::Cursor::new(&PNG_TEST_DATA)).unwrap();
+        let exif = png.exif().unwrap().unwrap();
+        assert_eq!(
+            exif.get_field(Tag::Orientation),
+            Some(Field::Orientation(Orientation::Rotate90CW))
+        );
+    }
+
+    #[test]
+    fn test_parse_crc_mismatch() {
+        let mut data = PNG_TEST_DATA.to_vec();
+        data[95] ^= 0xFF; // tEXt crc
+        let err = Png::parse(io::Cursor::new(&data)).unwrap_err();
+        assert_eq!(err.to_string(), "PNG checksum mismatch: chunk crc [74, 45, 58, 74]");
+    }
+
+    #[test]
+    fn test_parse_skips_bad_text() {
+        let mut data = PNG_TEST_DATA.to_vec();
+        data[82] = b'x'; // tEXt keyword null separator
+        let crc = chunk::crc32(&data[73..77], &data[77..93]);
+        data[93..97].copy_from_slice(&crc.to_be_bytes());
+        let png = Png::parse(io::Cursor::new(&data)).unwrap();
+        assert_eq!(png.text().len(), 2);
+        assert_eq!(png.text()[0].keyword, "Comment");
+    }
+
+    #[test]
+    fn test_parse_bad_icc_profile() {
+        let mut data = PNG_TEST_DATA.to_vec();
+        data[41..65].fill(b'x'); // iCCP without a profile name null separator
+        let crc = chunk::crc32(&data[37..41], &data[41..65]);
+        data[65..69].copy_from_slice(&crc.to_be_bytes());
+        let png = Png::parse(io::Cursor::new(&data)).unwrap();
+        assert_eq!(png.icc_profile_name(), None);
+        assert_eq!(png.text().len(), 3);
+        assert!(png.icc_profile().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_parse_chunk_length_beyond_input() {
+        let mut data = PNG_TEST_DATA[..33].to_vec();
+        data.extend([0x7F, 0xFF, 0xFF, 0xF0]);
+        data.extend(b"IDAT");
+        data.extend([0x00; 4]);
+        let err = Png::parse(io::Cursor::new(&data)).unwrap_err();
+        assert_eq!(err.to_string(), "PNG read failed: chunk data");
+        data[37..41].copy_from_slice(b"tEXt");
+        let err = Png::parse(io::Cursor::new(&data)).unwrap_err();
+        assert_eq!(err.to_string(), "PNG read failed: chunk data");
+    }
+
+    #[test]
+    fn test_parse_missing_iend() {
+        let err = Png::parse(io::Cursor::new(&PNG_TEST_DATA[..266])).unwrap_err();
+        assert_eq!(
+            err.all_to_string(),
+            "PNG read failed: chunk length ==> io::Error: failed to fill whole buffer"
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_header() {
+        let err = Png::parse(io::Cursor::new(&PNG_TEST_DATA[1..9])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "PNG parse failed: invalid header [50, 4e, 47, 0d, 0a, 1a, 0a, 00]"
+        );
+    }
+}
@@ -0,0 +1,74 @@
+// Two pixel PNG with metadata chunks
+#[cfg(test)]
+pub(crate) const PNG_TEST_DATA: [u8; 278] = [
+    /* 000-007 */ 0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, // PNG signature
+    //
+    /* 008-011 */ 0x00, 0x00, 0x00, 0x0d, // IHDR: length (13)
+    /* 012-015 */ 0x49, 0x48, 0x44, 0x52, // IHDR: type
+    /* 016-023 */ 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+    0x01, // IHDR: width (2), height (1)
+    /* 024-028 */ 0x08, 0x02, 0x00, 0x00,
+    0x00, // IHDR: depth, color type, compression, filter, interlace
+    /* 029-032 */ 0x7b, 0x40, 0xe8, 0xdd, // IHDR: crc
+    //
+    /* 033-036 */ 0x00, 0x00, 0x00, 0x18, // iCCP: length (24)
+    /* 037-040 */ 0x69, 0x43, 0x43, 0x50, // iCCP: type
+    /* 041-052 */ 0x49, 0x43, 0x43, 0x20, 0x50, 0x72, 0x6f, 0x66, 0x69, 0x6c, 0x65,
+    0x00, // iCCP: profile name
+    /* 053-053 */ 0x00, // iCCP: compression method
+    /* 054-064 */ 0x78, 0x9c, 0x63, 0x60, 0x40, 0x05, 0x00, 0x00, 0x10, 0x00,
+    0x01, // iCCP: compressed profile
+    /* 065-068 */ 0xd3, 0x35, 0x01, 0xef, // iCCP: crc
+    //
+    /* 069-072 */ 0x00, 0x00, 0x00, 0x10, // tEXt: length (16)
+    /* 073-076 */ 0x74, 0x45, 0x58, 0x74, // tEXt: type
+    /* 077-082 */ 0x54, 0x69, 0x74, 0x6c, 0x65, 0x00, // tEXt: keyword
+    /* 083-092 */ 0x53, 0x63, 0x72, 0x65, 0x65, 0x6e, 0x73, 0x68, 0x6f,
+    0x74, // tEXt: text
+    /* 093-096 */ 0x62, 0xaa, 0xc6, 0xdd, // tEXt: crc
+    //
+    /* 097-100 */ 0x00, 0x00, 0x00, 0x23, // zTXt: length (35)
+    /* 101-104 */ 0x7a, 0x54, 0x58, 0x74, // zTXt: type
+    /* 105-112 */ 0x43, 0x6f, 0x6d, 0x6d, 0x65, 0x6e, 0x74, 0x00, // zTXt: keyword
+    /* 113-113 */ 0x00, // zTXt: compression method
+    /* 114-125 */ 0x78, 0x9c, 0x73, 0xce, 0xcf, 0x2d, 0x28, 0x4a, 0x2d, 0x2e, 0x4e,
+    0x4d, // zTXt: compressed text
+    /* 126-137 */ 0x51, 0x48, 0xce, 0xcf, 0xcd, 0x4d, 0xcd, 0x2b, 0x01, 0x00, 0x43,
+    0x52, // zTXt: ...
+    /* 138-139 */ 0x07, 0x29, // zTXt: ...
+    /* 140-143 */ 0xad, 0x14, 0x6f, 0x6f, // zTXt: crc
+    //
+    /* 144-147 */ 0x00, 0x00, 0x00, 0x2d, // iTXt: length (45)
+    /* 148-151 */ 0x69, 0x54, 0x58, 0x74, // iTXt: type
+    /* 152-163 */ 0x44, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e,
+    0x00, // iTXt: keyword
+    /* 164-165 */ 0x01, 0x00, // iTXt: compression flag, method
+    /* 166-168 */ 0x64, 0x65, 0x00, // iTXt: language
+    /* 169-180 */ 0x42, 0x65, 0x73, 0x63, 0x68, 0x72, 0x65, 0x69, 0x62, 0x75, 0x6e,
+    0x67, // iTXt: translated keyword
+    /* 181-181 */ 0x00, // iTXt: ...
+    /* 182-193 */ 0x78, 0x9c, 0x73, 0x2f, 0x3a, 0xbc, 0xe7, 0xf0, 0xfc, 0x54, 0x00,
+    0x0f, // iTXt: compressed text
+    /* 194-196 */ 0x4f, 0x04, 0x00, // iTXt: ...
+    /* 197-200 */ 0xc2, 0x70, 0x26, 0x24, // iTXt: crc
+    //
+    /* 201-204 */ 0x00, 0x00, 0x00, 0x1a, // eXIf: length (26)
+    /* 205-208 */ 0x65, 0x58, 0x49, 0x66, // eXIf: type
+    /* 209-216 */ 0x49, 0x49, 0x2a, 0x00, 0x08, 0x00, 0x00, 0x00, // eXIf: TIFF header
+    /* 217-218 */ 0x01, 0x00, // eXIf: IFD 0: field count
+    /* 219-230 */ 0x12, 0x01, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00,
+    0x00, // eXIf: Orientation (6)
+    /* 231-234 */ 0x00, 0x00, 0x00, 0x00, // eXIf: IFD 0: next IFD offset i.e. none
+    /* 235-238 */ 0xb7, 0x48, 0x11, 0x29, // eXIf: crc
+    //
+    /* 239-242 */ 0x00, 0x00, 0x00, 0x0f, // IDAT: length (15)
+    /* 243-246 */ 0x49, 0x44, 0x41, 0x54, // IDAT: type
+    /* 247-258 */ 0x78, 0x9c, 0x63, 0xf8, 0xcf, 0xc0, 0xc0, 0xf0, 0x9f, 0x01, 0x00,
+    0x07, // IDAT: compressed image data
+    /* 259-261 */ 0xff, 0x01, 0xff, // IDAT: ...
+    /* 262-265 */ 0x01, 0x7f, 0x89, 0xa7, // IDAT: crc
+    //
+    /* 266-269 */ 0x00, 0x00, 0x00, 0x00, // IEND: length (0)
+    /* 270-273 */ 0x49, 0x45, 0x4e, 0x44, // IEND: type
+    /* 274-277 */ 0xae, 0x42, 0x60, 0x82, // IEND: crc
+];
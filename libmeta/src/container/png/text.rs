@@ -0,0 +1,97 @@
+use super::chunk::{self, ITXT, TEXT, ZTXT};
+use crate::errors::PngError;
+
+/// Textual meta data from the PNG tEXt, zTXt and iTXt chunks
+#[derive(Debug, Clone, PartialEq)]
+pub struct Text {
+    pub keyword: String,          // e.g. Title, Author, Description, Comment
+    pub language: Option<String>, // iTXt only, e.g. `en-us`
+    pub translated_keyword: Option<String>, // iTXt only, keyword in the given language
+    pub text: String,
+}
+
+impl Text {
+    /// Parse the given text chunk data based on the chunk type
+    /// * **tEXt** | keyword, null, Latin-1 text
+    /// * **zTXt** | keyword, null, compression method, compressed Latin-1 text
+    /// * **iTXt** | keyword, null, compression flag, compression method, language, null,
+    ///   translated keyword, null, optionally compressed UTF-8 text
+    pub(crate) fn parse(kind: [u8; 4], data: &[u8]) -> Result<Self, PngError> {
+        let (keyword, remain) = chunk::split_null(data, "keyword")?;
+        let keyword = chunk::latin1(keyword);
+
+        match kind {
+            TEXT => Ok(Self::new(keyword, chunk::latin1(remain))),
+            ZTXT => match remain.split_first() {
+                Some((method, compressed)) => {
+                    let text = chunk::inflate(*method, compressed)?;
+                    Ok(Self::new(keyword, chunk::latin1(&text)))
+                }
+                None => Err(PngError::parse(": zTXt missing compression method")),
+            },
+            ITXT => {
+                if remain.len() < 2 {
+                    return Err(PngError::parse(": iTXt missing compression fields"));
+                }
+                let (compressed, method) = (remain[0] == 1, remain[1]);
+                let (language, remain) = chunk::split_null(&remain[2..], "language")?;
+                let (translated, remain) = chunk::split_null(remain, "translated keyword")?;
+                let text = match compressed {
+                    true => chunk::inflate(method, remain)?,
+                    false => remain.to_vec(),
+                };
+                let text = String::from_utf8(text)
+                    .map_err(|e| PngError::parse(": iTXt text is not UTF-8").wrap(e))?;
+
+                let mut value = Self::new(keyword, text);
+                value.language = optional(String::from_utf8_lossy(language).into_owned());
+                value.translated_keyword =
+                    optional(String::from_utf8_lossy(translated).into_owned());
+                Ok(value)
+            }
+            _ => Err(PngError::parse(": not a text chunk").with_data(&kind)),
+        }
+    }
+
+    fn new(keyword: String, text: String) -> Self {
+        Self { keyword, language: None, translated_keyword: None, text }
+    }
+}
+
+/// Empty iTXt language and translated keyword fields are treated as not set
+fn optional(value: String) -> Option<String> {
+    match value.is_empty() {
+        true => None,
+        false => Some(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_text_latin1() {
+        let text = Text::parse(TEXT, b"Author\0Ren\xe9").unwrap();
+        assert_eq!(text.keyword, "Author");
+        assert_eq!(text.text, "René");
+        assert_eq!(text.language, None);
+    }
+
+    #[test]
+    fn test_parse_itxt_uncompressed() {
+        let text = Text::parse(ITXT, b"Title\0\0\0\0\0Gr\xc3\xbc\xc3\x9fe").unwrap();
+        assert_eq!(text.keyword, "Title");
+        assert_eq!(text.text, "Grüße");
+        assert_eq!(text.language, None);
+        assert_eq!(text.translated_keyword, None);
+    }
+
+    #[test]
+    fn test_parse_itxt_truncated() {
+        assert_eq!(
+            Text::parse(ITXT, b"Title\0\0\0en").unwrap_err().to_string(),
+            "PNG parse failed: language missing null separator"
+        );
+    }
+}
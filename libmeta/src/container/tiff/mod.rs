@@ -0,0 +1,10 @@
+// TIFF's are constructed from an 8 byte header followed by a chain of Image File Directories (IFDs)
+// each of which describes a single image i.e. page and the offsets to its strip or tile data.
+mod test_data;
+mod tiff;
+
+pub use tiff::*;
+
+// Expose testing data to other modules
+#[cfg(test)]
+pub(crate) use test_data::TIFF_TEST_DATA;
@@ -0,0 +1,98 @@
+// Little endian two page TIFF with a strip based and a tile based image
+#[cfg(test)]
+pub(crate) const TIFF_TEST_DATA: [u8; 220] = [
+    // TIFF header
+    /* 000-001 */ 0x49, 0x49, // byte alignment
+    /* 002-003 */ 0x2a, 0x00, // version identifier
+    /* 004-007 */ 0x08, 0x00, 0x00, 0x00, // IFD 0: offset
+    //
+    // IFD 0
+    /* 008-009 */ 0x08, 0x00, // IFD 0: field count
+    //
+    /* 010-011 */ 0x00, 0x01, // Field 0: ImageWidth
+    /* 012-013 */ 0x03, 0x00, // Field 0: format Unsigned Short
+    /* 014-017 */ 0x01, 0x00, 0x00, 0x00, // Field 0: components
+    /* 018-021 */ 0x02, 0x00, 0x00, 0x00, // Field 0: data (2)
+    //
+    /* 022-023 */ 0x01, 0x01, // Field 1: ImageHeight
+    /* 024-025 */ 0x03, 0x00, // Field 1: format Unsigned Short
+    /* 026-029 */ 0x01, 0x00, 0x00, 0x00, // Field 1: components
+    /* 030-033 */ 0x02, 0x00, 0x00, 0x00, // Field 1: data (2)
+    //
+    /* 034-035 */ 0x02, 0x01, // Field 2: BitsPerSample
+    /* 036-037 */ 0x03, 0x00, // Field 2: format Unsigned Short
+    /* 038-041 */ 0x01, 0x00, 0x00, 0x00, // Field 2: components
+    /* 042-045 */ 0x08, 0x00, 0x00, 0x00, // Field 2: data (8)
+    //
+    /* 046-047 */ 0x03, 0x01, // Field 3: Compression
+    /* 048-049 */ 0x03, 0x00, // Field 3: format Unsigned Short
+    /* 050-053 */ 0x01, 0x00, 0x00, 0x00, // Field 3: components
+    /* 054-057 */ 0x01, 0x00, 0x00, 0x00, // Field 3: data (1)
+    //
+    /* 058-059 */ 0x06, 0x01, // Field 4: PhotometricInterpretation
+    /* 060-061 */ 0x03, 0x00, // Field 4: format Unsigned Short
+    /* 062-065 */ 0x01, 0x00, 0x00, 0x00, // Field 4: components
+    /* 066-069 */ 0x01, 0x00, 0x00, 0x00, // Field 4: data (1)
+    //
+    /* 070-071 */ 0x11, 0x01, // Field 5: StripOffsets
+    /* 072-073 */ 0x04, 0x00, // Field 5: format Unsigned Long
+    /* 074-077 */ 0x01, 0x00, 0x00, 0x00, // Field 5: components
+    /* 078-081 */ 0xd0, 0x00, 0x00, 0x00, // Field 5: data (208)
+    //
+    /* 082-083 */ 0x16, 0x01, // Field 6: RowsPerStrip
+    /* 084-085 */ 0x03, 0x00, // Field 6: format Unsigned Short
+    /* 086-089 */ 0x01, 0x00, 0x00, 0x00, // Field 6: components
+    /* 090-093 */ 0x02, 0x00, 0x00, 0x00, // Field 6: data (2)
+    //
+    /* 094-095 */ 0x17, 0x01, // Field 7: StripByteCounts
+    /* 096-097 */ 0x04, 0x00, // Field 7: format Unsigned Long
+    /* 098-101 */ 0x01, 0x00, 0x00, 0x00, // Field 7: components
+    /* 102-105 */ 0x04, 0x00, 0x00, 0x00, // Field 7: data (4)
+    //
+    /* 106-109 */ 0x6e, 0x00, 0x00, 0x00, // IFD 0: next IFD offset (110)
+    //
+    // IFD 1
+    /* 110-111 */ 0x07, 0x00, // IFD 1: field count
+    //
+    /* 112-113 */ 0xfe, 0x00, // Field 0: NewSubfileType
+    /* 114-115 */ 0x04, 0x00, // Field 0: format Unsigned Long
+    /* 116-119 */ 0x01, 0x00, 0x00, 0x00, // Field 0: components
+    /* 120-123 */ 0x02, 0x00, 0x00, 0x00, // Field 0: data (2)
+    //
+    /* 124-125 */ 0x00, 0x01, // Field 1: ImageWidth
+    /* 126-127 */ 0x03, 0x00, // Field 1: format Unsigned Short
+    /* 128-131 */ 0x01, 0x00, 0x00, 0x00, // Field 1: components
+    /* 132-135 */ 0x02, 0x00, 0x00, 0x00, // Field 1: data (2)
+    //
+    /* 136-137 */ 0x01, 0x01, // Field 2: ImageHeight
+    /* 138-139 */ 0x03, 0x00, // Field 2: format Unsigned Short
+    /* 140-143 */ 0x01, 0x00, 0x00, 0x00, // Field 2: components
+    /* 144-147 */ 0x02, 0x00, 0x00, 0x00, // Field 2: data (2)
+    //
+    /* 148-149 */ 0x42, 0x01, // Field 3: TileWidth
+    /* 150-151 */ 0x03, 0x00, // Field 3: format Unsigned Short
+    /* 152-155 */ 0x01, 0x00, 0x00, 0x00, // Field 3: components
+    /* 156-159 */ 0x10, 0x00, 0x00, 0x00, // Field 3: data (16)
+    //
+    /* 160-161 */ 0x43, 0x01, // Field 4: TileLength
+    /* 162-163 */ 0x03, 0x00, // Field 4: format Unsigned Short
+    /* 164-167 */ 0x01, 0x00, 0x00, 0x00, // Field 4: components
+    /* 168-171 */ 0x10, 0x00, 0x00, 0x00, // Field 4: data (16)
+    //
+    /* 172-173 */ 0x44, 0x01, // Field 5: TileOffsets
+    /* 174-175 */ 0x04, 0x00, // Field 5: format Unsigned Long
+    /* 176-179 */ 0x02, 0x00, 0x00, 0x00, // Field 5: components
+    /* 180-183 */ 0xc8, 0x00, 0x00, 0x00, // Field 5: offset (200)
+    //
+    /* 184-185 */ 0x45, 0x01, // Field 6: TileByteCounts
+    /* 186-187 */ 0x03, 0x00, // Field 6: format Unsigned Short
+    /* 188-191 */ 0x02, 0x00, 0x00, 0x00, // Field 6: components
+    /* 192-195 */ 0x04, 0x00, 0x04, 0x00, // Field 6: data [4, 4]
+    //
+    /* 196-199 */ 0x00, 0x00, 0x00, 0x00, // IFD 1: next IFD offset i.e. none
+    //
+    /* 200-207 */ 0xd4, 0x00, 0x00, 0x00, 0xd8, 0x00, 0x00, 0x00, // IFD 1: tile offsets (212, 216)
+    /* 208-211 */ 0x00, 0x40, 0x80, 0xff, // IFD 0: strip data
+    /* 212-215 */ 0x11, 0x11, 0x11, 0x11, // IFD 1: tile 0 data
+    /* 216-219 */ 0x22, 0x22, 0x22, 0x22, // IFD 1: tile 1 data
+];
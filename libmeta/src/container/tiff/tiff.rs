@@ -0,0 +1,337 @@
+use std::{collections::HashSet, fmt::Display, io};
+
+use crate::{
+    errors::TiffError,
+    meta::{exif::format, Exif, Tag, MAX_IFDS},
+};
+
+/// Simplify the Tiff return type slightly
+pub type TiffResult<T> = Result<T, TiffError>;
+
+/// Little endian TIFF header `II*\0`
+const LITTLE_ENDIAN_HEADER: [u8; 4] = [0x49, 0x49, 0x2A, 0x00];
+
+/// Big endian TIFF header `MM\0*`
+const BIG_ENDIAN_HEADER: [u8; 4] = [0x4D, 0x4D, 0x00, 0x2A];
+
+/// TIFF header size i.e. byte order, magic number and first IFD offset
+const HEADER_SIZE: u64 = 8;
+
+/// IFD field entry size i.e. tag, format, component count and value or offset
+const ENTRY_SIZE: u64 = 12;
+
+/// IFD0 tag holding the embedded ICC profile, not part of the Exif tag set
+const ICC_PROFILE_TAG: u16 = 0x8773;
+
+#[derive(Debug)]
+pub struct Tiff {
+    pub(crate) data: Vec<u8>,
+}
+
+impl Tiff {
+    /// Parse all meta data from the given TIFF source.
+    /// * IFD offsets are relative to the start of the file so the data is kept at its original
+    ///   offsets, however only the IFDs, their out of line values and the IFD1 thumbnail are read
+    ///   leaving the image strips and tiles as zeros
+    pub fn parse<T: io::Read + io::Seek>(reader: T) -> TiffResult<Self> {
+        let mut source = Source::new(reader)
+            .map_err(|x| TiffError::read_failed(": source data").with_io_source(x))?;
+        let header = source
+            .read(0, HEADER_SIZE)
+            .map_err(|x| TiffError::read_failed(": header").with_io_source(x))?;
+        if !Self::is_tiff(&header) {
+            return Err(TiffError::parse(": invalid header")
+                .with_data(&header[..header.len().min(LITTLE_ENDIAN_HEADER.len())]));
+        }
+
+        let mut ranges = vec![(0, header.len() as u64)];
+        if header.len() as u64 == HEADER_SIZE {
+            let endian = Endian::from(&header);
+            walk_ifds(&mut source, endian, endian.u32(&header[4..]) as u64, &mut ranges)
+                .map_err(|x| TiffError::read_failed(": IFD data").with_io_source(x))?;
+        }
+
+        // Empty ranges beyond the end of the source mustn't pad the data out with zeros
+        ranges.retain(|(_, size)| *size > 0);
+        let end = ranges
+            .iter()
+            .map(|(offset, size)| offset + size)
+            .max()
+            .unwrap_or(0);
+        let mut data = vec![0; end as usize];
+        for (offset, size) in ranges {
+            source
+                .read_into(offset, &mut data[offset as usize..(offset + size) as usize])
+                .map_err(|x| TiffError::read_failed(": IFD data").with_io_source(x))?;
+        }
+
+        Ok(Tiff { data })
+    }
+
+    // Determine if the given header is from a tiff source
+    pub(crate) fn is_tiff(header: &[u8]) -> bool {
+        header.starts_with(&LITTLE_ENDIAN_HEADER) || header.starts_with(&BIG_ENDIAN_HEADER)
+    }
+
+    /// Get the raw ICC profile from the IFD0 `InterColorProfile` field if it exists
+    /// * A field that is out of bounds is treated as missing
+    pub(crate) fn icc_profile(&self) -> Option<&[u8]> {
+        let endian = Endian::from(&self.data);
+        let offset = endian.u32(self.data.get(4..8)?) as usize;
+        let count = endian.u16(self.data.get(offset..offset.checked_add(2)?)?) as usize;
+        let entries = self
+            .data
+            .get(offset + 2..)?
+            .chunks_exact(ENTRY_SIZE as usize);
+        let entry = entries
+            .take(count)
+            .find(|x| endian.u16(x) == ICC_PROFILE_TAG)?;
+        let len = format::size(endian.u16(&entry[2..])) * endian.u32(&entry[4..]) as u64;
+        match len {
+            0..=4 => entry.get(8..8 + len as usize),
+            _ => {
+                let start = endian.u32(&entry[8..]) as usize;
+                self.data.get(start..start.checked_add(len as usize)?)
+            }
+        }
+    }
+
+    /// Get the Exif meta data from the parsed TIFF i.e. all of its IFDs
+    pub(crate) fn exif(&self) -> TiffResult<Exif> {
+        Exif::parse_tiff(&self.data).map_err(|e| TiffError::parse(": exif parsing").wrap(e))
+    }
+}
+
+/// Seekable TIFF source with offsets relative to where the TIFF header starts
+struct Source<T: io::Read + io::Seek> {
+    reader: T,
+    base: u64,
+    len: u64,
+}
+
+impl<T: io::Read + io::Seek> Source<T> {
+    fn new(mut reader: T) -> io::Result<Self> {
+        let base = reader.stream_position()?;
+        let len = reader.seek(io::SeekFrom::End(0))?.saturating_sub(base);
+        Ok(Self { reader, base, len })
+    }
+
+    /// Clamp the given range to the source returning the number of bytes available
+    fn available(&self, offset: u64, size: u64) -> u64 {
+        self.len.saturating_sub(offset).min(size)
+    }
+
+    /// Read up to size bytes from the given offset stopping short at the end of the source
+    fn read(&mut self, offset: u64, size: u64) -> io::Result<Vec<u8>> {
+        let mut data = vec![0; self.available(offset, size) as usize];
+        self.read_into(offset, &mut data)?;
+        Ok(data)
+    }
+
+    fn read_into(&mut self, offset: u64, data: &mut [u8]) -> io::Result<()> {
+        if !data.is_empty() {
+            self.reader.seek(io::SeekFrom::Start(self.base + offset))?;
+            self.reader.read_exact(data)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Endian {
+    Little,
+    Big,
+}
+
+impl Endian {
+    fn from(header: &[u8]) -> Self {
+        match header.starts_with(&LITTLE_ENDIAN_HEADER) {
+            true => Endian::Little,
+            false => Endian::Big,
+        }
+    }
+
+    fn u16(self, data: &[u8]) -> u16 {
+        let bytes = [data[0], data[1]];
+        match self {
+            Endian::Little => u16::from_le_bytes(bytes),
+            Endian::Big => u16::from_be_bytes(bytes),
+        }
+    }
+
+    fn u32(self, data: &[u8]) -> u32 {
+        let bytes = [data[0], data[1], data[2], data[3]];
+        match self {
+            Endian::Little => u32::from_le_bytes(bytes),
+            Endian::Big => u32::from_be_bytes(bytes),
+        }
+    }
+}
+
+/// Walk the IFD page chain and sub-IFDs collecting the byte ranges the Exif parser will need
+/// * Ranges are clamped to the source so that truncated IFDs still fail in the Exif parser
+/// * Loops and overly long chains simply stop the walk as the Exif parser reports them
+fn walk_ifds<T: io::Read + io::Seek>(
+    source: &mut Source<T>,
+    endian: Endian,
+    first: u64,
+    ranges: &mut Vec<(u64, u64)>,
+) -> io::Result<()> {
+    let mut pending = vec![(first, true)];
+    let mut visited = HashSet::new();
+    while let Some((offset, is_page)) = pending.pop() {
+        if visited.len() >= MAX_IFDS || !visited.insert(offset) {
+            continue;
+        }
+        let count = source.read(offset, 2)?;
+        ranges.push((offset, count.len() as u64));
+        if count.len() < 2 {
+            continue;
+        }
+
+        let size = endian.u16(&count) as u64 * ENTRY_SIZE;
+        let entries = source.read(offset + 2, size)?;
+        ranges.push((offset + 2, entries.len() as u64));
+
+        let mut thumbnail = (None, None);
+        for entry in entries.chunks_exact(ENTRY_SIZE as usize) {
+            let tag = endian.u16(&entry[0..]);
+            let len = format::size(endian.u16(&entry[2..])) * endian.u32(&entry[4..]) as u64;
+            let value = endian.u32(&entry[8..]) as u64;
+            if len > 4 {
+                ranges.push((value, source.available(value, len)));
+            }
+            match Tag::from(tag) {
+                Tag::ExifSubIfdOffset | Tag::GpsSubIfdOffset | Tag::ExifInteroperabilityOffset => {
+                    pending.push((value, false))
+                }
+                Tag::ThumbnailOffset => thumbnail.0 = Some(value),
+                Tag::ThumbnailLength => thumbnail.1 = Some(value),
+                _ => (),
+            }
+        }
+        if let (Some(offset), Some(len)) = thumbnail {
+            ranges.push((offset, source.available(offset, len)));
+        }
+
+        if is_page {
+            let next = source.read(offset + 2 + size, 4)?;
+            ranges.push((offset + 2 + size, next.len() as u64));
+            if next.len() == 4 && endian.u32(&next) != 0 {
+                pending.push((endian.u32(&next) as u64, true));
+            }
+        }
+    }
+    Ok(())
+}
+
+impl Display for Tiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TIFF: {} bytes", self.data.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{container::TIFF_TEST_DATA, errors::BaseError, Field, Tag};
+
+    #[test]
+    fn test_is_tiff() {
+        assert!(Tiff::is_tiff(&[0x49, 0x49, 0x2A, 0x00]));
+        assert!(Tiff::is_tiff(&[0x4D, 0x4D, 0x00, 0x2A]));
+        assert!(!Tiff::is_tiff(&[0x49, 0x49, 0x00, 0x2A]));
+        assert!(!Tiff::is_tiff(&[0x49, 0x49]));
+    }
+
+    #[test]
+    fn test_parse_invalid_header() {
+        let err = Tiff::parse(io::Cursor::new(&[0xFF, 0xD8, 0xFF, 0xE0, 0x00])).unwrap_err();
+        assert_eq!(err.to_string(), "TIFF parse failed: invalid header [ff, d8, ff, e0]");
+    }
+
+    #[test]
+    fn test_exif_corrupt_ifd() {
+        let tiff = Tiff::parse(io::Cursor::new(&TIFF_TEST_DATA[..20])).unwrap();
+        let err = tiff.exif().unwrap_err();
+        assert_eq!(
+            err.all_to_string(),
+            "TIFF parse failed: exif parsing ==> Exif parse failed: IFD data ==> nom::Parsing requires 2 bytes/chars"
+        );
+    }
+
+    #[test]
+    fn test_exif_pages() {
+        let tiff = Tiff::parse(io::Cursor::new(&TIFF_TEST_DATA)).unwrap();
+        let exif = tiff.exif().unwrap();
+        assert_eq!(exif.page_count(), 2);
+
+        // Strip based first page
+        assert_eq!(exif.get_page_field(0, Tag::ImageWidth), Some(Field::ImageWidth(2)));
+        assert_eq!(exif.get_page_field(0, Tag::StripOffsets), Some(Field::StripOffsets(vec![208])));
+        assert_eq!(exif.get_page_field(0, Tag::RowsPerStrip), Some(Field::RowsPerStrip(2)));
+        assert_eq!(
+            exif.get_page_field(0, Tag::StripByteCounts),
+            Some(Field::StripByteCounts(vec![4]))
+        );
+        assert_eq!(exif.get_page_field(0, Tag::TileOffsets), None);
+
+        // Tile based second page
+        assert_eq!(exif.get_page_field(1, Tag::NewSubfileType), Some(Field::NewSubfileType(2)));
+        assert_eq!(exif.get_page_field(1, Tag::TileWidth), Some(Field::TileWidth(16)));
+        assert_eq!(exif.get_page_field(1, Tag::TileLength), Some(Field::TileLength(16)));
+        assert_eq!(
+            exif.get_page_field(1, Tag::TileOffsets),
+            Some(Field::TileOffsets(vec![212, 216]))
+        );
+        assert_eq!(
+            exif.get_page_field(1, Tag::TileByteCounts),
+            Some(Field::TileByteCounts(vec![4, 4]))
+        );
+        assert_eq!(exif.get_page_field(1, Tag::StripOffsets), None);
+        assert_eq!(exif.get_page_field(2, Tag::ImageWidth), None);
+    }
+
+    #[test]
+    fn test_parse_skips_image_data() {
+        let mut data = TIFF_TEST_DATA.to_vec();
+        data[208..].fill(0xAA);
+        data.extend_from_slice(&[0xAA; 64]);
+        let tiff = Tiff::parse(io::Cursor::new(&data)).unwrap();
+
+        // IFDs and out of line tile offsets are kept but not the strips and tiles that follow
+        assert_eq!(tiff.data.len(), 208);
+        assert_eq!(tiff.data[..208], TIFF_TEST_DATA[..208]);
+        assert_eq!(tiff.exif().unwrap().page_count(), 2);
+    }
+
+    #[test]
+    fn test_icc_profile() {
+        let mut data = vec![0x49, 0x49, 0x2A, 0x00, 0x08, 0x00, 0x00, 0x00, 0x01, 0x00];
+        data.extend_from_slice(&[
+            0x73, 0x87, 0x07, 0x00, 0x06, 0x00, 0x00, 0x00, 0x1A, 0x00, 0x00, 0x00,
+        ]);
+        data.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]);
+        data.extend_from_slice(b"icc!!!");
+        let tiff = Tiff::parse(io::Cursor::new(&data)).unwrap();
+        assert_eq!(tiff.icc_profile(), Some(&b"icc!!!"[..]));
+
+        // Truncated profile data
+        let tiff = Tiff::parse(io::Cursor::new(&data[..28])).unwrap();
+        assert_eq!(tiff.icc_profile(), None);
+
+        let tiff = Tiff::parse(io::Cursor::new(&TIFF_TEST_DATA)).unwrap();
+        assert_eq!(tiff.icc_profile(), None);
+    }
+
+    #[test]
+    fn test_parse_offset_source() {
+        let mut data = vec![0xFF; 16];
+        data.extend_from_slice(&TIFF_TEST_DATA);
+        let mut reader = io::Cursor::new(&data);
+        reader.set_position(16);
+        let tiff = Tiff::parse(reader).unwrap();
+        assert_eq!(tiff.data[..208], TIFF_TEST_DATA[..208]);
+        assert_eq!(tiff.exif().unwrap().page_count(), 2);
+    }
+}
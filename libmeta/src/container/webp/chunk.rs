@@ -0,0 +1,36 @@
+pub(crate) const RIFF: [u8; 4] = *b"RIFF"; // RIFF signature
+pub(crate) const WEBP: [u8; 4] = *b"WEBP"; // RIFF form type
+pub(crate) const VP8: [u8; 4] = *b"VP8 "; // Simple lossy image data
+pub(crate) const VP8L: [u8; 4] = *b"VP8L"; // Simple lossless image data
+pub(crate) const VP8X: [u8; 4] = *b"VP8X"; // Extended format header with feature flags
+pub(crate) const ICCP: [u8; 4] = *b"ICCP"; // Raw ICC profile
+pub(crate) const EXIF: [u8; 4] = *b"EXIF"; // Exif data starting with the TIFF header
+pub(crate) const XMP: [u8; 4] = *b"XMP "; // Raw XMP packet
+
+/// VP8 frames carry a 3 byte frame tag, 3 byte start code and the 2 byte width and height
+pub(crate) const VP8_HEADER_LEN: usize = 10;
+
+/// VP8L streams carry a 1 byte signature and the 4 byte packed dimensions, alpha and version
+pub(crate) const VP8L_HEADER_LEN: usize = 5;
+
+// VP8X feature flags
+pub(crate) const FLAG_ANIMATION: u8 = 0x02;
+pub(crate) const FLAG_ALPHA: u8 = 0x10;
+
+// WebP chunks are defined by their FourCC type and the data they contain
+#[derive(Debug, PartialEq)]
+pub(crate) struct Chunk {
+    pub(crate) kind: [u8; 4], // RIFF chunk FourCC type
+    pub(crate) data: Vec<u8>, // RIFF chunk data without padding
+}
+
+impl Chunk {
+    pub(crate) fn new(kind: [u8; 4], data: Vec<u8>) -> Self {
+        Self { kind, data }
+    }
+
+    /// Is this a chunk that carries meta data
+    pub(crate) fn is_meta(kind: &[u8; 4]) -> bool {
+        matches!(*kind, VP8 | VP8L | VP8X | ICCP | EXIF | XMP)
+    }
+}
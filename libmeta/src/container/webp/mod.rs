@@ -0,0 +1,11 @@
+// WebP's are RIFF files i.e. a 12 byte `RIFF <size> WEBP` header followed by `Chunks`. Each chunk is
+// made up of a 4 byte FourCC type, a 4 byte Little Endian size and the data padded to an even size.
+mod chunk;
+mod test_data;
+mod webp;
+
+pub use webp::*;
+
+// Expose testing data to other modules
+#[cfg(test)]
+pub(crate) use test_data::WEBP_TEST_DATA;
@@ -0,0 +1,39 @@
+// Lossless WebP with alpha, Exif and XMP chunks
+#[cfg(test)]
+pub(crate) const WEBP_TEST_DATA: [u8; 124] = [
+    /* 000-003 */ 0x52, 0x49, 0x46, 0x46, // RIFF: signature
+    /* 004-007 */ 0x74, 0x00, 0x00, 0x00, // RIFF: size (116)
+    /* 008-011 */ 0x57, 0x45, 0x42, 0x50, // RIFF: form type
+    //
+    /* 012-015 */ 0x56, 0x50, 0x38, 0x58, // VP8X: type
+    /* 016-019 */ 0x0a, 0x00, 0x00, 0x00, // VP8X: size (10)
+    /* 020-023 */ 0x1c, 0x00, 0x00, 0x00, // VP8X: flags alpha, exif, xmp and reserved
+    /* 024-026 */ 0x8f, 0x01, 0x00, // VP8X: canvas width - 1 (399)
+    /* 027-029 */ 0x2b, 0x01, 0x00, // VP8X: canvas height - 1 (299)
+    //
+    /* 030-033 */ 0x56, 0x50, 0x38, 0x4c, // VP8L: type
+    /* 034-037 */ 0x05, 0x00, 0x00, 0x00, // VP8L: size (5)
+    /* 038-038 */ 0x2f, // VP8L: signature
+    /* 039-042 */ 0x8f, 0xc1, 0x4a,
+    0x10, // VP8L: width - 1, height - 1, alpha and version bits
+    /* 043-043 */ 0x00, // VP8L: padding
+    //
+    /* 044-047 */ 0x45, 0x58, 0x49, 0x46, // EXIF: type
+    /* 048-051 */ 0x1a, 0x00, 0x00, 0x00, // EXIF: size (26)
+    /* 052-059 */ 0x49, 0x49, 0x2a, 0x00, 0x08, 0x00, 0x00, 0x00, // EXIF: TIFF header
+    /* 060-061 */ 0x01, 0x00, // EXIF: IFD 0 field count
+    /* 062-073 */ 0x12, 0x01, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00,
+    0x00, // EXIF: Orientation (3)
+    /* 074-077 */ 0x00, 0x00, 0x00, 0x00, // EXIF: IFD 0 next IFD offset i.e. none
+    //
+    /* 078-081 */ 0x58, 0x4d, 0x50, 0x20, // XMP: type
+    /* 082-085 */ 0x25, 0x00, 0x00, 0x00, // XMP: size (37)
+    /* 086-097 */ 0x3c, 0x78, 0x3a, 0x78, 0x6d, 0x70, 0x6d, 0x65, 0x74, 0x61, 0x20,
+    0x78, // XMP: packet
+    /* 098-109 */ 0x6d, 0x6c, 0x6e, 0x73, 0x3a, 0x78, 0x3d, 0x22, 0x61, 0x64, 0x6f,
+    0x62, // XMP: ...
+    /* 110-121 */ 0x65, 0x3a, 0x6e, 0x73, 0x3a, 0x6d, 0x65, 0x74, 0x61, 0x2f, 0x22,
+    0x2f, // XMP: ...
+    /* 122-122 */ 0x3e, // XMP: ...
+    /* 123-123 */ 0x00, // XMP: padding
+];
@@ -0,0 +1,312 @@
+use std::{
+    fmt::Display,
+    io::{self, prelude::*},
+};
+
+use super::chunk::{self, Chunk};
+use crate::{errors::WebPError, meta::Exif, slice};
+
+/// Simplify the WebP return type slightly
+pub type WebPResult<T> = Result<T, WebPError>;
+
+#[derive(Debug)]
+pub struct WebP {
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    pub(crate) animated: bool,
+    pub(crate) alpha: bool,
+    pub(crate) chunks: Vec<Chunk>,
+}
+
+impl WebP {
+    /// Parse all meta data from the given WebP source.
+    pub fn parse<T: io::BufRead>(mut reader: T) -> WebPResult<Self> {
+        // Check the header to determine the media type
+        let mut header = Vec::new();
+        reader
+            .by_ref()
+            .take(12)
+            .read_to_end(&mut header)
+            .map_err(|x| WebPError::read_failed(": invalid header").with_io_source(x))?;
+        if !Self::is_webp(&header) {
+            return Err(WebPError::parse(": invalid header").with_data(&header));
+        }
+
+        // RIFF size includes the form type which has already been read
+        let size = u32::from_le_bytes(header[4..8].try_into().unwrap());
+        let chunks = parse_chunks(reader.take(size.saturating_sub(4) as u64))?;
+
+        // Extended files describe the canvas directly otherwise use the image bitstream header
+        let mut webp = match chunks.first() {
+            Some(x) if x.kind == chunk::VP8X => parse_vp8x(&x.data)?,
+            _ => match chunks
+                .iter()
+                .find(|x| matches!(x.kind, chunk::VP8 | chunk::VP8L))
+            {
+                Some(x) if x.kind == chunk::VP8 => parse_vp8(&x.data)?,
+                Some(x) => parse_vp8l(&x.data)?,
+                None => return Err(WebPError::parse(": no image chunk found")),
+            },
+        };
+        webp.chunks = chunks;
+
+        Ok(webp)
+    }
+
+    // Determine if the given header is from a webp source
+    pub(crate) fn is_webp(header: &[u8]) -> bool {
+        header.len() >= 12 && header[0..4] == chunk::RIFF && header[8..12] == chunk::WEBP
+    }
+
+    /// Canvas width in pixels
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Canvas height in pixels
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Does the image contain multiple animation frames
+    pub fn is_animated(&self) -> bool {
+        self.animated
+    }
+
+    /// Does the image contain an alpha channel
+    pub fn has_alpha(&self) -> bool {
+        self.alpha
+    }
+
+    /// Get the raw ICC profile from the `ICCP` chunk if it exists
+    pub(crate) fn icc_profile(&self) -> Option<&[u8]> {
+        self.chunks
+            .iter()
+            .find(|x| x.kind == chunk::ICCP)
+            .map(|x| x.data.as_slice())
+    }
+
+    /// Get the raw XMP packet from the `XMP ` chunk if it exists
+    pub fn xmp(&self) -> Option<&[u8]> {
+        self.chunks
+            .iter()
+            .find(|x| x.kind == chunk::XMP)
+            .map(|x| x.data.as_slice())
+    }
+
+    /// Get the Exif meta data from the parsed WebP.
+    /// * EXIF data starts directly with the TIFF header, however some writers include the JPEG
+    ///   APP1 `Exif\0\0` identifier as well so it is skipped when found.
+    pub(crate) fn exif(&self) -> Option<WebPResult<Exif>> {
+        self.chunks
+            .iter()
+            .find(|x| x.kind == chunk::EXIF)
+            .map(|chunk| {
+                match chunk.data.starts_with(b"Exif\0\0") {
+                    true => Exif::parse(&chunk.data),
+                    false => Exif::parse_tiff(&chunk.data),
+                }
+                .map_err(|e| WebPError::parse(": exif parsing").wrap(e))
+            })
+    }
+}
+
+impl Display for WebP {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for chunk in self.chunks.iter() {
+            writeln!(f, "{}: {} bytes", String::from_utf8_lossy(&chunk.kind), chunk.data.len())?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse out all the meta data related chunks for the given WebP source.
+/// Only the headers of image bitstreams are read as only the dimensions are needed.
+/// A chunk has the following structure left to right:
+/// * (4 bytes) FourCC chunk type e.g. `VP8X`
+/// * (4 bytes) Data size in Little Endian, not including the type, size or padding
+/// * (n bytes) Chunk data
+/// * (0-1 byte) Padding to an even size
+fn parse_chunks(mut reader: impl io::BufRead) -> WebPResult<Vec<Chunk>> {
+    let mut chunks = Vec::new();
+
+    loop {
+        // Stop at the end of the RIFF data
+        let eof = reader
+            .fill_buf()
+            .map_err(|e| WebPError::read_failed(": chunk search").with_io_source(e))?
+            .is_empty();
+        if eof {
+            break;
+        }
+
+        let kind: [u8; 4] = slice::read_bytes(&mut reader, 4)
+            .map_err(|e| WebPError::read_failed(": chunk type").with_io_source(e))?
+            .try_into()
+            .unwrap();
+        let len = slice::read_le_u32(&mut reader)
+            .map_err(|e| WebPError::read_failed(": chunk size").with_io_source(e))?;
+
+        // Only read what is kept, everything else is skipped without buffering
+        let keep = match kind {
+            chunk::VP8 => chunk::VP8_HEADER_LEN.min(len as usize),
+            chunk::VP8L => chunk::VP8L_HEADER_LEN.min(len as usize),
+            _ if Chunk::is_meta(&kind) => len as usize,
+            _ => 0,
+        };
+        let data = slice::read_bytes(&mut reader, keep)
+            .map_err(|e| WebPError::read_failed(": chunk data").with_io_source(e))?;
+        slice::skip_bytes(&mut reader, len as u64 - keep as u64)
+            .map_err(|e| WebPError::read_failed(": chunk data").with_io_source(e))?;
+
+        // Writers occasionally drop the padding on the final chunk so allow it to be missing
+        if len % 2 == 1 {
+            reader
+                .read(&mut [0u8; 1])
+                .map_err(|e| WebPError::read_failed(": chunk padding").with_io_source(e))?;
+        }
+
+        if Chunk::is_meta(&kind) {
+            chunks.push(Chunk::new(kind, data));
+        }
+    }
+
+    Ok(chunks)
+}
+
+/// Parse the extended format header
+/// * (1 byte)  Feature flags i.e. ICC, alpha, Exif, XMP and animation
+/// * (3 bytes) Reserved
+/// * (3 bytes) Canvas width minus one in Little Endian
+/// * (3 bytes) Canvas height minus one in Little Endian
+fn parse_vp8x(data: &[u8]) -> WebPResult<WebP> {
+    if data.len() < 10 {
+        return Err(WebPError::parse(": VP8X chunk too short"));
+    }
+    let u24 = |x: &[u8]| u32::from_le_bytes([x[0], x[1], x[2], 0]);
+
+    Ok(WebP {
+        width: u24(&data[4..7]) + 1,
+        height: u24(&data[7..10]) + 1,
+        animated: data[0] & chunk::FLAG_ANIMATION != 0,
+        alpha: data[0] & chunk::FLAG_ALPHA != 0,
+        chunks: Vec::new(),
+    })
+}
+
+/// Parse the lossy bitstream key frame header
+/// * (3 bytes) Frame tag
+/// * (3 bytes) Start code `9D 01 2A`
+/// * (2 bytes) Width in Little Endian, upper 2 bits are the horizontal scale
+/// * (2 bytes) Height in Little Endian, upper 2 bits are the vertical scale
+fn parse_vp8(data: &[u8]) -> WebPResult<WebP> {
+    if data.len() < chunk::VP8_HEADER_LEN || data[3..6] != [0x9D, 0x01, 0x2A] {
+        return Err(WebPError::parse(": VP8 key frame header invalid"));
+    }
+
+    Ok(WebP {
+        width: (u16::from_le_bytes([data[6], data[7]]) & 0x3FFF) as u32,
+        height: (u16::from_le_bytes([data[8], data[9]]) & 0x3FFF) as u32,
+        animated: false,
+        alpha: false,
+        chunks: Vec::new(),
+    })
+}
+
+/// Parse the lossless bitstream header
+/// * (1 byte)  Signature `2F`
+/// * (14 bits) Width minus one
+/// * (14 bits) Height minus one
+/// * (1 bit)   Alpha is used
+/// * (3 bits)  Version
+fn parse_vp8l(data: &[u8]) -> WebPResult<WebP> {
+    if data.len() < chunk::VP8L_HEADER_LEN || data[0] != 0x2F {
+        return Err(WebPError::parse(": VP8L header invalid"));
+    }
+    let bits = u32::from_le_bytes(data[1..5].try_into().unwrap());
+
+    Ok(WebP {
+        width: (bits & 0x3FFF) + 1,
+        height: ((bits >> 14) & 0x3FFF) + 1,
+        animated: false,
+        alpha: (bits >> 28) & 1 == 1,
+        chunks: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{container::WEBP_TEST_DATA, errors::BaseError, Field, Orientation, Tag};
+
+    #[test]
+    fn test_parse_extended() {
+        let webp = WebP::parse(io::Cursor::new(&WEBP_TEST_DATA)).unwrap();
+        assert_eq!(webp.chunks.len(), 4);
+        assert_eq!((webp.width(), webp.height()), (400, 300));
+        assert!(webp.has_alpha());
+        assert!(!webp.is_animated());
+        assert_eq!(webp.xmp(), Some(&br#"<x:xmpmeta xmlns:x="adobe:ns:meta/"/>"#[..]));
+
+        let exif = webp.exif().unwrap().unwrap();
+        assert_eq!(
+            exif.get_field(Tag::Orientation),
+            Some(Field::Orientation(Orientation::Rotate180))
+        );
+    }
+
+    #[test]
+    fn test_parse_simple_lossy() {
+        let mut data = b"RIFF\x16\x00\x00\x00WEBPVP8 \x0a\x00\x00\x00".to_vec();
+        data.extend_from_slice(&[0x50, 0x02, 0x00, 0x9D, 0x01, 0x2A, 0x40, 0x01, 0xF0, 0x00]);
+        let webp = WebP::parse(io::Cursor::new(&data)).unwrap();
+        assert_eq!((webp.width(), webp.height()), (320, 240));
+        assert!(!webp.has_alpha());
+        assert_eq!(webp.xmp(), None);
+        assert!(webp.exif().is_none());
+    }
+
+    #[test]
+    fn test_parse_icc_profile() {
+        let mut data =
+            b"RIFF\x22\x00\x00\x00WEBPICCP\x03\x00\x00\x00icc\x00VP8 \x0a\x00\x00\x00".to_vec();
+        data.extend_from_slice(&[0x50, 0x02, 0x00, 0x9D, 0x01, 0x2A, 0x40, 0x01, 0xF0, 0x00]);
+        let webp = WebP::parse(io::Cursor::new(&data)).unwrap();
+        assert_eq!((webp.width(), webp.height()), (320, 240));
+        assert_eq!(webp.icc_profile(), Some(&b"icc"[..]));
+    }
+
+    #[test]
+    fn test_parse_no_image() {
+        let err = WebP::parse(io::Cursor::new(b"RIFF\x04\x00\x00\x00WEBP")).unwrap_err();
+        assert_eq!(err.to_string(), "WebP parse failed: no image chunk found");
+    }
+
+    #[test]
+    fn test_parse_truncated_chunk() {
+        let err = WebP::parse(io::Cursor::new(&WEBP_TEST_DATA[..40])).unwrap_err();
+        assert_eq!(
+            err.all_to_string(),
+            "WebP read failed: chunk data ==> io::Error: failed to fill whole buffer"
+        );
+    }
+
+    #[test]
+    fn test_parse_chunk_size_beyond_input() {
+        let mut data = b"RIFF\xFF\xFF\xFF\xFFWEBPANIM\xF0\xFF\xFF\xFF".to_vec();
+        data.extend_from_slice(&[0x00; 6]);
+        let err = WebP::parse(io::Cursor::new(&data)).unwrap_err();
+        assert_eq!(
+            err.all_to_string(),
+            "WebP read failed: chunk data ==> io::Error: failed to skip whole buffer"
+        );
+
+        let mut data = b"RIFF\xFF\xFF\xFF\xFFWEBPVP8 \xF0\xFF\xFF\xFF".to_vec();
+        data.extend_from_slice(&[0x50, 0x02, 0x00, 0x9D, 0x01, 0x2A, 0x40, 0x01, 0xF0, 0x00]);
+        let err = WebP::parse(io::Cursor::new(&data)).unwrap_err();
+        assert_eq!(
+            err.all_to_string(),
+            "WebP read failed: chunk data ==> io::Error: failed to skip whole buffer"
+        );
+    }
+}
@@ -40,6 +40,21 @@ impl ExifError {
         ExifError::with_kind(ExifErrorKind::OffsetIsZero)
     }
 
+    /// Create a new error for an offset that points outside the TIFF data
+    pub fn offset_out_of_bounds<T: AsRef<str>>(msg: T) -> Self {
+        ExifError::with_kind(ExifErrorKind::OffsetOutOfBounds).with_msg(msg)
+    }
+
+    /// Create a new error for an IFD offset that was already visited
+    pub fn ifd_loop() -> Self {
+        ExifError::with_kind(ExifErrorKind::IfdLoop)
+    }
+
+    /// Create a new error for an IFD graph larger than allowed
+    pub fn ifd_limit() -> Self {
+        ExifError::with_kind(ExifErrorKind::IfdLimit)
+    }
+
     /// Add additional error data for output with the error message
     pub(crate) fn with_data(mut self, data: &[u8]) -> Self {
         self.data = Some(data.into());
@@ -77,6 +92,9 @@ impl fmt::Display for ExifError {
         match &self.kind {
             ExifErrorKind::Parse => write!(f, "Exif parse failed")?,
             ExifErrorKind::OffsetIsZero => write!(f, "Exif parse failed: offset is zero")?,
+            ExifErrorKind::OffsetOutOfBounds => write!(f, "Exif offset out of bounds")?,
+            ExifErrorKind::IfdLoop => write!(f, "Exif IFD loop detected")?,
+            ExifErrorKind::IfdLimit => write!(f, "Exif IFD limit exceeded")?,
         };
 
         // Display additional messaging if available
@@ -114,10 +132,23 @@ impl AsRef<dyn Error> for ExifError {
 pub enum ExifErrorKind {
     Parse,
     OffsetIsZero,
+    OffsetOutOfBounds,
+    IfdLoop,
+    IfdLimit,
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corrupt_kinds() {
+        let err = ExifError::offset_out_of_bounds(": IFD").with_data(&[0x10]);
+        assert!(matches!(err.kind(), ExifErrorKind::OffsetOutOfBounds));
+        assert_eq!(err.to_string(), "Exif offset out of bounds: IFD [10]");
+        assert_eq!(ExifError::ifd_loop().to_string(), "Exif IFD loop detected");
+        assert_eq!(ExifError::ifd_limit().to_string(), "Exif IFD limit exceeded");
+    }
 
     // #[test]
     // fn test_jpeg_use_as_result() {
@@ -0,0 +1,91 @@
+use std::{error::Error, fmt};
+
+use super::{BaseError, ContextError};
+
+#[derive(Debug)]
+#[non_exhaustive] // allow for future error fields
+pub struct IrbError {
+    pub kind: IrbErrorKind,       // extensible kind
+    pub data: Option<Box<[u8]>>,  // additional error data
+    pub msg: Option<String>,      // optional error message to include
+    source: Option<ContextError>, // optional extensible source error
+}
+
+impl IrbError {
+    pub(crate) fn new(kind: IrbErrorKind) -> Self {
+        Self { kind, data: None, msg: None, source: None }
+    }
+
+    /// Create a new error for a failed operation
+    pub fn parse<T: AsRef<str>>(msg: T) -> Self {
+        IrbError::new(IrbErrorKind::Parse).with_msg(msg)
+    }
+
+    /// Add additional error data for output with the error message
+    pub(crate) fn with_data(mut self, data: &[u8]) -> Self {
+        self.data = Some(data.into());
+        self
+    }
+
+    /// Add optional error message detail for output with the standard error messsage for this kind
+    pub(crate) fn with_msg<T: AsRef<str>>(mut self, msg: T) -> Self {
+        self.msg = Some(msg.as_ref().into());
+        self
+    }
+}
+
+impl fmt::Display for IrbError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            IrbErrorKind::Parse => write!(f, "Photoshop resource parse failed")?,
+        };
+
+        // Display additional messaging if available
+        if let Some(msg) = self.msg.as_ref() {
+            if !msg.is_empty() {
+                write!(f, "{}", msg)?;
+            };
+        };
+        if let Some(data) = self.data.as_ref() {
+            if !data.is_empty() {
+                write!(f, " {:02x?}", data)?;
+            };
+        };
+        Ok(())
+    }
+}
+
+impl BaseError for IrbError {}
+
+impl Error for IrbError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.source {
+            Some(source) => Some(source),
+            None => None,
+        }
+    }
+}
+
+// Provides a way to get the generic Error type
+impl AsRef<dyn Error> for IrbError {
+    fn as_ref(&self) -> &(dyn Error + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum IrbErrorKind {
+    Parse, // any parsing related erorrs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_irb_error_with_data() {
+        let err = IrbError::parse(": resource truncated").with_data(&[0x04, 0x04]);
+        assert_eq!(err.to_string(), "Photoshop resource parse failed: resource truncated [04, 04]");
+    }
+}
@@ -1,13 +1,16 @@
 use std::{error::Error, fmt, io};
 
-use super::{BaseError, ContextError, JpegError};
+use super::{
+    BaseError, ContextError, GifError, HeifError, IccError, JpegError, PngError, TiffError,
+    WebPError, XmpError,
+};
 
 #[derive(Debug)]
 #[non_exhaustive]
 pub struct MetaError {
     pub data: Box<[u8]>,
     pub kind: MetaErrorKind,
-    source: Option<MetaErrorSource>,
+    source: Option<Box<MetaErrorSource>>, // boxed to keep results small as sources grow
 }
 
 impl BaseError for MetaError {}
@@ -23,6 +26,13 @@ impl fmt::Display for MetaError {
         match &self.kind {
             MetaErrorKind::Read => write!(f, "Meta file read failed")?,
             MetaErrorKind::Jpeg => write!(f, "Meta jpeg parse failed")?,
+            MetaErrorKind::Tiff => write!(f, "Meta tiff parse failed")?,
+            MetaErrorKind::Png => write!(f, "Meta png parse failed")?,
+            MetaErrorKind::WebP => write!(f, "Meta webp parse failed")?,
+            MetaErrorKind::Gif => write!(f, "Meta gif parse failed")?,
+            MetaErrorKind::Heif => write!(f, "Meta heif parse failed")?,
+            MetaErrorKind::Xmp => write!(f, "Meta xmp parse failed")?,
+            MetaErrorKind::Icc => write!(f, "Meta icc parse failed")?,
             MetaErrorKind::UnknownHeader => write!(f, "Meta unknown header")?,
         };
 
@@ -36,9 +46,16 @@ impl fmt::Display for MetaError {
 
 impl Error for MetaError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match &self.source {
+        match self.source.as_deref() {
             Some(MetaErrorSource::Io(source)) => Some(source),
             Some(MetaErrorSource::JpegParse(source)) => Some(source),
+            Some(MetaErrorSource::TiffParse(source)) => Some(source),
+            Some(MetaErrorSource::PngParse(source)) => Some(source),
+            Some(MetaErrorSource::WebPParse(source)) => Some(source),
+            Some(MetaErrorSource::GifParse(source)) => Some(source),
+            Some(MetaErrorSource::HeifParse(source)) => Some(source),
+            Some(MetaErrorSource::XmpParse(source)) => Some(source),
+            Some(MetaErrorSource::IccParse(source)) => Some(source),
             None => None,
         }
     }
@@ -56,7 +73,7 @@ impl From<io::Error> for MetaError {
         Self {
             data: Box::new([]),
             kind: MetaErrorKind::Read,
-            source: Some(MetaErrorSource::Io(ContextError::from("io::Error: ", e))),
+            source: Some(Box::new(MetaErrorSource::Io(ContextError::from("io::Error: ", e)))),
         }
     }
 }
@@ -66,7 +83,77 @@ impl From<JpegError> for MetaError {
         Self {
             data: Box::new([]),
             kind: MetaErrorKind::Jpeg,
-            source: Some(MetaErrorSource::JpegParse(e)),
+            source: Some(Box::new(MetaErrorSource::JpegParse(e))),
+        }
+    }
+}
+
+impl From<TiffError> for MetaError {
+    fn from(e: TiffError) -> Self {
+        Self {
+            data: Box::new([]),
+            kind: MetaErrorKind::Tiff,
+            source: Some(Box::new(MetaErrorSource::TiffParse(e))),
+        }
+    }
+}
+
+impl From<PngError> for MetaError {
+    fn from(e: PngError) -> Self {
+        Self {
+            data: Box::new([]),
+            kind: MetaErrorKind::Png,
+            source: Some(Box::new(MetaErrorSource::PngParse(e))),
+        }
+    }
+}
+
+impl From<WebPError> for MetaError {
+    fn from(e: WebPError) -> Self {
+        Self {
+            data: Box::new([]),
+            kind: MetaErrorKind::WebP,
+            source: Some(Box::new(MetaErrorSource::WebPParse(e))),
+        }
+    }
+}
+
+impl From<GifError> for MetaError {
+    fn from(e: GifError) -> Self {
+        Self {
+            data: Box::new([]),
+            kind: MetaErrorKind::Gif,
+            source: Some(Box::new(MetaErrorSource::GifParse(e))),
+        }
+    }
+}
+
+impl From<HeifError> for MetaError {
+    fn from(e: HeifError) -> Self {
+        Self {
+            data: Box::new([]),
+            kind: MetaErrorKind::Heif,
+            source: Some(Box::new(MetaErrorSource::HeifParse(e))),
+        }
+    }
+}
+
+impl From<XmpError> for MetaError {
+    fn from(e: XmpError) -> Self {
+        Self {
+            data: Box::new([]),
+            kind: MetaErrorKind::Xmp,
+            source: Some(Box::new(MetaErrorSource::XmpParse(e))),
+        }
+    }
+}
+
+impl From<IccError> for MetaError {
+    fn from(e: IccError) -> Self {
+        Self {
+            data: Box::new([]),
+            kind: MetaErrorKind::Icc,
+            source: Some(Box::new(MetaErrorSource::IccParse(e))),
         }
     }
 }
@@ -81,6 +168,27 @@ pub enum MetaErrorKind {
     #[non_exhaustive]
     Jpeg,
 
+    #[non_exhaustive]
+    Tiff,
+
+    #[non_exhaustive]
+    Png,
+
+    #[non_exhaustive]
+    WebP,
+
+    #[non_exhaustive]
+    Gif,
+
+    #[non_exhaustive]
+    Heif,
+
+    #[non_exhaustive]
+    Xmp,
+
+    #[non_exhaustive]
+    Icc,
+
     #[non_exhaustive]
     UnknownHeader,
 }
@@ -91,6 +199,13 @@ pub enum MetaErrorKind {
 pub enum MetaErrorSource {
     Io(ContextError),
     JpegParse(JpegError),
+    TiffParse(TiffError),
+    PngParse(PngError),
+    WebPParse(WebPError),
+    GifParse(GifError),
+    HeifParse(HeifError),
+    XmpParse(XmpError),
+    IccParse(IccError),
 }
 
 #[cfg(test)]
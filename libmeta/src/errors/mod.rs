@@ -4,9 +4,18 @@ mod context;
 mod data;
 mod exif;
 mod filetype;
+mod gif;
+mod heif;
+mod icc;
+mod iptc;
+mod irb;
 mod jfif;
 mod jpeg;
 mod meta;
+mod png;
+mod tiff;
+mod webp;
+mod xmp;
 
 // Export all error types together
 pub use context::*;
@@ -14,9 +23,18 @@ pub use core::*;
 pub use data::*;
 pub use exif::*;
 pub use filetype::*;
+pub use gif::*;
+pub use heif::*;
+pub use icc::*;
+pub use iptc::*;
+pub use irb::*;
 pub use jfif::*;
 pub use jpeg::*;
 pub use meta::*;
+pub use png::*;
+pub use tiff::*;
+pub use webp::*;
+pub use xmp::*;
 
 pub trait BaseError: Error + AsRef<dyn Error> {
     fn all_to_string(&self) -> String {
@@ -0,0 +1,131 @@
+use std::{error::Error, fmt, io};
+
+use super::{BaseError, ContextError};
+
+#[derive(Debug)]
+#[non_exhaustive] // allow for future error fields
+pub struct PngError {
+    pub kind: PngErrorKind,       // extensible kind
+    pub data: Option<Box<[u8]>>,  // additional error data
+    pub msg: Option<String>,      // optional error message to include
+    source: Option<ContextError>, // optional extensible source error
+}
+
+impl PngError {
+    pub(crate) fn new(kind: PngErrorKind) -> Self {
+        Self { kind, data: None, msg: None, source: None }
+    }
+
+    /// Create a new error for a failed operation
+    pub fn parse<T: AsRef<str>>(msg: T) -> Self {
+        PngError::new(PngErrorKind::Parse).with_msg(msg)
+    }
+
+    /// Create a new error for a chunk whose CRC doesn't match its contents
+    pub fn checksum_mismatch<T: AsRef<str>>(msg: T) -> Self {
+        PngError::new(PngErrorKind::ChecksumMismatch).with_msg(msg)
+    }
+
+    /// Create a new error for a read failure
+    pub fn read_failed<T: AsRef<str>>(msg: T) -> Self {
+        PngError::new(PngErrorKind::ReadFailed).with_msg(msg)
+    }
+
+    /// Add additional error data for output with the error message
+    pub(crate) fn with_data(mut self, data: &[u8]) -> Self {
+        self.data = Some(data.into());
+        self
+    }
+
+    /// Add optional error message detail for output with the standard error messsage for this kind
+    pub(crate) fn with_msg<T: AsRef<str>>(mut self, msg: T) -> Self {
+        self.msg = Some(msg.as_ref().into());
+        self
+    }
+
+    /// Add an optional source error
+    pub(crate) fn with_io_source(self, source: io::Error) -> Self {
+        self.with_source("io::Error: ", source)
+    }
+
+    /// Add an optional source error
+    pub(crate) fn with_source<T: Error>(mut self, kind: &str, source: T) -> Self {
+        self.source = Some(ContextError::from(kind, source));
+        self
+    }
+
+    /// Add an optional source error
+    pub(crate) fn wrap<T: Error>(mut self, source: T) -> Self {
+        self.source = Some(ContextError::from("", source));
+        self
+    }
+}
+
+impl fmt::Display for PngError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            PngErrorKind::Parse => write!(f, "PNG parse failed")?,
+            PngErrorKind::ChecksumMismatch => write!(f, "PNG checksum mismatch")?,
+            PngErrorKind::ReadFailed => write!(f, "PNG read failed")?,
+        };
+
+        // Display additional messaging if available
+        if let Some(msg) = self.msg.as_ref() {
+            if !msg.is_empty() {
+                write!(f, "{}", msg)?;
+            };
+        };
+        if let Some(data) = self.data.as_ref() {
+            if !data.is_empty() {
+                write!(f, " {:02x?}", data)?;
+            };
+        };
+        Ok(())
+    }
+}
+
+impl BaseError for PngError {}
+
+impl Error for PngError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.source {
+            Some(source) => Some(source),
+            None => None,
+        }
+    }
+}
+
+// Provides a way to get the generic Error type
+impl AsRef<dyn Error> for PngError {
+    fn as_ref(&self) -> &(dyn Error + 'static) {
+        self
+    }
+}
+
+impl From<io::Error> for PngError {
+    fn from(e: io::Error) -> Self {
+        PngError::new(PngErrorKind::ReadFailed).wrap(e)
+    }
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PngErrorKind {
+    Parse,            // any parsing related erorrs
+    ChecksumMismatch, // chunk CRC doesn't match
+    ReadFailed,       // low level io errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_png_error_with_data_and_io_source() {
+        let err = PngError::parse(": invalid header")
+            .with_data(&[0x89, 0x50])
+            .with_io_source(io::Error::from(io::ErrorKind::UnexpectedEof));
+        assert_eq!(err.to_string(), "PNG parse failed: invalid header [89, 50]");
+        assert_eq!(err.source_to_string(), "io::Error: unexpected end of file");
+    }
+}
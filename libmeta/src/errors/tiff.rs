@@ -0,0 +1,124 @@
+use std::{error::Error, fmt, io};
+
+use super::{BaseError, ContextError};
+
+#[derive(Debug)]
+#[non_exhaustive] // allow for future error fields
+pub struct TiffError {
+    pub kind: TiffErrorKind,      // extensible kind
+    pub data: Option<Box<[u8]>>,  // additional error data
+    pub msg: Option<String>,      // optional error message to include
+    source: Option<ContextError>, // optional extensible source error
+}
+
+impl TiffError {
+    pub(crate) fn new(kind: TiffErrorKind) -> Self {
+        Self { kind, data: None, msg: None, source: None }
+    }
+
+    /// Create a new error for a failed operation
+    pub fn parse<T: AsRef<str>>(msg: T) -> Self {
+        TiffError::new(TiffErrorKind::Parse).with_msg(msg)
+    }
+
+    /// Create a new error for a read failure
+    pub fn read_failed<T: AsRef<str>>(msg: T) -> Self {
+        TiffError::new(TiffErrorKind::ReadFailed).with_msg(msg)
+    }
+
+    /// Add additional error data for output with the error message
+    pub(crate) fn with_data(mut self, data: &[u8]) -> Self {
+        self.data = Some(data.into());
+        self
+    }
+
+    /// Add optional error message detail for output with the standard error messsage for this kind
+    pub(crate) fn with_msg<T: AsRef<str>>(mut self, msg: T) -> Self {
+        self.msg = Some(msg.as_ref().into());
+        self
+    }
+
+    /// Add an optional source error
+    pub(crate) fn with_io_source(self, source: io::Error) -> Self {
+        self.with_source("io::Error: ", source)
+    }
+
+    /// Add an optional source error
+    pub(crate) fn with_source<T: Error>(mut self, kind: &str, source: T) -> Self {
+        self.source = Some(ContextError::from(kind, source));
+        self
+    }
+
+    /// Add an optional source error
+    pub(crate) fn wrap<T: Error>(mut self, source: T) -> Self {
+        self.source = Some(ContextError::from("", source));
+        self
+    }
+}
+
+impl fmt::Display for TiffError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            TiffErrorKind::Parse => write!(f, "TIFF parse failed")?,
+            TiffErrorKind::ReadFailed => write!(f, "TIFF read failed")?,
+        };
+
+        // Display additional messaging if available
+        if let Some(msg) = self.msg.as_ref() {
+            if !msg.is_empty() {
+                write!(f, "{}", msg)?;
+            };
+        };
+        if let Some(data) = self.data.as_ref() {
+            if !data.is_empty() {
+                write!(f, " {:02x?}", data)?;
+            };
+        };
+        Ok(())
+    }
+}
+
+impl BaseError for TiffError {}
+
+impl Error for TiffError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.source {
+            Some(source) => Some(source),
+            None => None,
+        }
+    }
+}
+
+// Provides a way to get the generic Error type
+impl AsRef<dyn Error> for TiffError {
+    fn as_ref(&self) -> &(dyn Error + 'static) {
+        self
+    }
+}
+
+impl From<io::Error> for TiffError {
+    fn from(e: io::Error) -> Self {
+        TiffError::new(TiffErrorKind::ReadFailed).wrap(e)
+    }
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TiffErrorKind {
+    Parse,      // any parsing related erorrs
+    ReadFailed, // low level io errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tiff_error_with_data_and_io_source() {
+        let err = TiffError::parse(": invalid header")
+            .with_data(&[0x49, 0x49])
+            .with_io_source(io::Error::from(io::ErrorKind::UnexpectedEof));
+        assert_eq!(err.to_string(), "TIFF parse failed: invalid header [49, 49]");
+        assert_eq!(err.source_to_string(), "io::Error: unexpected end of file");
+    }
+}
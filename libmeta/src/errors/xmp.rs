@@ -0,0 +1,106 @@
+use std::{error::Error, fmt};
+
+use super::{BaseError, ContextError};
+
+#[derive(Debug)]
+#[non_exhaustive] // allow for future error fields
+pub struct XmpError {
+    pub kind: XmpErrorKind,       // extensible kind
+    pub data: Option<Box<[u8]>>,  // additional error data
+    pub msg: Option<String>,      // optional error message to include
+    source: Option<ContextError>, // optional extensible source error
+}
+
+impl XmpError {
+    pub(crate) fn new(kind: XmpErrorKind) -> Self {
+        Self { kind, data: None, msg: None, source: None }
+    }
+
+    /// Create a new error for a failed operation
+    pub fn parse<T: AsRef<str>>(msg: T) -> Self {
+        XmpError::new(XmpErrorKind::Parse).with_msg(msg)
+    }
+
+    /// Add additional error data for output with the error message
+    pub(crate) fn with_data(mut self, data: &[u8]) -> Self {
+        self.data = Some(data.into());
+        self
+    }
+
+    /// Add optional error message detail for output with the standard error messsage for this kind
+    pub(crate) fn with_msg<T: AsRef<str>>(mut self, msg: T) -> Self {
+        self.msg = Some(msg.as_ref().into());
+        self
+    }
+
+    /// Add a quick-xml source error
+    pub(crate) fn with_xml_source(self, source: quick_xml::Error) -> Self {
+        self.with_source("quick_xml::Error: ", source)
+    }
+
+    /// Add an optional source error
+    pub(crate) fn with_source<T: Error>(mut self, kind: &str, source: T) -> Self {
+        self.source = Some(ContextError::from(kind, source));
+        self
+    }
+}
+
+impl fmt::Display for XmpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            XmpErrorKind::Parse => write!(f, "XMP parse failed")?,
+        };
+
+        // Display additional messaging if available
+        if let Some(msg) = self.msg.as_ref() {
+            if !msg.is_empty() {
+                write!(f, "{}", msg)?;
+            };
+        };
+        if let Some(data) = self.data.as_ref() {
+            if !data.is_empty() {
+                write!(f, " {:02x?}", data)?;
+            };
+        };
+        Ok(())
+    }
+}
+
+impl BaseError for XmpError {}
+
+impl Error for XmpError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.source {
+            Some(source) => Some(source),
+            None => None,
+        }
+    }
+}
+
+// Provides a way to get the generic Error type
+impl AsRef<dyn Error> for XmpError {
+    fn as_ref(&self) -> &(dyn Error + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum XmpErrorKind {
+    Parse, // any parsing related erorrs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn test_xmp_error_with_xml_source() {
+        let err = XmpError::parse(": XML").with_xml_source(quick_xml::Error::Io(
+            io::Error::from(io::ErrorKind::UnexpectedEof).into(),
+        ));
+        assert_eq!(err.to_string(), "XMP parse failed: XML");
+        assert_eq!(err.source_to_string(), "quick_xml::Error: I/O error: unexpected end of file ==> quick_xml::Error: unexpected end of file");
+    }
+}
@@ -17,7 +17,14 @@ pub(crate) const VERSION: &str = env!("CARGO_PKG_VERSION");
 pub mod prelude {
     pub use crate::container::*;
     pub use crate::errors::*;
-    //pub use crate::meta::*;
+    pub use crate::meta::{
+        CaptureSource, CaptureTime, ColorSpace, ComponentConfiguration, Contrast, CustomRendered,
+        DensityUnit, ExposureMode, ExposureProgram, Field, FileSource, Flash, Gain, GpsPosition,
+        IccColorSpace, IccProfile, IccProfileClass, IccRenderingIntent, IptcField, IptcTag,
+        JfifThumbnail, LightSource, Meta, MeteringMode, Orientation, Rational, ResolutionUnit,
+        SRational, Saturation, Scene, SceneType, SensingMethod, Sharpness, SubjectDistanceRange,
+        Tag, Vendor, WhiteBalance, Xmp, XmpProperty, XmpValue, YCbCrPositioning,
+    };
 }
 
 /// Create a new meta data instance for the given media stream
@@ -25,6 +32,12 @@ pub fn parse<T: io::BufRead + io::Seek>(reader: T) -> MetaResult<Meta> {
     Meta::parse(reader)
 }
 
+/// Decode the embedded ICC profile of the given media stream if it exists
+/// * Only the container is parsed so that damaged Exif, XMP or IPTC data doesn't get in the way
+pub fn parse_icc_profile<T: io::BufRead + io::Seek>(reader: T) -> MetaResult<Option<IccProfile>> {
+    Meta::parse_container(reader)?.icc_profile().transpose()
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -1,16 +1,24 @@
 use nom::bytes::streaming as nom_bytes;
 use nom::number::streaming as nom_nums;
-use std::fmt::Display;
+use std::{collections::HashSet, fmt::Display};
 
-use super::{Tag, Endian, Ifd, BIG_ENDIAN, EXIF_IDENTIFIER, LITTLE_ENDIAN, TIFF_VERSION};
+use super::{
+    parse_maker_note, CaptureSource, CaptureTime, Endian, Field, GpsPosition, Ifd, IfdKind, Tag,
+    Vendor, BIG_ENDIAN, EXIF_IDENTIFIER, LITTLE_ENDIAN, TIFF_VERSION,
+};
 use crate::errors::{ExifError, ExifErrorKind};
 
+/// Maximum number of IFDs to parse from a single source to bound corrupt or malicious files
+pub(crate) const MAX_IFDS: usize = 256;
+
 /// Simplify the Exif return type slightly
 pub type ExifResult<T> = Result<T, ExifError>;
 
 #[derive(Debug)]
 pub struct Exif {
     pub(crate) ifds: Vec<Ifd>,
+    pub(crate) thumbnail: Option<Vec<u8>>, // IFD1 JPEG thumbnail
+    pub(crate) skipped: Vec<(IfdKind, ExifError)>, // IFDs dropped as corrupt
 }
 
 impl Exif {
@@ -20,9 +28,16 @@ impl Exif {
     /// * *Tiff header*    | 8     | `4949 2A00 0800 0000`, 2 bytes align `0x4949` is Little-Endian, `0x4D4D` is Big-Endian
     pub(crate) fn parse(input: &[u8]) -> ExifResult<Exif> {
         let (exif_data, _) = parse_exif_header(input)?;
+        Self::parse_tiff(exif_data)
+    }
 
+    /// Parse the given TIFF data into a Exif structure
+    /// * **Field**        | **Bytes** | **Description**
+    /// * *Tiff header*    | 8     | `4949 2A00 0800 0000`, 2 bytes align `0x4949` is Little-Endian, `0x4D4D` is Big-Endian
+    /// * *IFDs*           | n     | IFD0 -> IFD1 -> ... chain with any sub IFDs they reference
+    pub(crate) fn parse_tiff(input: &[u8]) -> ExifResult<Exif> {
         // Parse TIFF alignment
-        let (remain, endian) = parse_tiff_endian(exif_data)?;
+        let (remain, endian) = parse_tiff_endian(input)?;
 
         // Parse TIFF version
         let (remain, marker) = parse_tiff_version(remain, endian)?;
@@ -31,9 +46,125 @@ impl Exif {
         }
 
         // Parse the IFDs
-        let (_, ifds) = parse_ifds(exif_data, remain, endian)?;
+        let mut skipped = Vec::new();
+        let (_, ifds) = parse_ifds(input, remain, endian, &mut skipped)?;
+        let thumbnail = parse_thumbnail(input, &ifds);
+
+        Ok(Self { ifds, thumbnail, skipped })
+    }
+
+    /// Get the GPS position from the GPS IFD if it has a valid latitude and longitude
+    pub(crate) fn gps_position(&self) -> Option<GpsPosition> {
+        let Some(Field::GpsLatitude(latitude)) = self.get_field(Tag::GpsLatitude) else {
+            return None;
+        };
+        let Some(Field::GpsLatitudeRef(lat_ref)) = self.get_field(Tag::GpsLatitudeRef) else {
+            return None;
+        };
+        let Some(Field::GpsLongitude(longitude)) = self.get_field(Tag::GpsLongitude) else {
+            return None;
+        };
+        let Some(Field::GpsLongitudeRef(lon_ref)) = self.get_field(Tag::GpsLongitudeRef) else {
+            return None;
+        };
+        let altitude = match self.get_field(Tag::GpsAltitude) {
+            Some(Field::GpsAltitude(x)) => Some(x),
+            _ => None,
+        };
+        let alt_ref = match self.get_field(Tag::GpsAltitudeRef) {
+            Some(Field::GpsAltitudeRef(x)) => Some(x),
+            _ => None,
+        };
+        GpsPosition::new(&latitude, &lat_ref, &longitude, &lon_ref, altitude, alt_ref)
+    }
+
+    /// Get the capture time from the first source with a valid date
+    /// * DateTimeOriginal, DateTimeDigitized and DateTime with their sub second and offset tags
+    /// * Falls back on the GPS date and time stamp in UTC
+    pub(crate) fn capture_time(&self) -> Option<CaptureTime> {
+        let ascii = |tag: Tag| {
+            self.ifds
+                .iter()
+                .find_map(|ifd| ifd.field_by_tag(tag))
+                .and_then(|x| x.to_ascii())
+        };
+        let sources = [
+            (
+                CaptureSource::DateTimeOriginal,
+                Tag::DateTimeOriginal,
+                Tag::SubSecTimeOriginal,
+                Tag::OffsetTimeOriginal,
+            ),
+            (
+                CaptureSource::DateTimeDigitized,
+                Tag::DateTimeDigitized,
+                Tag::SubSecTimeDigitized,
+                Tag::OffsetTimeDigitized,
+            ),
+            (CaptureSource::DateTime, Tag::DateTime, Tag::SubSecTime, Tag::OffsetTime),
+        ];
+        for (source, datetime, subsec, offset) in sources {
+            if let Some(time) = ascii(datetime).and_then(|x| {
+                CaptureTime::new(&x, ascii(subsec).as_deref(), ascii(offset).as_deref(), source)
+            }) {
+                return Some(time);
+            }
+        }
+
+        match (self.get_field(Tag::GpsDateStamp), self.get_field(Tag::GpsTimeStamp)) {
+            (Some(Field::GpsDateStamp(date)), Some(Field::GpsTimeStamp(time))) => {
+                CaptureTime::from_gps(&date, &time)
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the IFD1 JPEG thumbnail bytes if they exist and are a complete JPEG stream
+    pub(crate) fn thumbnail(&self) -> Option<&[u8]> {
+        self.thumbnail.as_deref()
+    }
 
-        Ok(Self { ifds })
+    /// Get the typed field for the given tag from the first IFD that contains it
+    pub(crate) fn get_field(&self, tag: Tag) -> Option<Field> {
+        self.ifds
+            .iter()
+            .find_map(|ifd| ifd.field_by_tag(tag))
+            .and_then(|x| x.to_field())
+    }
+
+    /// Get the number of pages i.e. image IFDs in the IFD0 -> IFD1 -> ... chain
+    pub(crate) fn page_count(&self) -> usize {
+        self.ifds
+            .iter()
+            .filter(|x| matches!(x.kind, IfdKind::Image(_)))
+            .count()
+    }
+
+    /// Get the typed field for the given tag from the given page or its sub IFDs
+    pub(crate) fn get_page_field(&self, page: usize, tag: Tag) -> Option<Field> {
+        self.ifds
+            .iter()
+            .filter(|ifd| ifd.kind.page() == page)
+            .find_map(|ifd| ifd.field_by_tag(tag))
+            .and_then(|x| x.to_field())
+    }
+
+    /// Get the vendor of the first parsed MakerNote
+    pub(crate) fn vendor(&self) -> Option<Vendor> {
+        self.ifds.iter().find_map(|ifd| match ifd.kind {
+            IfdKind::MakerNote(vendor, _) => Some(vendor),
+            _ => None,
+        })
+    }
+
+    /// Get the tags whose format or component count break the EXIF spec
+    pub(crate) fn nonconforming(&self) -> Vec<Tag> {
+        self.ifds
+            .iter()
+            .flat_map(|ifd| ifd.fields.iter())
+            .filter(|x| !x.conforms())
+            .map(|x| x.tag)
+            .collect()
     }
 }
 
@@ -51,6 +182,9 @@ impl Display for Exif {
                 writeln!(f, "  {: <32}: {}", field.tag.to_string(), field.to_string())?;
             }
         }
+        for (kind, err) in &self.skipped {
+            writeln!(f, "  {: <32}: Bad {:?} IFD, {}", "Warning", kind, err)?;
+        }
         Ok(())
     }
 }
@@ -58,37 +192,56 @@ impl Display for Exif {
 /// Parse IFDs
 /// * **input** is the full data source from tiff header alignment
 /// * **remain** starts with the ifd offset
+/// * **skipped** collects the IFDs that failed to parse so the rest of the IFDs are kept
+/// * Only a failure in IFD0 is fatal, a bad page ends the chain and a bad sub IFD is dropped
+/// * Page offsets are tracked so a loop or an excessively long chain ends it. Sub IFDs don't link
+///   to further IFDs so pages may share them freely.
 fn parse_ifds<'a>(
     input: &'a [u8],
     remain: &'a [u8],
     endian: Endian,
+    skipped: &mut Vec<(IfdKind, ExifError)>,
 ) -> ExifResult<(&'a [u8], Vec<Ifd>)> {
     let mut ifds: Vec<Ifd> = Vec::new();
+    let mut visited = HashSet::new();
 
     let mut outer = remain;
-    loop {
-        // Parse the IFD offset or end of IFDs
-        let (inner, offset) = match parse_ifd_offset(outer, endian) {
-            Ok((inner, offset)) => (inner, offset as usize),
-            Err(e) => match e.kind() {
-                ExifErrorKind::OffsetIsZero => break,
-                _ => return Err(e),
-            },
+    for page in 0.. {
+        // Parse the IFD offset or end of IFDs then the IFD at that offset
+        let parsed = parse_ifd_offset(outer, endian).and_then(|(_, offset)| {
+            visit(&mut visited, offset as usize)?;
+            Ifd::parse(input, endian, offset as usize, IfdKind::Image(page))
+        });
+        let inner = match parsed {
+            Ok((inner, ifd)) => {
+                ifds.push(ifd);
+                inner
+            }
+            Err(e) if matches!(e.kind(), ExifErrorKind::OffsetIsZero) => break,
+            Err(e) if page == 0 => return Err(e),
+            Err(e) => {
+                skipped.push((IfdKind::Image(page), e));
+                break;
+            }
         };
 
-        // Parse the IFD passing in the offset
-        let (inner, ifd) = Ifd::parse(input, inner, endian, offset)?;
-        ifds.push(ifd);
-
-        // Parse Sub IFDs
+        // Parse Sub IFDs, the interop IFD and MakerNote are referenced from the Exif IFD
         let ifd = ifds.last().unwrap();
-        if let Some(field) = ifd.field_by_tag(Tag::ExifSubIfdOffset) {
-            if let Some(offset) = field.to_unsigned() {
-                // Don't need to track location as it is in an arbitrary location
-                let (_, ifd) = Ifd::parse(input, inner, endian, offset as usize)?;
-                ifds.push(ifd);
-            }
-        }
+        let mut sub_ifd = |parent: &Ifd, tag: Tag, kind: IfdKind| {
+            parse_sub_ifd(input, parent, tag, kind, skipped)
+        };
+        let exif = sub_ifd(ifd, Tag::ExifSubIfdOffset, IfdKind::Exif(page));
+        let gps = sub_ifd(ifd, Tag::GpsSubIfdOffset, IfdKind::Gps(page));
+        let interop = exif
+            .as_ref()
+            .and_then(|x| sub_ifd(x, Tag::ExifInteroperabilityOffset, IfdKind::Interop(page)));
+        let maker = exif
+            .as_ref()
+            .and_then(|x| parse_maker_note(input, ifd, x, page, skipped));
+        ifds.extend(exif);
+        ifds.extend(interop);
+        ifds.extend(maker);
+        ifds.extend(gps);
 
         // Track location
         outer = inner;
@@ -97,6 +250,57 @@ fn parse_ifds<'a>(
     Ok((outer, ifds))
 }
 
+/// Parse the sub IFD referenced by the given offset tag in the parent IFD if it exists
+/// * **input** is the full data source from tiff header alignment
+/// * Sub IFDs are at arbitrary locations so the offset is always taken from the start of the input
+/// * Bad pointers are common in edited files so failures are recorded in **skipped** instead
+fn parse_sub_ifd(
+    input: &[u8],
+    parent: &Ifd,
+    tag: Tag,
+    kind: IfdKind,
+    skipped: &mut Vec<(IfdKind, ExifError)>,
+) -> Option<Ifd> {
+    let offset = parent.field_by_tag(tag).and_then(|x| x.to_unsigned())?;
+    match Ifd::parse(input, parent.endian, offset, kind) {
+        Ok((_, ifd)) => Some(ifd),
+        Err(e) => {
+            skipped.push((kind, e));
+            None
+        }
+    }
+}
+
+/// Track the given page offset failing if it was already visited or the chain is too long
+fn visit(visited: &mut HashSet<usize>, offset: usize) -> ExifResult<()> {
+    if visited.len() >= MAX_IFDS {
+        return Err(ExifError::ifd_limit());
+    }
+    if !visited.insert(offset) {
+        return Err(ExifError::ifd_loop().with_data(&(offset as u32).to_be_bytes()));
+    }
+    Ok(())
+}
+
+/// Parse out the JPEG thumbnail referenced by the IFD1 thumbnail offset and length
+/// * **input** is the full data source from tiff header alignment as the offset is relative to it
+/// * Thumbnails that are out of bounds or don't start with `FFD8` and end with `FFD9` are ignored
+fn parse_thumbnail(input: &[u8], ifds: &[Ifd]) -> Option<Vec<u8>> {
+    let field = |tag| {
+        ifds.iter()
+            .filter(|ifd| ifd.kind.page() == 1)
+            .find_map(|ifd| ifd.field_by_tag(tag))
+            .and_then(|x| x.to_unsigned())
+    };
+    let offset = field(Tag::ThumbnailOffset)?;
+    let length = field(Tag::ThumbnailLength)?;
+    let data = input.get(offset..offset.checked_add(length)?)?;
+    match data.starts_with(&[0xFF, 0xD8]) && data.ends_with(&[0xFF, 0xD9]) {
+        true => Some(data.to_vec()),
+        false => None,
+    }
+}
+
 /// Parse out a 4 byte value as raw data
 /// Returns: (remaining bytes, data bytes)
 pub(crate) fn parse_ifd_data(input: &[u8]) -> ExifResult<(&[u8], &[u8])> {
@@ -132,7 +336,7 @@ fn parse_exif_header(input: &[u8]) -> ExifResult<(&[u8], [u8; 4])> {
 }
 
 /// (2 bytes) Parse the TIFF header byte alignment
-fn parse_tiff_endian(input: &[u8]) -> ExifResult<(&[u8], Endian)> {
+pub(crate) fn parse_tiff_endian(input: &[u8]) -> ExifResult<(&[u8], Endian)> {
     let (remain, alignment) = nom::branch::alt((
         nom::bytes::streaming::tag::<[u8; 2], &[u8], nom::error::Error<&[u8]>>(BIG_ENDIAN),
         nom::bytes::streaming::tag::<[u8; 2], &[u8], nom::error::Error<&[u8]>>(LITTLE_ENDIAN),
@@ -163,7 +367,8 @@ fn parse_tiff_version(input: &[u8], endian: Endian) -> ExifResult<(&[u8], [u8; 2
 mod tests {
     use super::*;
     use crate::meta::exif::{format, tag};
-    use crate::test_data::EXIF_TEST_DATA;
+    use crate::test_data::{EXIF_TEST_DATA, GPS_TEST_DATA, INTEROP_TEST_DATA};
+    use crate::Rational;
     use crate::{container::JPEG_TEST_DATA, errors::BaseError};
 
     const EXIF_HEADER: [u8; 6] = [0x45, 0x78, 0x69, 0x66, 0x00, 0x00];
@@ -174,9 +379,229 @@ mod tests {
         assert_eq!(exif.ifds.len(), 3);
     }
 
+    #[test]
+    fn test_parse_ifds_out_of_order() {
+        // IFD 1 at offset 8 comes before IFD 0 at offset 14
+        let data = [
+            0x49, 0x49, 0x2a, 0x00, 0x0e, 0x00, 0x00, 0x00, // TIFF header
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // IFD 1: no fields, end of IFDs
+            0x00, 0x00, 0x08, 0x00, 0x00, 0x00, // IFD 0: no fields, next IFD 8
+        ];
+        assert_eq!(Exif::parse_tiff(&data).unwrap().page_count(), 2);
+    }
+
+    #[test]
+    fn test_parse_ifds_corrupt() {
+        // IFD 0 points back to itself
+        let data = [
+            0x49, 0x49, 0x2a, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        ];
+        let exif = Exif::parse_tiff(&data).unwrap();
+        assert_eq!(exif.page_count(), 1);
+        assert_eq!(exif.skipped.len(), 1);
+        assert!(matches!(exif.skipped[0].1.kind(), ExifErrorKind::IfdLoop));
+        assert_eq!(exif.skipped[0].1.to_string(), "Exif IFD loop detected [00, 00, 00, 08]");
+
+        // IFD 0 is past the end of the data
+        let data = [0x49, 0x49, 0x2a, 0x00, 0x00, 0x01, 0x00, 0x00];
+        let err = Exif::parse_tiff(&data).unwrap_err();
+        assert!(matches!(err.kind(), ExifErrorKind::OffsetOutOfBounds));
+
+        // Chain of IFDs longer than the limit
+        let mut data = vec![0x49, 0x49, 0x2a, 0x00, 0x08, 0x00, 0x00, 0x00];
+        for i in 0..MAX_IFDS + 1 {
+            data.extend_from_slice(&[0x00, 0x00]);
+            data.extend_from_slice(&(8 + 6 * (i as u32 + 1)).to_le_bytes());
+        }
+        let exif = Exif::parse_tiff(&data).unwrap();
+        assert_eq!(exif.page_count(), MAX_IFDS);
+        assert_eq!(exif.skipped[0].0, IfdKind::Image(MAX_IFDS));
+        assert!(matches!(exif.skipped[0].1.kind(), ExifErrorKind::IfdLimit));
+
+        // IFD 1 is past the end of the data
+        let data = [
+            0x49, 0x49, 0x2a, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        ];
+        let exif = Exif::parse_tiff(&data).unwrap();
+        assert_eq!(exif.page_count(), 1);
+        assert!(matches!(exif.skipped[0].1.kind(), ExifErrorKind::OffsetOutOfBounds));
+    }
+
+    #[test]
+    fn test_parse_shared_sub_ifd() {
+        // IFD 0 and IFD 1 both reference the Exif IFD at 44
+        let data = [
+            /* 000-007 */ 0x49, 0x49, 0x2a, 0x00, 0x08, 0x00, 0x00, 0x00, // Header
+            /* 008-009 */ 0x01, 0x00, // IFD 0: field count
+            /* 010-011 */ 0x69, 0x87, // Field 0: Exif IFD Offset
+            /* 012-013 */ 0x04, 0x00, // Field 0: Unsigned Long
+            /* 014-017 */ 0x01, 0x00, 0x00, 0x00, // Field 0: components (1)
+            /* 018-021 */ 0x2c, 0x00, 0x00, 0x00, // Field 0: data (44)
+            /* 022-025 */ 0x1a, 0x00, 0x00, 0x00, // IFD 1 offset (26)
+            /* 026-027 */ 0x01, 0x00, // IFD 1: field count
+            /* 028-029 */ 0x69, 0x87, // Field 0: Exif IFD Offset
+            /* 030-031 */ 0x04, 0x00, // Field 0: Unsigned Long
+            /* 032-035 */ 0x01, 0x00, 0x00, 0x00, // Field 0: components (1)
+            /* 036-039 */ 0x2c, 0x00, 0x00, 0x00, // Field 0: data (44)
+            /* 040-043 */ 0x00, 0x00, 0x00, 0x00, // End of IFDs
+            /* 044-045 */ 0x00, 0x00, // Exif IFD: field count
+            /* 046-049 */ 0x00, 0x00, 0x00, 0x00, // End of Exif IFD
+        ];
+        let exif = Exif::parse_tiff(&data).unwrap();
+        let kinds = exif.ifds.iter().map(|x| x.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                IfdKind::Image(0),
+                IfdKind::Exif(0),
+                IfdKind::Image(1),
+                IfdKind::Exif(1)
+            ]
+        );
+        assert!(exif.skipped.is_empty());
+    }
+
+    #[test]
+    fn test_parse_gps() {
+        let exif = Exif::parse_tiff(&GPS_TEST_DATA).unwrap();
+        assert_eq!(exif.ifds.len(), 2);
+        assert_eq!(exif.ifds[1].kind, IfdKind::Gps(0));
+        assert_eq!(exif.get_field(Tag::GpsVersionId), Some(Field::GpsVersionId(vec![2, 3, 0, 0])));
+        assert_eq!(exif.get_field(Tag::GpsLatitudeRef), Some(Field::GpsLatitudeRef("N".into())));
+        assert_eq!(exif.get_field(Tag::GpsMapDatum), Some(Field::GpsMapDatum("WGS-84".into())));
+        assert_eq!(
+            exif.get_field(Tag::GpsDateStamp),
+            Some(Field::GpsDateStamp("2024:05:04".into()))
+        );
+        assert_eq!(
+            exif.get_field(Tag::GpsTimeStamp),
+            Some(Field::GpsTimeStamp(vec![
+                Rational::new(13, 1),
+                Rational::new(5, 1),
+                Rational::new(30, 1)
+            ]))
+        );
+
+        let position = exif.gps_position().unwrap();
+        assert_eq!(position.latitude, 37.775);
+        assert!((position.longitude + 122.419166).abs() < 0.000001);
+        assert_eq!(position.altitude, Some(-52.5));
+
+        // No GPS IFD
+        let exif = Exif::parse_tiff(&EXIF_TEST_DATA).unwrap();
+        assert_eq!(exif.gps_position(), None);
+    }
+
+    #[test]
+    fn test_capture_time() {
+        let time = Exif::parse(&JPEG_TEST_DATA[24..])
+            .unwrap()
+            .capture_time()
+            .unwrap();
+        assert_eq!(time.source, CaptureSource::DateTime);
+        assert_eq!(time.to_string(), "2016-05-04T03:02:01");
+
+        // GPS only source falls back on the GPS date and time stamp
+        let time = Exif::parse_tiff(&GPS_TEST_DATA)
+            .unwrap()
+            .capture_time()
+            .unwrap();
+        assert_eq!(time.source, CaptureSource::Gps);
+        assert_eq!(time.to_string(), "2024-05-04T13:05:30Z");
+
+        // Zeroed DateTime is skipped in favour of the GPS time stamp
+        let exif = Exif::parse_tiff(&[
+            0x49, 0x49, 0x2a, 0x00, 0x08, 0x00, 0x00, 0x00, // TIFF header
+            0x01, 0x00, // field count
+            0x32, 0x01, 0x02, 0x00, 0x14, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00,
+            0x00, // DateTime
+            0x00, 0x00, 0x00, 0x00, // end of IFDs
+            0x30, 0x30, 0x30, 0x30, 0x3a, 0x30, 0x30, 0x3a, 0x30, 0x30, 0x20, 0x30, 0x30, 0x3a,
+            0x30, 0x30, 0x3a, 0x30, 0x30, 0x00, // 0000:00:00 00:00:00
+        ])
+        .unwrap();
+        assert_eq!(exif.capture_time(), None);
+    }
+
+    #[test]
+    fn test_parse_interop() {
+        let exif = Exif::parse_tiff(&INTEROP_TEST_DATA).unwrap();
+        let kinds = exif.ifds.iter().map(|x| x.kind).collect::<Vec<_>>();
+        assert_eq!(kinds, vec![IfdKind::Image(0), IfdKind::Exif(0), IfdKind::Interop(0)]);
+        assert_eq!(exif.get_field(Tag::InteropIndex), Some(Field::InteropIndex("R98".into())));
+        assert_eq!(
+            exif.get_page_field(0, Tag::InteropVersion),
+            Some(Field::InteropVersion("0100".into()))
+        );
+
+        // Interop tags are only valid inside the interop IFD
+        assert_eq!(exif.get_field(Tag::GpsLatitudeRef), None);
+    }
+
+    #[test]
+    fn test_parse_bad_sub_ifd_pointers() {
+        // Exif IFD is kept when its interop pointer is out of bounds
+        let mut data = INTEROP_TEST_DATA.to_vec();
+        data.truncate(data.len() - 20);
+        let exif = Exif::parse_tiff(&data).unwrap();
+        let kinds = exif.ifds.iter().map(|x| x.kind).collect::<Vec<_>>();
+        assert_eq!(kinds, vec![IfdKind::Image(0), IfdKind::Exif(0)]);
+        assert_eq!(exif.skipped.len(), 1);
+        assert_eq!(exif.skipped[0].0, IfdKind::Interop(0));
+
+        // IFD0 is kept when its GPS pointer is out of bounds
+        let mut data = GPS_TEST_DATA.to_vec();
+        data[18..22].copy_from_slice(&[0x00, 0x00, 0x10, 0x00]);
+        let exif = Exif::parse_tiff(&data).unwrap();
+        assert_eq!(exif.ifds.len(), 1);
+        assert!(matches!(exif.skipped[0].1.kind(), ExifErrorKind::OffsetOutOfBounds));
+        assert!(exif.to_string().contains("Warning"));
+    }
+
+    #[test]
+    fn test_nonconforming() {
+        assert_eq!(Exif::parse(&JPEG_TEST_DATA[24..]).unwrap().nonconforming(), vec![]);
+        assert_eq!(Exif::parse_tiff(&GPS_TEST_DATA).unwrap().nonconforming(), vec![]);
+        assert_eq!(
+            Exif::parse_tiff(&INTEROP_TEST_DATA)
+                .unwrap()
+                .nonconforming(),
+            vec![]
+        );
+        assert_eq!(Exif::parse_tiff(&EXIF_TEST_DATA).unwrap().nonconforming(), vec![]);
+
+        // DateTime should be 20 ASCII components
+        let data = [
+            0x49, 0x49, 0x2a, 0x00, 0x08, 0x00, 0x00, 0x00, // TIFF header
+            0x01, 0x00, // field count
+            0x32, 0x01, 0x02, 0x00, 0x04, 0x00, 0x00, 0x00, 0x32, 0x30, 0x32,
+            0x34, // DateTime
+            0x00, 0x00, 0x00, 0x00, // end of IFDs
+        ];
+        let exif = Exif::parse_tiff(&data).unwrap();
+        assert_eq!(exif.nonconforming(), vec![Tag::DateTime]);
+        assert_eq!(exif.get_field(Tag::DateTime), Some(Field::DateTime("2024".into())));
+    }
+
+    #[test]
+    fn test_parse_thumbnail() {
+        let exif = Exif::parse(&JPEG_TEST_DATA[24..]).unwrap();
+        let thumbnail = exif.thumbnail().unwrap();
+        assert_eq!(thumbnail.len(), 648);
+        assert_eq!(thumbnail[..2], [0xFF, 0xD8]);
+        assert_eq!(thumbnail[646..], [0xFF, 0xD9]);
+
+        // Truncated thumbnail data is ignored
+        let exif = Exif::parse(&JPEG_TEST_DATA[24..880]).unwrap();
+        assert_eq!(exif.thumbnail(), None);
+    }
+
     #[test]
     fn test_parse_ifds() {
-        let (_, ifds) = parse_ifds(&EXIF_TEST_DATA, &EXIF_TEST_DATA[4..], Endian::Big).unwrap();
+        let mut skipped = Vec::new();
+        let (_, ifds) =
+            parse_ifds(&EXIF_TEST_DATA, &EXIF_TEST_DATA[4..], Endian::Big, &mut skipped).unwrap();
+        assert!(skipped.is_empty());
         assert_eq!(ifds.len(), 3);
 
         // IFD 0 spot check
@@ -1,4 +1,3 @@
-use nom::bytes::streaming as nom_bytes;
 use nom::number::streaming as nom_nums;
 
 use crate::errors::ExifError;
@@ -6,12 +5,202 @@ use crate::errors::ExifError;
 use super::{
     format,
     tag::{self, *},
-    Endian, ExifResult,
+    Endian, ExifResult, IfdKind,
 };
 
-#[derive(Debug, PartialEq)]
+/// Typed value of a parsed tag
+/// * Each variant mirrors the `Tag` of the same name
+#[derive(Debug, Clone, PartialEq)]
 pub enum Field {
+    NewSubfileType(u32),
     ImageWidth(u32),
+    ImageHeight(u32),
+    BitsPerSample(Vec<u16>),
+    Compression(u16),
+    PhotometricInterpretation(u16),
+    ImageDescription(String),
+    Make(String),
+    Model(String),
+    StripOffsets(Vec<u32>),
+    Orientation(Orientation),
+    SamplesPerPixel(u16),
+    RowsPerStrip(u32),
+    StripByteCounts(Vec<u32>),
+    XResolution(Rational),
+    YResolution(Rational),
+    PlanarConfiguration(u16),
+    ResolutionUnit(ResolutionUnit),
+    PageNumber(Vec<u16>),
+    TransferFunction(Vec<u16>),
+    Software(String),
+    DateTime(String),
+    Artist(String),
+    WhitePoint(Vec<Rational>),
+    PrimaryChromaticities(Vec<Rational>),
+    TileWidth(u32),
+    TileLength(u32),
+    TileOffsets(Vec<u32>),
+    TileByteCounts(Vec<u32>),
+    ThumbnailOffset(u32),
+    ThumbnailLength(u32),
+    YCbCrCoefficients(Vec<Rational>),
+    YCbCrSubSampling(Vec<u16>),
+    YCbCrPositioning(YCbCrPositioning),
+    ReferenceBlackWhite(Vec<Rational>),
+    Copyright(String),
+    ExposureTime(Rational),
+    FNumber(Rational),
+    ExifSubIfdOffset(u32),
+    ExposureProgram(ExposureProgram),
+    SpectralSensitivity(String),
+    GpsSubIfdOffset(u32),
+    IsoSpeedRatings(Vec<u16>),
+    Oecf(Vec<u8>),
+    SensitivityType(u16),
+    StandardOutputSensitivity(u32),
+    RecommendedExposureIndex(u32),
+    IsoSpeed(u32),
+    IsoSpeedLatitudeyyy(u32),
+    IsoSpeedLatitudezzz(u32),
+    ExifVersion(String),
+    DateTimeOriginal(String),
+    DateTimeDigitized(String),
+    OffsetTime(String),
+    OffsetTimeOriginal(String),
+    OffsetTimeDigitized(String),
+    ComponentConfiguration(ComponentConfiguration),
+    CompressedBitsPerPixel(Rational),
+    ShutterSpeedValue(SRational),
+    ApexApertureValue(Rational),
+    BrightnessValue(SRational),
+    ExposureBiasValue(SRational),
+    MaxApertureValue(Rational),
+    SubjectDistance(Rational),
+    MeteringMode(MeteringMode),
+    LightSource(LightSource),
+    Flash(Flash),
+    FocalLength(Rational),
+    SubjectArea(Vec<u16>),
+    MakerNote(Vec<u8>),
+    UserComment(String),
+    SubSecTime(String),
+    SubSecTimeOriginal(String),
+    SubSecTimeDigitized(String),
+    Temperature(SRational),
+    Humidity(Rational),
+    Pressure(Rational),
+    WaterDepth(SRational),
+    Acceleration(Rational),
+    CameraElevationAngle(SRational),
+    XPTitle(String),
+    XPComment(String),
+    XPAuthor(String),
+    XPKeywords(String),
+    XPSubject(String),
+    FlashPixVersion(String),
+    ColorSpace(ColorSpace),
+    ExifImageWidth(u32),
+    ExifImageHeight(u32),
+    RelatedSoundFile(String),
+    ExifInteroperabilityOffset(u32),
+    FlashEnergy(Rational),
+    SpatialFrequencyResponse(Vec<u8>),
+    FocalPlaneXResolution(Rational),
+    FocalPlaneYResolution(Rational),
+    FocalPlaneResolutionUnit(ResolutionUnit),
+    SubjectLocation(Vec<u16>),
+    ExposureIndex(Rational),
+    SensingMethod(SensingMethod),
+    FileSource(FileSource),
+    SceneType(SceneType),
+    CfaPattern(Vec<u8>),
+    CustomRendered(CustomRendered),
+    ExposureMode(ExposureMode),
+    WhiteBalance(WhiteBalance),
+    DigitalZoomRatio(Rational),
+    FocalLengthIn35mmFormat(u16),
+    SceneCaptureType(Scene),
+    GainControl(Gain),
+    Contrast(Contrast),
+    Saturation(Saturation),
+    Sharpness(Sharpness),
+    DeviceSettingDescription(Vec<u8>),
+    SubjectDistanceRange(SubjectDistanceRange),
+    ImageUniqueID(String),
+    OwnerName(String),
+    SerialNumber(String),
+    LensSpecification(Vec<Rational>),
+    LensMake(String),
+    LensModel(String),
+    LensSerialNumber(String),
+    Title(String),
+    CompositeImage(u16),
+    SourceImageNumberOfCompositeImage(Vec<u16>),
+    SourceExposureTimesOfCompositeImage(Vec<u8>),
+    Gamma(Rational),
+    GpsVersionId(Vec<u8>),
+    GpsLatitudeRef(String),
+    GpsLatitude(Vec<Rational>),
+    GpsLongitudeRef(String),
+    GpsLongitude(Vec<Rational>),
+    GpsAltitudeRef(u8),
+    GpsAltitude(Rational),
+    GpsTimeStamp(Vec<Rational>),
+    GpsSatellites(String),
+    GpsStatus(String),
+    GpsMeasureMode(String),
+    GpsDop(Rational),
+    GpsSpeedRef(String),
+    GpsSpeed(Rational),
+    GpsTrackRef(String),
+    GpsTrack(Rational),
+    GpsImgDirectionRef(String),
+    GpsImgDirection(Rational),
+    GpsMapDatum(String),
+    GpsDestLatitudeRef(String),
+    GpsDestLatitude(Vec<Rational>),
+    GpsDestLongitudeRef(String),
+    GpsDestLongitude(Vec<Rational>),
+    GpsDestBearingRef(String),
+    GpsDestBearing(Rational),
+    GpsDestDistanceRef(String),
+    GpsDestDistance(Rational),
+    GpsProcessingMethod(String),
+    GpsAreaInformation(String),
+    GpsDateStamp(String),
+    GpsDifferential(u16),
+    GpsHPositioningError(Rational),
+    InteropIndex(String),
+    InteropVersion(String),
+    RelatedImageFileFormat(String),
+    RelatedImageWidth(u32),
+    RelatedImageHeight(u32),
+    CanonImageType(String),
+    CanonFirmwareVersion(String),
+    CanonOwnerName(String),
+    CanonSerialNumber(u32),
+    CanonLensModel(String),
+    CanonInternalSerialNumber(String),
+    NikonFocusMode(String),
+    NikonSerialNumber(String),
+    NikonLens(Vec<Rational>),
+    NikonShutterCount(u32),
+    SonyModelId(u16),
+    SonyLensType(u32),
+    SonyFocusMode(u32),
+    FujifilmInternalSerialNumber(String),
+    FujifilmQuality(String),
+    FujifilmFocusMode(u16),
+    FujifilmImageCount(u16),
+    OlympusCameraType(String),
+    OlympusCameraId(String),
+    PanasonicFocusMode(u16),
+    PanasonicInternalSerialNumber(String),
+    PanasonicLensType(String),
+    PanasonicLensSerialNumber(String),
+
+    /// Unknown tag identifier and its raw data
+    Raw(u16, Vec<u8>),
     None,
 }
 
@@ -41,11 +230,13 @@ impl IfdField {
     /// * 4 byte Offset to data value or data itself
     /// * **input** is the full data source from tiff header alignment
     /// * **remain** is where the header starts
+    /// * **kind** is the role of the parent IFD which determines the namespace of the tag
     /// * Returns: (remaining bytes, IfdField)
     pub(crate) fn parse<'a>(
         input: &'a [u8],
         remain: &'a [u8],
         endian: Endian,
+        kind: IfdKind,
     ) -> ExifResult<(&'a [u8], IfdField)> {
         // Tag: 2 bytes
         let (remain, tag) = match endian {
@@ -69,26 +260,18 @@ impl IfdField {
         .map_err(|x| ExifError::parse(": IFD field components").with_nom_source(x))?;
 
         // Create the ifd field and calculate if there is an offset to extract data from
-        let mut field = IfdField::new(endian, tag, format, components);
+        let mut field = IfdField::new(endian, kind.tag(tag), format, components);
         let remain = if field.length() > 4 {
             let (remain, offset) = super::parse_ifd_offset(remain, endian)?;
 
-            // Skip to the offset location
-            let consumed = input.len() - remain.len();
-            if consumed > offset as usize {
-                return Err(ExifError::parse(": IFD field offset is negative"));
-            }
-            let inner = if offset as usize > consumed {
-                let (inner, _) = nom_bytes::take(offset as usize - consumed)(remain)
-                    .map_err(|x| ExifError::parse(": IFD field offset").with_nom_source(x))?;
-                inner
-            } else {
-                remain
-            };
-
-            // Read the data from the offset location
-            let (_, data) = nom_bytes::take(field.length())(inner)
-                .map_err(|x| ExifError::parse(": IFD field data").with_nom_source(x))?;
+            // Read the data from the offset location which may be anywhere in the input
+            let data = (offset as usize)
+                .checked_add(field.length() as usize)
+                .and_then(|end| input.get(offset as usize..end))
+                .ok_or_else(|| {
+                    ExifError::offset_out_of_bounds(": IFD field data")
+                        .with_data(&offset.to_be_bytes())
+                })?;
 
             field.offset = Some(offset);
             field.data = Some(data.to_vec());
@@ -113,20 +296,24 @@ impl IfdField {
 
     // Calculate the length of the tag's data in number of bytes
     pub(crate) fn length(&self) -> u64 {
-        match self.format {
-            format::UNSIGNED_BYTE => self.components as u64,
-            format::ASCII_STRING => self.components as u64,
-            format::UNSIGNED_SHORT => self.components as u64 * 2,
-            format::UNSIGNED_LONG => self.components as u64 * 4,
-            format::UNSIGNED_RATIONAL => self.components as u64 * 8,
-            format::SIGNED_BYTE => self.components as u64,
-            format::UNDEFINED => self.components as u64,
-            format::SIGNED_SHORT => self.components as u64 * 2,
-            format::SIGNED_LONG => self.components as u64 * 4,
-            format::SIGNED_RATIONAL => self.components as u64 * 8,
-            format::SINGLE_FLOAT => self.components as u64 * 4,
-            format::DOUBLE_FLOAT => self.components as u64 * 8,
-            _ => 0,
+        self.components as u64 * format::size(self.format)
+    }
+
+    /// Check the field's format and component count against the EXIF spec for its tag
+    /// * Unknown tags always conform as there is nothing to check them against
+    pub(crate) fn conforms(&self) -> bool {
+        let (formats, components) = self.tag.spec();
+        (formats.is_empty() || formats.contains(&self.format))
+            && components.is_none_or(|(min, max)| (min..=max).contains(&self.components))
+    }
+
+    // Split the data into one slice per component of the field's format
+    // * Trailing bytes short of a full component are dropped rather than read out of bounds
+    fn chunks(&self) -> Option<impl Iterator<Item = &[u8]>> {
+        let data = self.data.as_ref()?;
+        match format::size(self.format) as usize {
+            0 => None,
+            size => Some(data.chunks_exact(size).take(self.components as usize)),
         }
     }
 
@@ -147,88 +334,379 @@ impl IfdField {
         }
     }
 
-    /// Convert the data to a rational number
-    /// * Note: this only gets the first rational number
+    /// Convert the data to a list of rational numbers for every component
     pub(crate) fn to_rationals(&self) -> ExifResult<Vec<Rational>> {
-        match self.data {
-            Some(ref data) => {
-                let mut rationals = Vec::new();
-                for i in (0..data.len()).step_by(8) {
-                    let rational = Rational::try_from(&data[i..i + 8], self.endian)?;
-                    rationals.push(rational);
-                }
-                Ok(rationals)
+        match self.format {
+            format::UNSIGNED_RATIONAL => match self.chunks() {
+                Some(chunks) => chunks.map(|x| Rational::try_from(x, self.endian)).collect(),
+                None => Err(ExifError::parse(": no data to convert to rational")),
+            },
+            _ => Err(ExifError::parse(": format is not a rational")
+                .with_data(&self.format.to_be_bytes())),
+        }
+    }
+
+    /// Convert the data to a list of signed rational numbers for every component
+    pub(crate) fn to_srationals(&self) -> ExifResult<Vec<SRational>> {
+        match self.format {
+            format::SIGNED_RATIONAL => match self.chunks() {
+                Some(chunks) => chunks
+                    .map(|x| SRational::try_from(x, self.endian))
+                    .collect(),
+                None => Err(ExifError::parse(": no data to convert to signed rational")),
+            },
+            _ => Err(ExifError::parse(": format is not a signed rational")
+                .with_data(&self.format.to_be_bytes())),
+        }
+    }
+
+    /// Convert the data to a list of unsigned integers for every component
+    /// * BYTE, SHORT and LONG formats are all widened to u32
+    pub(crate) fn to_unsigned_vec(&self) -> Option<Vec<u32>> {
+        let chunks = self.chunks()?;
+        match self.format {
+            format::UNSIGNED_BYTE => Some(chunks.map(|x| x[0] as u32).collect()),
+            format::UNSIGNED_SHORT => Some(
+                chunks
+                    .map(|x| match self.endian {
+                        Endian::Little => u16::from_le_bytes([x[0], x[1]]) as u32,
+                        Endian::Big => u16::from_be_bytes([x[0], x[1]]) as u32,
+                    })
+                    .collect(),
+            ),
+            format::UNSIGNED_LONG => Some(
+                chunks
+                    .map(|x| match self.endian {
+                        Endian::Little => u32::from_le_bytes([x[0], x[1], x[2], x[3]]),
+                        Endian::Big => u32::from_be_bytes([x[0], x[1], x[2], x[3]]),
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Convert the data to a string from the Windows XP UTF-16LE byte encoding
+    pub(crate) fn to_utf16(&self) -> Option<String> {
+        let data = self.data.as_ref()?;
+        let units: Vec<u16> = data
+            .chunks_exact(2)
+            .map(|x| u16::from_le_bytes([x[0], x[1]]))
+            .take_while(|&x| x != 0)
+            .collect();
+        Some(String::from_utf16_lossy(&units))
+    }
+
+    /// Convert the data to a string from the UserComment encoding
+    /// * First 8 bytes are the character code e.g. `ASCII\0\0\0`, `UNICODE\0` or all zeros
+    pub(crate) fn to_user_comment(&self) -> Option<String> {
+        let data = self.data.as_ref()?;
+        if data.len() < 8 {
+            return self.to_ascii();
+        }
+        let (code, text) = data.split_at(8);
+        let comment = match code {
+            b"UNICODE\0" => {
+                let units: Vec<u16> = text
+                    .chunks_exact(2)
+                    .map(|x| match self.endian {
+                        Endian::Little => u16::from_le_bytes([x[0], x[1]]),
+                        Endian::Big => u16::from_be_bytes([x[0], x[1]]),
+                    })
+                    .take_while(|&x| x != 0)
+                    .collect();
+                String::from_utf16_lossy(&units)
+            }
+            _ => text
+                .iter()
+                .take_while(|&&x| x != 0)
+                .map(|&x| x as char)
+                .collect(),
+        };
+        Some(comment.trim_end().to_string())
+    }
+
+    /// Convert the data into the typed field for this tag
+    pub(crate) fn to_field(&self) -> Option<Field> {
+        let ascii = || self.to_ascii();
+        let u16s = || -> Option<Vec<u16>> {
+            self.to_unsigned_vec()?
+                .into_iter()
+                .map(|x| u16::try_from(x).ok())
+                .collect()
+        };
+        let u16 = || self.to_unsigned().and_then(|x| u16::try_from(x).ok());
+        let u32 = || self.to_unsigned().and_then(|x| u32::try_from(x).ok());
+        let rational = || self.to_rationals().ok().and_then(|x| x.into_iter().next());
+        let rationals = || self.to_rationals().ok();
+        let srational = || self.to_srationals().ok().and_then(|x| x.into_iter().next());
+        let bytes = || self.data.clone();
+        let byte = || self.data.as_ref().and_then(|x| x.first().copied());
+
+        match self.tag {
+            Tag::NewSubfileType => u32().map(Field::NewSubfileType),
+            Tag::ImageWidth => u32().map(Field::ImageWidth),
+            Tag::ImageHeight => u32().map(Field::ImageHeight),
+            Tag::BitsPerSample => u16s().map(Field::BitsPerSample),
+            Tag::Compression => u16().map(Field::Compression),
+            Tag::PhotometricInterpretation => u16().map(Field::PhotometricInterpretation),
+            Tag::ImageDescription => ascii().map(Field::ImageDescription),
+            Tag::Make => ascii().map(Field::Make),
+            Tag::Model => ascii().map(Field::Model),
+            Tag::StripOffsets => self.to_unsigned_vec().map(Field::StripOffsets),
+            Tag::Orientation => u16().map(|x| Field::Orientation(x.into())),
+            Tag::SamplesPerPixel => u16().map(Field::SamplesPerPixel),
+            Tag::RowsPerStrip => u32().map(Field::RowsPerStrip),
+            Tag::StripByteCounts => self.to_unsigned_vec().map(Field::StripByteCounts),
+            Tag::XResolution => rational().map(Field::XResolution),
+            Tag::YResolution => rational().map(Field::YResolution),
+            Tag::PlanarConfiguration => u16().map(Field::PlanarConfiguration),
+            Tag::ResolutionUnit => self.to_unsigned().map(|x| Field::ResolutionUnit(x.into())),
+            Tag::PageNumber => u16s().map(Field::PageNumber),
+            Tag::TransferFunction => u16s().map(Field::TransferFunction),
+            Tag::Software => ascii().map(Field::Software),
+            Tag::DateTime => ascii().map(Field::DateTime),
+            Tag::Artist => ascii().map(Field::Artist),
+            Tag::WhitePoint => rationals().map(Field::WhitePoint),
+            Tag::PrimaryChromaticities => rationals().map(Field::PrimaryChromaticities),
+            Tag::TileWidth => u32().map(Field::TileWidth),
+            Tag::TileLength => u32().map(Field::TileLength),
+            Tag::TileOffsets => self.to_unsigned_vec().map(Field::TileOffsets),
+            Tag::TileByteCounts => self.to_unsigned_vec().map(Field::TileByteCounts),
+            Tag::ThumbnailOffset => u32().map(Field::ThumbnailOffset),
+            Tag::ThumbnailLength => u32().map(Field::ThumbnailLength),
+            Tag::YCbCrCoefficients => rationals().map(Field::YCbCrCoefficients),
+            Tag::YCbCrSubSampling => u16s().map(Field::YCbCrSubSampling),
+            Tag::YCbCrPositioning => u16().map(|x| Field::YCbCrPositioning(x.into())),
+            Tag::ReferenceBlackWhite => rationals().map(Field::ReferenceBlackWhite),
+            Tag::Copyright => ascii().map(Field::Copyright),
+            Tag::ExposureTime => rational().map(Field::ExposureTime),
+            Tag::FNumber => rational().map(Field::FNumber),
+            Tag::ExifSubIfdOffset => u32().map(Field::ExifSubIfdOffset),
+            Tag::ExposureProgram => u16().map(|x| Field::ExposureProgram(x.into())),
+            Tag::SpectralSensitivity => ascii().map(Field::SpectralSensitivity),
+            Tag::GpsSubIfdOffset => u32().map(Field::GpsSubIfdOffset),
+            Tag::IsoSpeedRatings => u16s().map(Field::IsoSpeedRatings),
+            Tag::Oecf => bytes().map(Field::Oecf),
+            Tag::SensitivityType => u16().map(Field::SensitivityType),
+            Tag::StandardOutputSensitivity => u32().map(Field::StandardOutputSensitivity),
+            Tag::RecommendedExposureIndex => u32().map(Field::RecommendedExposureIndex),
+            Tag::IsoSpeed => u32().map(Field::IsoSpeed),
+            Tag::IsoSpeedLatitudeyyy => u32().map(Field::IsoSpeedLatitudeyyy),
+            Tag::IsoSpeedLatitudezzz => u32().map(Field::IsoSpeedLatitudezzz),
+            Tag::ExifVersion => ascii().map(Field::ExifVersion),
+            Tag::DateTimeOriginal => ascii().map(Field::DateTimeOriginal),
+            Tag::DateTimeDigitized => ascii().map(Field::DateTimeDigitized),
+            Tag::OffsetTime => ascii().map(Field::OffsetTime),
+            Tag::OffsetTimeOriginal => ascii().map(Field::OffsetTimeOriginal),
+            Tag::OffsetTimeDigitized => ascii().map(Field::OffsetTimeDigitized),
+            Tag::ComponentConfiguration => bytes().map(|x| Field::ComponentConfiguration(x.into())),
+            Tag::CompressedBitsPerPixel => rational().map(Field::CompressedBitsPerPixel),
+            Tag::ShutterSpeedValue => srational().map(Field::ShutterSpeedValue),
+            Tag::ApexApertureValue => rational().map(Field::ApexApertureValue),
+            Tag::BrightnessValue => srational().map(Field::BrightnessValue),
+            Tag::ExposureBiasValue => srational().map(Field::ExposureBiasValue),
+            Tag::MaxApertureValue => rational().map(Field::MaxApertureValue),
+            Tag::SubjectDistance => rational().map(Field::SubjectDistance),
+            Tag::MeteringMode => u16().map(|x| Field::MeteringMode(x.into())),
+            Tag::LightSource => u16().map(|x| Field::LightSource(x.into())),
+            Tag::Flash => u16().map(|x| Field::Flash(x.into())),
+            Tag::FocalLength => rational().map(Field::FocalLength),
+            Tag::SubjectArea => u16s().map(Field::SubjectArea),
+            Tag::MakerNote => bytes().map(Field::MakerNote),
+            Tag::UserComment => self.to_user_comment().map(Field::UserComment),
+            Tag::SubSecTime => ascii().map(Field::SubSecTime),
+            Tag::SubSecTimeOriginal => ascii().map(Field::SubSecTimeOriginal),
+            Tag::SubSecTimeDigitized => ascii().map(Field::SubSecTimeDigitized),
+            Tag::Temperature => srational().map(Field::Temperature),
+            Tag::Humidity => rational().map(Field::Humidity),
+            Tag::Pressure => rational().map(Field::Pressure),
+            Tag::WaterDepth => srational().map(Field::WaterDepth),
+            Tag::Acceleration => rational().map(Field::Acceleration),
+            Tag::CameraElevationAngle => srational().map(Field::CameraElevationAngle),
+            Tag::XPTitle => self.to_utf16().map(Field::XPTitle),
+            Tag::XPComment => self.to_utf16().map(Field::XPComment),
+            Tag::XPAuthor => self.to_utf16().map(Field::XPAuthor),
+            Tag::XPKeywords => self.to_utf16().map(Field::XPKeywords),
+            Tag::XPSubject => self.to_utf16().map(Field::XPSubject),
+            Tag::FlashPixVersion => ascii().map(Field::FlashPixVersion),
+            Tag::ColorSpace => u16().map(|x| Field::ColorSpace(x.into())),
+            Tag::ExifImageWidth => u32().map(Field::ExifImageWidth),
+            Tag::ExifImageHeight => u32().map(Field::ExifImageHeight),
+            Tag::RelatedSoundFile => ascii().map(Field::RelatedSoundFile),
+            Tag::ExifInteroperabilityOffset => u32().map(Field::ExifInteroperabilityOffset),
+            Tag::FlashEnergy => rational().map(Field::FlashEnergy),
+            Tag::SpatialFrequencyResponse => bytes().map(Field::SpatialFrequencyResponse),
+            Tag::FocalPlaneXResolution => rational().map(Field::FocalPlaneXResolution),
+            Tag::FocalPlaneYResolution => rational().map(Field::FocalPlaneYResolution),
+            Tag::FocalPlaneResolutionUnit => self
+                .to_unsigned()
+                .map(|x| Field::FocalPlaneResolutionUnit(x.into())),
+            Tag::SubjectLocation => u16s().map(Field::SubjectLocation),
+            Tag::ExposureIndex => rational().map(Field::ExposureIndex),
+            Tag::SensingMethod => u16().map(|x| Field::SensingMethod(x.into())),
+            Tag::FileSource => byte().map(|x| Field::FileSource(x.into())),
+            Tag::SceneType => byte().map(|x| Field::SceneType(x.into())),
+            Tag::CfaPattern => bytes().map(Field::CfaPattern),
+            Tag::CustomRendered => u16().map(|x| Field::CustomRendered(x.into())),
+            Tag::ExposureMode => u16().map(|x| Field::ExposureMode(x.into())),
+            Tag::WhiteBalance => u16().map(|x| Field::WhiteBalance(x.into())),
+            Tag::DigitalZoomRatio => rational().map(Field::DigitalZoomRatio),
+            Tag::FocalLengthIn35mmFormat => u16().map(Field::FocalLengthIn35mmFormat),
+            Tag::SceneCaptureType => u16().map(|x| Field::SceneCaptureType(x.into())),
+            Tag::GainControl => u16().map(|x| Field::GainControl(x.into())),
+            Tag::Contrast => u16().map(|x| Field::Contrast(x.into())),
+            Tag::Saturation => u16().map(|x| Field::Saturation(x.into())),
+            Tag::Sharpness => u16().map(|x| Field::Sharpness(x.into())),
+            Tag::DeviceSettingDescription => bytes().map(Field::DeviceSettingDescription),
+            Tag::SubjectDistanceRange => u16().map(|x| Field::SubjectDistanceRange(x.into())),
+            Tag::ImageUniqueID => ascii().map(Field::ImageUniqueID),
+            Tag::OwnerName => ascii().map(Field::OwnerName),
+            Tag::SerialNumber => ascii().map(Field::SerialNumber),
+            Tag::LensSpecification => rationals().map(Field::LensSpecification),
+            Tag::LensMake => ascii().map(Field::LensMake),
+            Tag::LensModel => ascii().map(Field::LensModel),
+            Tag::LensSerialNumber => ascii().map(Field::LensSerialNumber),
+            Tag::Title => ascii().map(Field::Title),
+            Tag::CompositeImage => u16().map(Field::CompositeImage),
+            Tag::SourceImageNumberOfCompositeImage => {
+                u16s().map(Field::SourceImageNumberOfCompositeImage)
+            }
+            Tag::SourceExposureTimesOfCompositeImage => {
+                bytes().map(Field::SourceExposureTimesOfCompositeImage)
             }
-            None => Err(ExifError::parse(": no data to convert to rational")),
+            Tag::Gamma => rational().map(Field::Gamma),
+            Tag::GpsVersionId => bytes().map(Field::GpsVersionId),
+            Tag::GpsLatitudeRef => ascii().map(Field::GpsLatitudeRef),
+            Tag::GpsLatitude => rationals().map(Field::GpsLatitude),
+            Tag::GpsLongitudeRef => ascii().map(Field::GpsLongitudeRef),
+            Tag::GpsLongitude => rationals().map(Field::GpsLongitude),
+            Tag::GpsAltitudeRef => byte().map(Field::GpsAltitudeRef),
+            Tag::GpsAltitude => rational().map(Field::GpsAltitude),
+            Tag::GpsTimeStamp => rationals().map(Field::GpsTimeStamp),
+            Tag::GpsSatellites => ascii().map(Field::GpsSatellites),
+            Tag::GpsStatus => ascii().map(Field::GpsStatus),
+            Tag::GpsMeasureMode => ascii().map(Field::GpsMeasureMode),
+            Tag::GpsDop => rational().map(Field::GpsDop),
+            Tag::GpsSpeedRef => ascii().map(Field::GpsSpeedRef),
+            Tag::GpsSpeed => rational().map(Field::GpsSpeed),
+            Tag::GpsTrackRef => ascii().map(Field::GpsTrackRef),
+            Tag::GpsTrack => rational().map(Field::GpsTrack),
+            Tag::GpsImgDirectionRef => ascii().map(Field::GpsImgDirectionRef),
+            Tag::GpsImgDirection => rational().map(Field::GpsImgDirection),
+            Tag::GpsMapDatum => ascii().map(Field::GpsMapDatum),
+            Tag::GpsDestLatitudeRef => ascii().map(Field::GpsDestLatitudeRef),
+            Tag::GpsDestLatitude => rationals().map(Field::GpsDestLatitude),
+            Tag::GpsDestLongitudeRef => ascii().map(Field::GpsDestLongitudeRef),
+            Tag::GpsDestLongitude => rationals().map(Field::GpsDestLongitude),
+            Tag::GpsDestBearingRef => ascii().map(Field::GpsDestBearingRef),
+            Tag::GpsDestBearing => rational().map(Field::GpsDestBearing),
+            Tag::GpsDestDistanceRef => ascii().map(Field::GpsDestDistanceRef),
+            Tag::GpsDestDistance => rational().map(Field::GpsDestDistance),
+            Tag::GpsProcessingMethod => self.to_user_comment().map(Field::GpsProcessingMethod),
+            Tag::GpsAreaInformation => self.to_user_comment().map(Field::GpsAreaInformation),
+            Tag::GpsDateStamp => ascii().map(Field::GpsDateStamp),
+            Tag::GpsDifferential => u16().map(Field::GpsDifferential),
+            Tag::GpsHPositioningError => rational().map(Field::GpsHPositioningError),
+            Tag::InteropIndex => ascii().map(Field::InteropIndex),
+            Tag::InteropVersion => ascii().map(Field::InteropVersion),
+            Tag::RelatedImageFileFormat => ascii().map(Field::RelatedImageFileFormat),
+            Tag::RelatedImageWidth => u32().map(Field::RelatedImageWidth),
+            Tag::RelatedImageHeight => u32().map(Field::RelatedImageHeight),
+            Tag::CanonImageType => ascii().map(Field::CanonImageType),
+            Tag::CanonFirmwareVersion => ascii().map(Field::CanonFirmwareVersion),
+            Tag::CanonOwnerName => ascii().map(Field::CanonOwnerName),
+            Tag::CanonSerialNumber => u32().map(Field::CanonSerialNumber),
+            Tag::CanonLensModel => ascii().map(Field::CanonLensModel),
+            Tag::CanonInternalSerialNumber => ascii().map(Field::CanonInternalSerialNumber),
+            Tag::NikonFocusMode => ascii().map(Field::NikonFocusMode),
+            Tag::NikonSerialNumber => ascii().map(Field::NikonSerialNumber),
+            Tag::NikonLens => rationals().map(Field::NikonLens),
+            Tag::NikonShutterCount => u32().map(Field::NikonShutterCount),
+            Tag::SonyModelId => u16().map(Field::SonyModelId),
+            Tag::SonyLensType => u32().map(Field::SonyLensType),
+            Tag::SonyFocusMode => u32().map(Field::SonyFocusMode),
+            Tag::FujifilmInternalSerialNumber => ascii().map(Field::FujifilmInternalSerialNumber),
+            Tag::FujifilmQuality => ascii().map(Field::FujifilmQuality),
+            Tag::FujifilmFocusMode => u16().map(Field::FujifilmFocusMode),
+            Tag::FujifilmImageCount => u16().map(Field::FujifilmImageCount),
+            Tag::OlympusCameraType => ascii().map(Field::OlympusCameraType),
+            Tag::OlympusCameraId => ascii().map(Field::OlympusCameraId),
+            Tag::PanasonicFocusMode => u16().map(Field::PanasonicFocusMode),
+            Tag::PanasonicInternalSerialNumber => ascii().map(Field::PanasonicInternalSerialNumber),
+            Tag::PanasonicLensType => ascii().map(Field::PanasonicLensType),
+            Tag::PanasonicLensSerialNumber => ascii().map(Field::PanasonicLensSerialNumber),
+            Tag::Raw(id) => bytes().map(|x| Field::Raw(id, x)),
         }
     }
 
-    /// Convert the data to an signed integer
-    pub(crate) fn to_signed(&self) -> Option<isize> {
-        match self.data {
-            Some(ref data) => match self.format {
-                format::SIGNED_BYTE => match data.len() {
-                    1.. => Some(data[0] as isize),
-                    _ => None,
-                },
-                format::SIGNED_SHORT => match data.len() {
-                    2.. => {
-                        if self.endian == Endian::Little {
-                            Some(u16::from_le_bytes(data[0..2].try_into().unwrap()) as isize)
-                        } else {
-                            Some(u16::from_be_bytes(data[0..2].try_into().unwrap()) as isize)
-                        }
-                    }
-                    _ => None,
-                },
-                format::SIGNED_LONG => match data.len() {
-                    4.. => {
-                        if self.endian == Endian::Little {
-                            Some(u32::from_le_bytes(data[0..4].try_into().unwrap()) as isize)
-                        } else {
-                            Some(u32::from_be_bytes(data[0..4].try_into().unwrap()) as isize)
+    /// Convert the data to a list of signed integers for every component
+    /// * SBYTE, SSHORT and SLONG formats are reinterpreted as two's complement and widened to i32
+    pub(crate) fn to_signed_vec(&self) -> Option<Vec<i32>> {
+        let chunks = self.chunks()?;
+        match self.format {
+            format::SIGNED_BYTE => Some(chunks.map(|x| x[0] as i8 as i32).collect()),
+            format::SIGNED_SHORT => Some(
+                chunks
+                    .map(|x| match self.endian {
+                        Endian::Little => i16::from_le_bytes([x[0], x[1]]) as i32,
+                        Endian::Big => i16::from_be_bytes([x[0], x[1]]) as i32,
+                    })
+                    .collect(),
+            ),
+            format::SIGNED_LONG => Some(
+                chunks
+                    .map(|x| match self.endian {
+                        Endian::Little => i32::from_le_bytes([x[0], x[1], x[2], x[3]]),
+                        Endian::Big => i32::from_be_bytes([x[0], x[1], x[2], x[3]]),
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Convert the data to a list of single precision floats for every component
+    pub(crate) fn to_f32s(&self) -> Option<Vec<f32>> {
+        match self.format {
+            format::SINGLE_FLOAT => Some(
+                self.chunks()?
+                    .map(|x| match self.endian {
+                        Endian::Little => f32::from_le_bytes([x[0], x[1], x[2], x[3]]),
+                        Endian::Big => f32::from_be_bytes([x[0], x[1], x[2], x[3]]),
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Convert the data to a list of double precision floats for every component
+    /// * FLOAT components are widened to f64
+    pub(crate) fn to_f64s(&self) -> Option<Vec<f64>> {
+        match self.format {
+            format::SINGLE_FLOAT => self
+                .to_f32s()
+                .map(|x| x.into_iter().map(|v| v as f64).collect()),
+            format::DOUBLE_FLOAT => Some(
+                self.chunks()?
+                    .map(|x| {
+                        let bytes = x.try_into().unwrap();
+                        match self.endian {
+                            Endian::Little => f64::from_le_bytes(bytes),
+                            Endian::Big => f64::from_be_bytes(bytes),
                         }
-                    }
-                    _ => None,
-                },
-                _ => None,
-            },
-            None => None,
+                    })
+                    .collect(),
+            ),
+            _ => None,
         }
     }
 
     /// Convert the data to an unsigned integer
+    /// * Note: this only gets the first component, see `to_unsigned_vec` for all of them
     pub(crate) fn to_unsigned(&self) -> Option<usize> {
-        match self.data {
-            Some(ref data) => match self.format {
-                format::UNSIGNED_BYTE => match data.len() {
-                    1.. => Some(data[0] as usize),
-                    _ => None,
-                },
-                format::UNSIGNED_SHORT => match data.len() {
-                    2.. => {
-                        if self.endian == Endian::Little {
-                            Some(u16::from_le_bytes(data[0..2].try_into().unwrap()) as usize)
-                        } else {
-                            Some(u16::from_be_bytes(data[0..2].try_into().unwrap()) as usize)
-                        }
-                    }
-                    _ => None,
-                },
-                format::UNSIGNED_LONG => match data.len() {
-                    4.. => {
-                        if self.endian == Endian::Little {
-                            Some(u32::from_le_bytes(data[0..4].try_into().unwrap()) as usize)
-                        } else {
-                            Some(u32::from_be_bytes(data[0..4].try_into().unwrap()) as usize)
-                        }
-                    }
-                    _ => None,
-                },
-                _ => None,
-            },
-            None => None,
-        }
+        self.to_unsigned_vec()?.first().map(|&x| x as usize)
     }
 
     /// Convert the data type into a human readable string
@@ -242,10 +720,59 @@ impl IfdField {
             Tag::SceneCaptureType=> self.to_unsigned().map(|x| Scene::from(x).to_string()),
             Tag::GainControl=> self.to_unsigned().map(|x| Gain::from(x).to_string()),
 
+            Tag::ExposureProgram => self
+                .to_unsigned()
+                .map(|x| ExposureProgram::from(x).to_string()),
+            Tag::MeteringMode => self
+                .to_unsigned()
+                .map(|x| MeteringMode::from(x).to_string()),
+            Tag::LightSource => self.to_unsigned().map(|x| LightSource::from(x).to_string()),
+            Tag::Flash => self.to_unsigned().map(|x| Flash::from(x).to_string()),
+            Tag::ColorSpace => self.to_unsigned().map(|x| ColorSpace::from(x).to_string()),
+            Tag::SensingMethod => self
+                .to_unsigned()
+                .map(|x| SensingMethod::from(x).to_string()),
+            Tag::FileSource => self
+                .data
+                .as_ref()
+                .and_then(|x| x.first())
+                .map(|&x| FileSource::from(x).to_string()),
+            Tag::SceneType => self
+                .data
+                .as_ref()
+                .and_then(|x| x.first())
+                .map(|&x| SceneType::from(x).to_string()),
+            Tag::ExposureMode => self
+                .to_unsigned()
+                .map(|x| ExposureMode::from(x).to_string()),
+            Tag::WhiteBalance => self
+                .to_unsigned()
+                .map(|x| WhiteBalance::from(x).to_string()),
+            Tag::CustomRendered => self
+                .to_unsigned()
+                .map(|x| CustomRendered::from(x).to_string()),
+            Tag::SubjectDistanceRange => self
+                .to_unsigned()
+                .map(|x| SubjectDistanceRange::from(x).to_string()),
+            Tag::MakerNote => Some(format!("(Binary data {} bytes)", self.length())),
+            Tag::ComponentConfiguration => self
+                .data
+                .clone()
+                .map(|x| ComponentConfiguration::from(x).to_string()),
+
+            // Exposure time as a fraction of a second and f-number as exiftool renders them
+            Tag::ExposureTime => self
+                .to_rationals()
+                .ok()
+                .and_then(|x| exposure_time(x.first()?)),
+            Tag::FNumber => self.to_rationals().ok().and_then(|x| f_number(x.first()?)),
+
             // Lens specification consists of 4 rational numbers
-            // tag::LENS_SPECIFICATION => self.to_rationals().ok().map(|x| {
-            //     Gain::from(x).to_string()
-            // }),
+            Tag::LensSpecification | Tag::NikonLens => self
+                .to_rationals()
+                .ok()
+                .and_then(|x| LensSpec::new(&x))
+                .map(|x| x.to_string()),
             Tag::ResolutionUnit=> self.to_unsigned()
                 .map(|x| ResolutionUnit::from(x).to_string()),
             Tag::YCbCrPositioning=> self.to_unsigned()
@@ -254,18 +781,16 @@ impl IfdField {
             // Try by format type
             _ => match self.format {
                 format::ASCII_STRING => self.to_ascii(),
-                format::UNSIGNED_BYTE => self.to_unsigned().map(|v| v.to_string()),
-                format::UNSIGNED_SHORT => self.to_unsigned().map(|v| v.to_string()),
-                format::UNSIGNED_LONG => self.to_unsigned().map(|v| v.to_string()),
-                format::UNSIGNED_RATIONAL => self.to_rationals().ok().map(|v| {
-                    v.iter().map(|r| r.to_string()).collect::<Vec<String>>().join(", ")
-                }),
-                format::SIGNED_BYTE => self.to_signed().map(|v| v.to_string()),
-                format::SIGNED_SHORT => self.to_signed().map(|v| v.to_string()),
-                format::SIGNED_LONG => self.to_signed().map(|v| v.to_string()),
-                // format::SIGNED_RATIONAL => self.to_rational().map(|(n, d)| format!("{}/{}", n, d)),
-                // format::SINGLE_FLOAT => self.to_unsigned().map(|v| v.to_string()),
-                // format::DOUBLE_FLOAT => self.to_unsigned().map(|v| v.to_string()),
+                format::UNSIGNED_BYTE | format::UNSIGNED_SHORT | format::UNSIGNED_LONG => {
+                    self.to_unsigned_vec().map(|v| join(&v))
+                }
+                format::UNSIGNED_RATIONAL => self.to_rationals().ok().map(|v| join(&v)),
+                format::SIGNED_BYTE | format::SIGNED_SHORT | format::SIGNED_LONG => {
+                    self.to_signed_vec().map(|v| join(&v))
+                }
+                format::SIGNED_RATIONAL => self.to_srationals().ok().map(|v| join(&v)),
+                format::SINGLE_FLOAT => self.to_f32s().map(|v| join(&v)),
+                format::DOUBLE_FLOAT => self.to_f64s().map(|v| join(&v)),
                 format::UNDEFINED => self.to_ascii(),
                 _ => None,
             },
@@ -285,9 +810,40 @@ impl IfdField {
     }
 }
 
+// Join every component into a comma separated string
+fn join<T: std::fmt::Display>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+// Render the exposure time in seconds the way exiftool does i.e. as a fraction up to a quarter
+// second e.g. `1/250 s` otherwise to a single decimal place e.g. `0.7 s` or `30 s`
+fn exposure_time(value: &Rational) -> Option<String> {
+    match (value.num, value.den) {
+        (_, 0) => None,
+        (0, _) => Some("0 s".to_string()),
+        (num, den) if num as f64 / den as f64 <= 0.25001 => {
+            Some(format!("1/{} s", (den as f64 / num as f64).round()))
+        }
+        (num, den) => {
+            let secs = format!("{:.1}", num as f64 / den as f64);
+            Some(format!("{} s", secs.strip_suffix(".0").unwrap_or(&secs)))
+        }
+    }
+}
+
+// Render the f-number to a single decimal place e.g. `f/2.8`
+fn f_number(value: &Rational) -> Option<String> {
+    value.to_f64().map(|x| format!("f/{:.1}", x))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::errors::ExifErrorKind;
     use crate::exif::tag;
 
     const IFD_LE: [u8; 42] = [
@@ -320,7 +876,8 @@ mod tests {
             0x00, 0x00, 0x00, 0x00, 0x01, // data
         ];
 
-        let (remain, ifd) = IfdField::parse(data, &data[10..], Endian::Big).unwrap();
+        let (remain, ifd) =
+            IfdField::parse(data, &data[10..], Endian::Big, IfdKind::Image(0)).unwrap();
         assert_eq!(remain, &data[22..]);
         assert_eq!(ifd.tag, Tag::from(270));
         assert_eq!(ifd.format, 2);
@@ -332,7 +889,8 @@ mod tests {
 
     #[test]
     fn test_parse_ifd_field_little_endian() {
-        let (remain, ifd) = IfdField::parse(&IFD_LE, &IFD_LE[10..], Endian::Little).unwrap();
+        let (remain, ifd) =
+            IfdField::parse(&IFD_LE, &IFD_LE[10..], Endian::Little, IfdKind::Image(0)).unwrap();
         assert_eq!(remain, &IFD_LE[22..]);
         assert_eq!(ifd.tag, Tag::from(282));
         assert_eq!(ifd.format, 5);
@@ -343,17 +901,40 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_ifd_offset_negative() {
+    fn test_parse_ifd_offset_before_field() {
         let data = &[
             0x01, 0x0e, // tag
             0x00, 0x02, // data format
             0x00, 0x00, 0x00, 0x05, // number of components
-            0x00, 0x00, 0x00, 0x01, // invalid offset
+            0x00, 0x00, 0x00, 0x01, // offset back into the field header
             0x00, 0x00, 0x00, 0x00, 0x01, // data
         ];
 
-        let err = IfdField::parse(data, data, Endian::Big).unwrap_err();
-        assert_eq!(err.to_string(), "Exif parse failed: IFD field offset is negative");
+        let (_, field) = IfdField::parse(data, data, Endian::Big, IfdKind::Image(0)).unwrap();
+        assert_eq!(field.offset, Some(1));
+        assert_eq!(field.data, Some(data[1..6].to_vec()));
+    }
+
+    #[test]
+    fn test_parse_ifd_offset_out_of_bounds() {
+        let data = &[
+            0x01, 0x0e, // tag
+            0x00, 0x02, // data format
+            0x00, 0x00, 0x00, 0x05, // number of components
+            0x00, 0x00, 0x00, 0x0e, // offset leaving only 3 bytes
+            0x00, 0x00, 0x01, // data
+        ];
+
+        let err = IfdField::parse(data, data, Endian::Big, IfdKind::Image(0)).unwrap_err();
+        assert!(matches!(err.kind(), ExifErrorKind::OffsetOutOfBounds));
+        assert_eq!(err.to_string(), "Exif offset out of bounds: IFD field data [00, 00, 00, 0e]");
+
+        // Component counts that overflow the length are caught as well
+        let data = &[
+            0x01, 0x0e, 0x00, 0x0c, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x0c,
+        ];
+        let err = IfdField::parse(data, data, Endian::Big, IfdKind::Image(0)).unwrap_err();
+        assert!(matches!(err.kind(), ExifErrorKind::OffsetOutOfBounds));
     }
 
     #[test]
@@ -366,6 +947,103 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_data_to_signed() {
+        assert_eq!(
+            IfdField::new(Endian::Big, 0, format::SIGNED_BYTE, 2)
+                .with_data(&[0xFE, 0x02, 0x00, 0x00])
+                .to_signed_vec(),
+            Some(vec![-2, 2])
+        );
+        assert_eq!(
+            IfdField::new(Endian::Little, 0, format::SIGNED_SHORT, 2)
+                .with_data(&[0xFF, 0xFF, 0x00, 0x80])
+                .to_signed_vec(),
+            Some(vec![-1, -32768])
+        );
+        let field = IfdField::new(Endian::Big, 0, format::SIGNED_LONG, 1)
+            .with_data(&[0xFF, 0xFF, 0xFF, 0xFA]);
+        assert_eq!(field.to_signed_vec(), Some(vec![-6]));
+        assert_eq!(field.to_string(), "-6");
+        assert_eq!(
+            IfdField::new(Endian::Big, 0, format::UNSIGNED_SHORT, 1)
+                .with_data(&[0x00, 0x01, 0x00, 0x00])
+                .to_signed_vec(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_data_to_floats() {
+        let field = IfdField::new(Endian::Little, 0, format::SINGLE_FLOAT, 2)
+            .with_data(&[0x00, 0x00, 0xC0, 0x3F, 0x00, 0x00, 0x20, 0xC1]);
+        assert_eq!(field.to_f32s(), Some(vec![1.5, -10.0]));
+        assert_eq!(field.to_f64s(), Some(vec![1.5, -10.0]));
+        assert_eq!(field.to_string(), "1.5, -10");
+
+        let field = IfdField::new(Endian::Big, 0, format::DOUBLE_FLOAT, 1)
+            .with_data(&[0xBF, 0xD0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(field.to_f32s(), None);
+        assert_eq!(field.to_f64s(), Some(vec![-0.25]));
+        assert_eq!(field.to_string(), "-0.25");
+    }
+
+    #[test]
+    fn test_data_to_srational_string() {
+        assert_eq!(
+            IfdField::new(Endian::Little, Tag::ShutterSpeedValue, format::SIGNED_RATIONAL, 2)
+                .with_data(&[
+                    0xF6, 0xFF, 0xFF, 0xFF, 0x03, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x01,
+                    0x00, 0x00, 0x00,
+                ])
+                .to_string(),
+            "-10/3, 7"
+        );
+    }
+
+    #[test]
+    fn test_data_to_vecs() {
+        let field = IfdField::new(Endian::Big, Tag::StripOffsets, format::UNSIGNED_LONG, 3)
+            .with_data(&[
+                0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00,
+            ]);
+        assert_eq!(field.to_unsigned_vec(), Some(vec![8, 256, 65536]));
+        assert_eq!(field.to_unsigned(), Some(8));
+        assert_eq!(field.to_string(), "8, 256, 65536");
+
+        // Short data only yields the whole components
+        let field = IfdField::new(Endian::Little, Tag::SubjectArea, format::UNSIGNED_SHORT, 4)
+            .with_data(&[0x10, 0x00, 0x20, 0x00, 0x30]);
+        assert_eq!(field.to_field(), Some(Field::SubjectArea(vec![16, 32])));
+
+        // Unknown formats have no components
+        let field = IfdField::new(Endian::Big, 0, 0x0D, 1).with_data(&[0x01, 0x02]);
+        assert_eq!(field.length(), 0);
+        assert_eq!(field.to_unsigned_vec(), None);
+    }
+
+    #[test]
+    fn test_data_to_rationals() {
+        let field =
+            IfdField::new(Endian::Big, Tag::LensSpecification, format::UNSIGNED_RATIONAL, 4)
+                .with_data(&[
+                    0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x01, // 24
+                    0x00, 0x00, 0x00, 0x46, 0x00, 0x00, 0x00, 0x01, // 70
+                    0x00, 0x00, 0x00, 0x1C, 0x00, 0x00, 0x00, 0x0A, // 2.8
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // unknown
+                    0x00, 0x00, 0x00, // trailing bytes
+                ]);
+        assert_eq!(field.to_rationals().unwrap().len(), 4);
+        assert_eq!(field.to_string(), "24-70 mm f/2.8");
+
+        // Rationals must be read from a rational format
+        let field = IfdField::new(Endian::Big, Tag::XResolution, format::UNSIGNED_LONG, 2)
+            .with_data(&[0x00, 0x00, 0x00, 0x48, 0x00, 0x00, 0x00, 0x01]);
+        let err = field.to_rationals().unwrap_err();
+        assert_eq!(err.to_string(), "Exif parse failed: format is not a rational [00, 04]");
+        assert!(field.to_srationals().is_err());
+    }
+
     #[test]
     fn test_data_to_rational() {
         assert_eq!(
@@ -377,6 +1055,144 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_to_field() {
+        assert_eq!(
+            IfdField::new(Endian::Big, Tag::ExposureBiasValue, format::SIGNED_RATIONAL, 1)
+                .with_data(&[0xFF, 0xFF, 0xFF, 0xFD, 0x00, 0x00, 0x00, 0x03])
+                .to_field(),
+            Some(Field::ExposureBiasValue(SRational::new(-3, 3)))
+        );
+        assert_eq!(
+            IfdField::new(Endian::Little, Tag::BitsPerSample, format::UNSIGNED_SHORT, 3)
+                .with_data(&[0x08, 0x00, 0x08, 0x00, 0x08, 0x00])
+                .to_field(),
+            Some(Field::BitsPerSample(vec![8, 8, 8]))
+        );
+        assert_eq!(
+            IfdField::new(Endian::Big, Tag::Orientation, format::UNSIGNED_SHORT, 1)
+                .with_data(&[0x00, 0x06, 0x00, 0x00])
+                .to_field(),
+            Some(Field::Orientation(Orientation::Rotate90CW))
+        );
+        assert_eq!(
+            IfdField::new(Endian::Little, Tag::XPAuthor, format::UNSIGNED_BYTE, 6)
+                .with_data(&[0x4a, 0x00, 0x6f, 0x00, 0x00, 0x00])
+                .to_field(),
+            Some(Field::XPAuthor("Jo".into()))
+        );
+        assert_eq!(
+            IfdField::new(Endian::Big, Tag::UserComment, format::UNDEFINED, 11)
+                .with_data(&[0x41, 0x53, 0x43, 0x49, 0x49, 0x00, 0x00, 0x00, 0x48, 0x69, 0x20])
+                .to_field(),
+            Some(Field::UserComment("Hi".into()))
+        );
+        assert_eq!(
+            IfdField::new(Endian::Big, 0xFFFF, format::UNDEFINED, 2)
+                .with_data(&[0x01, 0x02])
+                .to_field(),
+            Some(Field::Raw(0xFFFF, vec![0x01, 0x02]))
+        );
+
+        // Values that don't fit the field's type are rejected rather than truncated
+        assert_eq!(
+            IfdField::new(Endian::Big, Tag::Orientation, format::UNSIGNED_LONG, 1)
+                .with_data(&[0x00, 0x01, 0x00, 0x06])
+                .to_field(),
+            None
+        );
+        assert_eq!(
+            IfdField::new(Endian::Big, Tag::BitsPerSample, format::UNSIGNED_LONG, 2)
+                .with_data(&[0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x00])
+                .to_field(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_to_string_enumerated() {
+        let short = |tag: Tag, val: u16| {
+            IfdField::new(Endian::Big, tag, format::UNSIGNED_SHORT, 1)
+                .with_data(&[(val >> 8) as u8, val as u8, 0x00, 0x00])
+                .to_string()
+        };
+        assert_eq!(short(Tag::ExposureProgram, 3), "Aperture-priority AE");
+        assert_eq!(short(Tag::MeteringMode, 5), "Multi-segment");
+        assert_eq!(short(Tag::LightSource, 21), "D65");
+        assert_eq!(short(Tag::Flash, 0x19), "Auto, Fired");
+        assert_eq!(short(Tag::ColorSpace, 0xFFFF), "Uncalibrated");
+        assert_eq!(short(Tag::SensingMethod, 2), "One-chip color area");
+        assert_eq!(short(Tag::ExposureMode, 2), "Auto bracket");
+        assert_eq!(short(Tag::WhiteBalance, 1), "Manual");
+        assert_eq!(short(Tag::CustomRendered, 1), "Custom");
+        assert_eq!(short(Tag::SubjectDistanceRange, 1), "Macro");
+        assert_eq!(short(Tag::MeteringMode, 7), "Invalid (7)");
+        assert_eq!(short(Tag::ExposureMode, 5), "Unknown (5)");
+        assert_eq!(short(Tag::LightSource, 5), "Invalid (5)");
+
+        let undefined = |tag: Tag, data: &[u8]| {
+            IfdField::new(Endian::Big, tag, format::UNDEFINED, data.len() as u32)
+                .with_data(data)
+                .to_string()
+        };
+        assert_eq!(undefined(Tag::FileSource, &[0x03, 0, 0, 0]), "Digital Camera");
+        assert_eq!(undefined(Tag::SceneType, &[0x01, 0, 0, 0]), "Directly photographed");
+        assert_eq!(undefined(Tag::ComponentConfiguration, &[1, 2, 3, 0]), "Y, Cb, Cr, -");
+
+        assert_eq!(
+            IfdField::new(Endian::Big, Tag::Flash, format::UNSIGNED_SHORT, 1)
+                .with_data(&[0x00, 0x10, 0x00, 0x00])
+                .to_field(),
+            Some(Field::Flash(Flash::from(0x10u16)))
+        );
+    }
+
+    #[test]
+    fn test_to_string_exposure() {
+        let rational = |tag: Tag, num: u8, den: u8| {
+            IfdField::new(Endian::Big, tag, format::UNSIGNED_RATIONAL, 1)
+                .with_data(&[0, 0, 0, num, 0, 0, 0, den])
+                .to_string()
+        };
+        assert_eq!(rational(Tag::ExposureTime, 1, 250), "1/250 s");
+        assert_eq!(rational(Tag::ExposureTime, 10, 150), "1/15 s");
+        assert_eq!(rational(Tag::ExposureTime, 1, 4), "1/4 s");
+        assert_eq!(rational(Tag::ExposureTime, 3, 10), "0.3 s");
+        assert_eq!(rational(Tag::ExposureTime, 2, 3), "0.7 s");
+        assert_eq!(rational(Tag::ExposureTime, 30, 1), "30 s");
+        assert_eq!(rational(Tag::ExposureTime, 25, 10), "2.5 s");
+        assert_eq!(rational(Tag::ExposureTime, 0, 1), "0 s");
+        assert_eq!(rational(Tag::FNumber, 28, 10), "f/2.8");
+        assert_eq!(rational(Tag::FNumber, 8, 1), "f/8.0");
+    }
+
+    #[test]
+    fn test_tag_ids() {
+        assert_eq!(Tag::from(0x9010), Tag::OffsetTime);
+        assert_eq!(Tag::from(0x9291), Tag::SubSecTimeOriginal);
+        assert_eq!(Tag::from(0x9C9D), Tag::XPAuthor);
+        assert_eq!(Tag::from(0xA460), Tag::CompositeImage);
+        assert_eq!(Tag::from_gps(0x001F), Tag::GpsHPositioningError);
+        assert_eq!(Tag::from_interop(0x1001), Tag::RelatedImageWidth);
+        assert_eq!(Tag::from(0x9293), Tag::Raw(0x9293));
+    }
+
+    #[test]
+    fn test_conforms() {
+        let field = |tag: Tag, format: u16, components: u32| {
+            IfdField::new(Endian::Big, tag, format, components).conforms()
+        };
+        assert!(field(Tag::ImageWidth, format::UNSIGNED_SHORT, 1));
+        assert!(field(Tag::ImageWidth, format::UNSIGNED_LONG, 1));
+        assert!(!field(Tag::ImageWidth, format::UNSIGNED_RATIONAL, 1));
+        assert!(!field(Tag::DateTimeOriginal, format::ASCII_STRING, 19));
+        assert!(field(Tag::OffsetTime, format::ASCII_STRING, 7));
+        assert!(field(Tag::SubjectArea, format::UNSIGNED_SHORT, 3));
+        assert!(!field(Tag::SubjectArea, format::UNSIGNED_SHORT, 5));
+        assert!(field(Tag::MakerNote, format::UNDEFINED, 1024));
+        assert!(field(Tag::Raw(0xFFFF), format::DOUBLE_FLOAT, 9));
+    }
+
     #[test]
     fn test_data_to_ascii() {
         assert_eq!(
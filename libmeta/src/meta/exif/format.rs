@@ -11,3 +11,15 @@ pub(crate) const SIGNED_LONG: u16 = 0x09; // 4 bytes per component
 pub(crate) const SIGNED_RATIONAL: u16 = 0x0A; // 8 bytes per component
 pub(crate) const SINGLE_FLOAT: u16 = 0x0B; // 4 bytes per component
 pub(crate) const DOUBLE_FLOAT: u16 = 0x0C; // 8 bytes per component
+
+/// Size in bytes of a single component of the given format
+/// * Returns: 0 for unknown formats
+pub(crate) fn size(format: u16) -> u64 {
+    match format {
+        UNSIGNED_BYTE | ASCII_STRING | SIGNED_BYTE | UNDEFINED => 1,
+        UNSIGNED_SHORT | SIGNED_SHORT => 2,
+        UNSIGNED_LONG | SIGNED_LONG | SINGLE_FLOAT => 4,
+        UNSIGNED_RATIONAL | SIGNED_RATIONAL | DOUBLE_FLOAT => 8,
+        _ => 0,
+    }
+}
@@ -1,34 +1,83 @@
-use nom::bytes::streaming as nom_bytes;
 use nom::number::streaming as nom_nums;
 
-use super::{tag::Tag, Endian, ExifResult, IfdField};
+use super::{
+    tag::{Tag, Vendor},
+    Endian, ExifResult, IfdField,
+};
 use crate::errors::ExifError;
 
+/// Role an IFD plays in the TIFF structure along with the page it belongs to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum IfdKind {
+    /// Image IFD from the IFD0 -> IFD1 -> ... chain i.e. a page
+    Image(usize),
+
+    /// Exif sub IFD referenced by the given page
+    Exif(usize),
+
+    /// GPS sub IFD referenced by the given page
+    Gps(usize),
+
+    /// Interoperability sub IFD referenced by the Exif IFD of the given page
+    Interop(usize),
+
+    /// Vendor MakerNote IFD referenced by the Exif IFD of the given page
+    MakerNote(Vendor, usize),
+}
+
+impl IfdKind {
+    /// Page i.e. index of the image IFD in the chain this IFD belongs to
+    pub(crate) fn page(&self) -> usize {
+        match self {
+            IfdKind::Image(page)
+            | IfdKind::Exif(page)
+            | IfdKind::Gps(page)
+            | IfdKind::Interop(page)
+            | IfdKind::MakerNote(_, page) => *page,
+        }
+    }
+
+    /// Map the tag identifier into this IFD's namespace
+    /// * GPS, interop and vendor tags reuse identifiers so they are only valid inside their own IFD
+    pub(crate) fn tag(&self, id: u16) -> Tag {
+        match self {
+            IfdKind::Image(_) | IfdKind::Exif(_) => Tag::from(id),
+            IfdKind::Gps(_) => Tag::from_gps(id),
+            IfdKind::Interop(_) => Tag::from_interop(id),
+            IfdKind::MakerNote(vendor, _) => Tag::from_maker(*vendor, id),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct Ifd {
     pub(crate) endian: Endian,
+    pub(crate) kind: IfdKind,
     pub(crate) fields: Vec<IfdField>,
 }
 
 impl Ifd {
-    pub(crate) fn new(endian: Endian) -> Self {
-        Self { endian, fields: Vec::new() }
+    pub(crate) fn new(endian: Endian, kind: IfdKind) -> Self {
+        Self { endian, kind, fields: Vec::new() }
     }
 
-    /// Parse IFD returns a list of ifds
+    /// Parse the IFD at the given offset
     /// * **input** is the full data source from tiff header alignment
-    /// * **remain** starts with the ifd field count
-    pub(crate) fn parse<'a>(
-        input: &'a [u8],
-        remain: &'a [u8],
+    /// * **offset** is from the start of the input and may point anywhere in it
+    /// * **kind** is the role of the IFD which determines the namespace of its tags
+    /// * Returns: (bytes following the IFD fields i.e. the next IFD offset, Ifd)
+    pub(crate) fn parse(
+        input: &[u8],
         endian: Endian,
         offset: usize,
-    ) -> ExifResult<(&'a [u8], Ifd)> {
-        let mut ifd = Ifd::new(endian);
+        kind: IfdKind,
+    ) -> ExifResult<(&[u8], Ifd)> {
+        let mut ifd = Ifd::new(endian, kind);
 
-        // Skip to offset location
-        let (remain, _) = nom_bytes::take(offset - (input.len() - remain.len()))(remain)
-            .map_err(|x| ExifError::parse(": offset to IFD").with_nom_source(x))?;
+        // Jump to the offset location
+        let remain = input.get(offset..).ok_or_else(|| {
+            ExifError::offset_out_of_bounds(": IFD").with_data(&(offset as u32).to_be_bytes())
+        })?;
 
         // Parse out the number of IFD fields to expect
         let (remain, count) = parse_field_count(remain, endian)?;
@@ -36,7 +85,7 @@ impl Ifd {
         // Parse out each of the IFD fields
         let mut outer = remain;
         for _ in 0..count {
-            let (inner, field) = IfdField::parse(input, outer, endian)?;
+            let (inner, field) = IfdField::parse(input, outer, endian, kind)?;
             outer = inner;
             ifd.fields.push(field);
         }
@@ -69,8 +118,7 @@ mod tests {
 
     #[test]
     fn test_parse_exif_ifd() {
-        let (_, ifd) =
-            Ifd::parse(&EXIF_TEST_DATA, &EXIF_TEST_DATA[86..], Endian::Big, 134).unwrap();
+        let (_, ifd) = Ifd::parse(&EXIF_TEST_DATA, Endian::Big, 134, IfdKind::Image(0)).unwrap();
         assert_eq!(ifd.fields.len(), 3);
 
         let field = &ifd.fields[0];
@@ -100,8 +148,7 @@ mod tests {
 
     #[test]
     fn test_parse_ifd1() {
-        let (_, ifd) =
-            Ifd::parse(&EXIF_TEST_DATA, &EXIF_TEST_DATA[86..], Endian::Big, 176).unwrap();
+        let (_, ifd) = Ifd::parse(&EXIF_TEST_DATA, Endian::Big, 176, IfdKind::Image(0)).unwrap();
 
         let field0 = &ifd.fields[0];
         assert_eq!(field0.tag, Tag::ThumbnailOffset);
@@ -122,7 +169,7 @@ mod tests {
 
     #[test]
     fn test_parse_ifd0() {
-        let (_, ifd) = Ifd::parse(&EXIF_TEST_DATA, &EXIF_TEST_DATA[8..], Endian::Big, 8).unwrap();
+        let (_, ifd) = Ifd::parse(&EXIF_TEST_DATA, Endian::Big, 8, IfdKind::Image(0)).unwrap();
 
         let field0 = &ifd.fields[0];
         assert_eq!(field0.endian, Endian::Big);
@@ -215,7 +262,7 @@ mod tests {
             /* 22-26 */ 0x00, 0x00, 0x00, 0x00, 0x01, // data
         ];
 
-        let (remain, ifd) = Ifd::parse(&data, &data[8..], Endian::Big, 8).unwrap();
+        let (remain, ifd) = Ifd::parse(&data, Endian::Big, 8, IfdKind::Image(0)).unwrap();
         assert_eq!(remain, &data[22..]);
 
         let field = &ifd.fields[0];
@@ -245,7 +292,7 @@ mod tests {
 
     #[test]
     fn test_parse_ifd_fields_little_endian() {
-        let (remain, ifd) = Ifd::parse(&IFD_LE, &IFD_LE[8..], Endian::Little, 8).unwrap();
+        let (remain, ifd) = Ifd::parse(&IFD_LE, Endian::Little, 8, IfdKind::Image(0)).unwrap();
         assert_eq!(remain, &IFD_LE[34..]);
 
         let field = &ifd.fields[0];
@@ -0,0 +1,210 @@
+// Exiftool MakerNote definitions are an invaluable source
+// https://exiftool.org/makernote_types.html
+
+use super::{parse_ifd_offset, parse_tiff_endian, Endian, ExifResult, Ifd, IfdKind, Tag, Vendor};
+use crate::errors::ExifError;
+
+const NIKON_SIGNATURE: &[u8] = b"Nikon\0";
+const SONY_SIGNATURE: &[u8] = b"SONY";
+const FUJIFILM_SIGNATURE: &[u8] = b"FUJIFILM";
+const OLYMPUS_SIGNATURE: &[u8] = b"OLYMPUS\0";
+const OLYMPUS_OLD_SIGNATURE: &[u8] = b"OLYMP\0";
+const OM_SYSTEM_SIGNATURE: &[u8] = b"OM SYSTEM\0";
+const PANASONIC_SIGNATURE: &[u8] = b"Panasonic\0";
+
+/// Parse the MakerNote referenced by the Exif IFD into a vendor IFD
+/// * **input** is the full data source from tiff header alignment
+/// * **image** is the image IFD of the page which holds the Make used to detect the vendor
+/// * **exif** is the Exif IFD of the page which holds the MakerNote
+/// * MakerNotes are undocumented and often damaged by editing software so unknown vendors are
+///   ignored and broken notes are recorded as skipped rather than failing the whole Exif parse
+pub(crate) fn parse_maker_note(
+    input: &[u8],
+    image: &Ifd,
+    exif: &Ifd,
+    page: usize,
+    skipped: &mut Vec<(IfdKind, ExifError)>,
+) -> Option<Ifd> {
+    let vendor = Vendor::from_make(&image.field_by_tag(Tag::Make)?.to_ascii()?)?;
+    let field = exif.field_by_tag(Tag::MakerNote)?;
+    let (offset, data) = (field.offset? as usize, field.data.as_deref()?);
+    let kind = IfdKind::MakerNote(vendor, page);
+    let parsed = locate(input, data, offset, exif.endian, vendor)
+        .and_then(|(base, endian, ifd)| Ifd::parse(base, endian, ifd, kind));
+    match parsed {
+        Ok((_, ifd)) => Some(ifd),
+        Err(e) => {
+            skipped.push((kind, e));
+            None
+        }
+    }
+}
+
+/// Locate the vendor IFD inside the MakerNote from its signature header
+/// * **data** is the MakerNote value found at **offset** from the start of the input
+/// * Returns: (data the IFD offsets are relative to, endian, IFD offset into that data)
+/// * **Vendor**  | **Header**                        | **Offsets relative to**
+/// * *Canon*     | none                              | TIFF header
+/// * *Nikon*     | `Nikon\0` + version + TIFF header | embedded TIFF header, type 1 TIFF header
+/// * *Sony*      | `SONY DSC \0\0\0` or none         | TIFF header
+/// * *Fujifilm*  | `FUJIFILM` + LE IFD offset        | MakerNote, always little endian
+/// * *Olympus*   | `OLYMPUS\0II\x03\0`, `OLYMP\0`    | MakerNote, type 1 TIFF header
+/// * *Panasonic* | `Panasonic\0\0\0`                 | TIFF header
+fn locate<'a>(
+    input: &'a [u8],
+    data: &'a [u8],
+    offset: usize,
+    endian: Endian,
+    vendor: Vendor,
+) -> ExifResult<(&'a [u8], Endian, usize)> {
+    let get = |start: usize| {
+        data.get(start..).ok_or_else(|| {
+            ExifError::offset_out_of_bounds(": MakerNote").with_data(&(start as u32).to_be_bytes())
+        })
+    };
+
+    match vendor {
+        // Type 3 embeds a complete TIFF header with its own byte order after the version
+        Vendor::Nikon if data.starts_with(NIKON_SIGNATURE) && data.get(6) == Some(&0x02) => {
+            let tiff = get(10)?;
+            let (_, endian) = parse_tiff_endian(tiff)?;
+            let (_, ifd) = parse_ifd_offset(get(14)?, endian)?;
+            Ok((tiff, endian, ifd as usize))
+        }
+        Vendor::Nikon if data.starts_with(NIKON_SIGNATURE) => Ok((input, endian, offset + 8)),
+        Vendor::Sony if data.starts_with(SONY_SIGNATURE) => Ok((input, endian, offset + 12)),
+        Vendor::Fujifilm if data.starts_with(FUJIFILM_SIGNATURE) => {
+            let (_, ifd) = parse_ifd_offset(get(8)?, Endian::Little)?;
+            Ok((data, Endian::Little, ifd as usize))
+        }
+        Vendor::Olympus if data.starts_with(OLYMPUS_SIGNATURE) => {
+            let (_, endian) = parse_tiff_endian(get(8)?)?;
+            Ok((data, endian, 12))
+        }
+        Vendor::Olympus if data.starts_with(OM_SYSTEM_SIGNATURE) => {
+            let (_, endian) = parse_tiff_endian(get(12)?)?;
+            Ok((data, endian, 16))
+        }
+        Vendor::Olympus if data.starts_with(OLYMPUS_OLD_SIGNATURE) => {
+            Ok((input, endian, offset + 8))
+        }
+        Vendor::Panasonic if data.starts_with(PANASONIC_SIGNATURE) => {
+            Ok((input, endian, offset + 12))
+        }
+
+        // Canon and older models of the other vendors store a bare IFD
+        _ => Ok((input, endian, offset)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meta::exif::test_data::{
+        CANON_TEST_DATA, FUJIFILM_TEST_DATA, NIKON_TEST_DATA, OLYMPUS_TEST_DATA,
+        PANASONIC_TEST_DATA, SONY_TEST_DATA,
+    };
+    use crate::meta::exif::{Exif, Field};
+    use crate::Rational;
+
+    #[test]
+    fn test_parse_canon() {
+        let exif = Exif::parse_tiff(&CANON_TEST_DATA).unwrap();
+        assert_eq!(exif.ifds.len(), 3);
+        assert_eq!(exif.ifds[2].kind, IfdKind::MakerNote(Vendor::Canon, 0));
+        assert_eq!(exif.vendor(), Some(Vendor::Canon));
+        assert_eq!(
+            exif.get_field(Tag::CanonLensModel),
+            Some(Field::CanonLensModel("EF50mm f/1.8 STM".into()))
+        );
+        assert_eq!(exif.get_field(Tag::CanonSerialNumber), Some(Field::CanonSerialNumber(123456)));
+        assert_eq!(
+            exif.get_page_field(0, Tag::CanonSerialNumber),
+            exif.get_field(Tag::CanonSerialNumber)
+        );
+    }
+
+    #[test]
+    fn test_parse_nikon() {
+        let exif = Exif::parse_tiff(&NIKON_TEST_DATA).unwrap();
+        assert_eq!(exif.get_field(Tag::NikonShutterCount), Some(Field::NikonShutterCount(12345)));
+        assert_eq!(
+            exif.get_field(Tag::NikonLens),
+            Some(Field::NikonLens(vec![
+                Rational::new(24, 1),
+                Rational::new(70, 1),
+                Rational::new(28, 10),
+                Rational::new(28, 10),
+            ]))
+        );
+        let field = exif.ifds[2].field_by_tag(Tag::NikonLens).unwrap();
+        assert_eq!(field.to_string(), "24-70 mm f/2.8");
+    }
+
+    #[test]
+    fn test_parse_fujifilm() {
+        let exif = Exif::parse_tiff(&FUJIFILM_TEST_DATA).unwrap();
+        assert_eq!(
+            exif.get_field(Tag::FujifilmInternalSerialNumber),
+            Some(Field::FujifilmInternalSerialNumber("FF01234".into()))
+        );
+        assert_eq!(exif.get_field(Tag::FujifilmFocusMode), Some(Field::FujifilmFocusMode(1)));
+    }
+
+    #[test]
+    fn test_parse_sony() {
+        let exif = Exif::parse_tiff(&SONY_TEST_DATA).unwrap();
+        assert_eq!(exif.ifds[2].kind, IfdKind::MakerNote(Vendor::Sony, 0));
+        assert_eq!(exif.get_field(Tag::SonyModelId), Some(Field::SonyModelId(358)));
+        assert_eq!(exif.get_field(Tag::SonyLensType), Some(Field::SonyLensType(32784)));
+        assert!(exif.skipped.is_empty());
+    }
+
+    #[test]
+    fn test_parse_olympus_and_panasonic() {
+        let exif = Exif::parse_tiff(&OLYMPUS_TEST_DATA).unwrap();
+        assert_eq!(
+            exif.get_field(Tag::OlympusCameraType),
+            Some(Field::OlympusCameraType("D4".into()))
+        );
+
+        let exif = Exif::parse_tiff(&PANASONIC_TEST_DATA).unwrap();
+        assert_eq!(exif.get_field(Tag::PanasonicFocusMode), Some(Field::PanasonicFocusMode(2)));
+
+        // Vendor identifiers don't leak into the main IFD namespace
+        assert_eq!(exif.get_field(Tag::GpsTimeStamp), None);
+    }
+
+    #[test]
+    fn test_parse_ignored() {
+        // Unknown vendors are left as opaque MakerNote data
+        let mut data = CANON_TEST_DATA;
+        data[56..61].copy_from_slice(b"Apple");
+        let exif = Exif::parse_tiff(&data).unwrap();
+        assert_eq!(exif.ifds.len(), 2);
+        assert_eq!(exif.vendor(), None);
+        let field = exif.ifds[1].field_by_tag(Tag::MakerNote).unwrap();
+        assert_eq!(field.to_string(), "(Binary data 47 bytes)");
+
+        // Broken notes don't fail the Exif parse but are recorded as skipped
+        let mut data = NIKON_TEST_DATA;
+        data[84..86].copy_from_slice(b"XX");
+        let exif = Exif::parse_tiff(&data).unwrap();
+        assert_eq!(exif.ifds.len(), 2);
+        assert_eq!(exif.skipped.len(), 1);
+        assert_eq!(exif.skipped[0].0, IfdKind::MakerNote(Vendor::Nikon, 0));
+        let mut data = CANON_TEST_DATA;
+        data[62..64].copy_from_slice(&[0xFF, 0xFF]);
+        let exif = Exif::parse_tiff(&data).unwrap();
+        assert_eq!(exif.ifds.len(), 2);
+        assert_eq!(exif.skipped.len(), 1);
+        assert_eq!(exif.skipped[0].0, IfdKind::MakerNote(Vendor::Canon, 0));
+
+        // Sony signature followed by an IFD field count beyond the data
+        let mut data = SONY_TEST_DATA;
+        data[73..75].copy_from_slice(&[0xFF, 0xFF]);
+        let exif = Exif::parse_tiff(&data).unwrap();
+        assert_eq!(exif.ifds.len(), 2);
+        assert_eq!(exif.skipped[0].0, IfdKind::MakerNote(Vendor::Sony, 0));
+    }
+}
@@ -1,7 +1,8 @@
 mod endian;
 mod field;
-mod format;
+pub(crate) mod format;
 mod ifd;
+mod maker_note;
 mod tag;
 
 // Surface module directly
@@ -10,10 +11,11 @@ pub(crate) mod test_data;
 
 // Surface types from modules directly in the meta module
 pub(crate) use endian::*;
-pub(crate) use tag::*;
 pub(crate) use exif::*;
-pub(crate) use field::*;
+pub use field::*;
 pub(crate) use ifd::*;
+pub(crate) use maker_note::*;
+pub use tag::*;
 
 const EXIF_IDENTIFIER: [u8; 4] = [0x45, 0x78, 0x69, 0x66];
 const TIFF_VERSION: [u8; 2] = [0x00, 0x2A];
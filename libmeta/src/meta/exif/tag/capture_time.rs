@@ -0,0 +1,308 @@
+use std::fmt::Display;
+
+use super::Rational;
+
+/// Tag the capture time was read from in order of preference
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptureSource {
+    DateTimeOriginal,
+    DateTimeDigitized,
+    DateTime,
+    Gps,
+}
+
+impl Display for CaptureSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CaptureSource::DateTimeOriginal => write!(f, "Date/Time Original"),
+            CaptureSource::DateTimeDigitized => write!(f, "Date/Time Digitized"),
+            CaptureSource::DateTime => write!(f, "Date/Time"),
+            CaptureSource::Gps => write!(f, "GPS"),
+        }
+    }
+}
+
+/// Capture time of the image in local time with the UTC offset when known
+/// * **offset** is in minutes east of UTC e.g. `+02:00` is 120
+/// * GPS timestamps are always UTC so have an offset of 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CaptureTime {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub nanosecond: u32,
+    pub offset: Option<i32>,
+    pub source: CaptureSource,
+}
+
+impl CaptureTime {
+    /// Create a new capture time from the raw Exif strings
+    /// * **datetime** is `YYYY:MM:DD HH:MM:SS` tolerating `-` or `/` date separators, a `T`
+    ///   separator, a missing time and trailing fractions or offsets
+    /// * **subsec** is the SubSecTime digits e.g. `123` for 0.123 seconds
+    /// * **offset** is the OffsetTime e.g. `+02:00`
+    /// * Returns: None for blank, zeroed e.g. `0000:00:00 00:00:00` or out of range values
+    pub(crate) fn new(
+        datetime: &str,
+        subsec: Option<&str>,
+        offset: Option<&str>,
+        source: CaptureSource,
+    ) -> Option<Self> {
+        let datetime = datetime.trim_matches(|x: char| x.is_whitespace() || x == '\0');
+        let (date, time) = match datetime.find([' ', 'T']) {
+            Some(i) => (&datetime[..i], datetime[i + 1..].trim_start()),
+            None => (datetime, ""),
+        };
+        let (year, month, day) = parse_date(date)?;
+
+        // Time may carry its own fraction and offset e.g. `13:05:30.123+02:00`
+        let (time, inline_offset) = match time.find(['+', '-', 'Z']) {
+            Some(i) => (&time[..i], Some(&time[i..])),
+            None => (time, None),
+        };
+        let (time, inline_subsec) = match time.split_once('.') {
+            Some((time, subsec)) => (time, Some(subsec)),
+            None => (time, None),
+        };
+        let (hour, minute, second) = match time.is_empty() {
+            true => (0, 0, 0),
+            false => parse_time(time)?,
+        };
+
+        Some(Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanosecond: subsec
+                .and_then(parse_subsec)
+                .or_else(|| inline_subsec.and_then(parse_subsec))
+                .unwrap_or(0),
+            offset: offset
+                .and_then(parse_offset)
+                .or_else(|| inline_offset.and_then(parse_offset)),
+            source,
+        })
+    }
+
+    /// Create a new UTC capture time from the GPS date stamp and time stamp
+    /// * **date** is `YYYY:MM:DD`
+    /// * **time** is hours, minutes and seconds
+    pub(crate) fn from_gps(date: &str, time: &[Rational]) -> Option<Self> {
+        let (year, month, day) =
+            parse_date(date.trim_matches(|x: char| x.is_whitespace() || x == '\0'))?;
+        let value = |i: usize| time.get(i).and_then(|x| x.to_f64());
+        let (hour, minute, second) = (value(0)?, value(1)?, value(2)?);
+        if hour >= 24.0 || minute >= 60.0 || second >= 61.0 {
+            return None;
+        }
+        Some(Self {
+            year,
+            month,
+            day,
+            hour: hour as u8,
+            minute: minute as u8,
+            second: second as u8,
+            nanosecond: (second.fract() * 1e9).round() as u32,
+            offset: Some(0),
+            source: CaptureSource::Gps,
+        })
+    }
+
+    /// Get the number of seconds since the Unix epoch when the UTC offset is known
+    pub fn timestamp(&self) -> Option<i64> {
+        let days = days_from_civil(self.year as i64, self.month as i64, self.day as i64);
+        let seconds =
+            days * 86400 + self.hour as i64 * 3600 + self.minute as i64 * 60 + self.second as i64;
+        self.offset.map(|x| seconds - x as i64 * 60)
+    }
+}
+
+impl Display for CaptureTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )?;
+        if self.nanosecond > 0 {
+            let fraction = format!("{:09}", self.nanosecond);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        match self.offset {
+            Some(0) => write!(f, "Z"),
+            Some(x) => {
+                let sign = if x < 0 { '-' } else { '+' };
+                write!(f, "{}{:02}:{:02}", sign, x.abs() / 60, x.abs() % 60)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+// Split the value on any of the common separators into numeric parts
+fn parse_parts(value: &str, separators: &[char]) -> Option<Vec<u32>> {
+    value
+        .split(separators)
+        .map(|x| x.trim().parse::<u32>().ok())
+        .collect()
+}
+
+// Parse `YYYY:MM:DD` with `:`, `-` or `/` separators rejecting zeroed or out of range dates
+fn parse_date(date: &str) -> Option<(u16, u8, u8)> {
+    match parse_parts(date, &[':', '-', '/'])?[..] {
+        [year, month, day]
+            if (1..=9999).contains(&year)
+                && (1..=12).contains(&month)
+                && day >= 1
+                && day <= days_in_month(year, month) =>
+        {
+            Some((year as u16, month as u8, day as u8))
+        }
+        _ => None,
+    }
+}
+
+// Number of days in the given month of the Gregorian calendar
+fn days_in_month(year: u32, month: u32) -> u32 {
+    let leap = year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+    match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Parse `HH:MM:SS` allowing a missing seconds component
+fn parse_time(time: &str) -> Option<(u8, u8, u8)> {
+    let (hour, minute, second) = match parse_parts(time, &[':'])?[..] {
+        [hour, minute, second] => (hour, minute, second),
+        [hour, minute] => (hour, minute, 0),
+        _ => return None,
+    };
+    match hour < 24 && minute < 60 && second <= 60 {
+        true => Some((hour as u8, minute as u8, second as u8)),
+        false => None,
+    }
+}
+
+// Parse the fraction digits of a second into nanoseconds e.g. `5` is 500ms
+fn parse_subsec(subsec: &str) -> Option<u32> {
+    let digits = subsec.trim_matches(|x: char| x.is_whitespace() || x == '\0');
+    if digits.is_empty() || !digits.bytes().all(|x| x.is_ascii_digit()) {
+        return None;
+    }
+    let digits = &digits[..digits.len().min(9)];
+    Some(digits.parse::<u32>().ok()? * 10u32.pow(9 - digits.len() as u32))
+}
+
+// Parse `+HH:MM`, `-HH:MM`, `+HHMM` or `Z` into minutes east of UTC
+fn parse_offset(offset: &str) -> Option<i32> {
+    let offset = offset.trim_matches(|x: char| x.is_whitespace() || x == '\0');
+    if offset == "Z" {
+        return Some(0);
+    }
+    let sign = match offset.chars().next()? {
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+    let digits: String = offset[1..].chars().filter(|x| *x != ':').collect();
+    if digits.len() != 4 || !digits.bytes().all(|x| x.is_ascii_digit()) {
+        return None;
+    }
+    let (hours, minutes) = (digits[..2].parse::<i32>().ok()?, digits[2..].parse::<i32>().ok()?);
+    match hours <= 14 && minutes < 60 {
+        true => Some(sign * (hours * 60 + minutes)),
+        false => None,
+    }
+}
+
+// Days since the Unix epoch for the given proleptic Gregorian date
+// * Howard Hinnant's days_from_civil algorithm
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let doy = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let time = CaptureTime::new(
+            "2024:05:04 13:05:30",
+            Some("123"),
+            Some("+02:00"),
+            CaptureSource::DateTimeOriginal,
+        )
+        .unwrap();
+        assert_eq!((time.year, time.month, time.day), (2024, 5, 4));
+        assert_eq!((time.hour, time.minute, time.second), (13, 5, 30));
+        assert_eq!(time.nanosecond, 123_000_000);
+        assert_eq!(time.offset, Some(120));
+        assert_eq!(time.to_string(), "2024-05-04T13:05:30.123+02:00");
+        assert_eq!(time.timestamp(), Some(1714820730));
+
+        // No offset means local time of unknown zone
+        let time =
+            CaptureTime::new("2011:06:12 08:00:00", None, None, CaptureSource::DateTime).unwrap();
+        assert_eq!(time.to_string(), "2011-06-12T08:00:00");
+        assert_eq!(time.timestamp(), None);
+    }
+
+    #[test]
+    fn test_new_malformed() {
+        let source = CaptureSource::DateTime;
+        let parse = |x: &str| CaptureTime::new(x, None, None, source).map(|x| x.to_string());
+        assert_eq!(parse("2024/05/04 13:05:30"), Some("2024-05-04T13:05:30".into()));
+        assert_eq!(parse("2024-05-04T13:05:30Z"), Some("2024-05-04T13:05:30Z".into()));
+        assert_eq!(
+            parse("2024:05:04 13:05:30.5-05:30"),
+            Some("2024-05-04T13:05:30.5-05:30".into())
+        );
+        assert_eq!(parse("2024:05:04 13:05"), Some("2024-05-04T13:05:00".into()));
+        assert_eq!(parse("2024:05:04\0"), Some("2024-05-04T00:00:00".into()));
+        assert_eq!(parse("0000:00:00 00:00:00"), None);
+        assert_eq!(parse("    :  :     :  :  "), None);
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("2024:13:04 13:05:30"), None);
+        assert_eq!(parse("2024:05:04 25:05:30"), None);
+
+        // Days are checked against the month and years must fit in four digits
+        assert_eq!(parse("2024:02:29 13:05:30"), Some("2024-02-29T13:05:30".into()));
+        assert_eq!(parse("2023:02:29 13:05:30"), None);
+        assert_eq!(parse("1900:02:29 13:05:30"), None);
+        assert_eq!(parse("2024:04:31 13:05:30"), None);
+        assert_eq!(parse("65537:05:04 13:05:30"), None);
+
+        // Blank sub second and offset values are ignored
+        let time = CaptureTime::new("2024:05:04 13:05:30", Some("  "), Some("   :  "), source);
+        assert_eq!(time.map(|x| x.to_string()), Some("2024-05-04T13:05:30".into()));
+    }
+
+    #[test]
+    fn test_from_gps() {
+        let time = [
+            Rational::new(13, 1),
+            Rational::new(5, 1),
+            Rational::new(3025, 100),
+        ];
+        let time = CaptureTime::from_gps("2024:05:04", &time).unwrap();
+        assert_eq!(time.source, CaptureSource::Gps);
+        assert_eq!(time.to_string(), "2024-05-04T13:05:30.25Z");
+        assert_eq!(time.timestamp(), Some(1714827930));
+        assert_eq!(CaptureTime::from_gps("2024:05:04", &[Rational::new(1, 1)]), None);
+    }
+}
@@ -0,0 +1,39 @@
+use std::fmt::Display;
+
+/// ColorSpace values
+/// https://exiftool.org/TagNames/EXIF.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorSpace {
+    Srgb,         // 1
+    AdobeRgb,     // 2
+    Uncalibrated, // 0xFFFF
+    Unknown(u16), // any other value
+}
+
+impl From<usize> for ColorSpace {
+    fn from(val: usize) -> Self {
+        ColorSpace::from(val as u16)
+    }
+}
+
+impl From<u16> for ColorSpace {
+    fn from(val: u16) -> Self {
+        match val {
+            1 => ColorSpace::Srgb,
+            2 => ColorSpace::AdobeRgb,
+            0xFFFF => ColorSpace::Uncalibrated,
+            x => ColorSpace::Unknown(x),
+        }
+    }
+}
+
+impl Display for ColorSpace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorSpace::Srgb => write!(f, "sRGB"),
+            ColorSpace::AdobeRgb => write!(f, "Adobe RGB"),
+            ColorSpace::Uncalibrated => write!(f, "Uncalibrated"),
+            ColorSpace::Unknown(x) => write!(f, "Unknown ({})", x),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::fmt::Display;
+
+/// ComponentConfiguration channel order of the compressed data
+/// https://exiftool.org/TagNames/EXIF.html
+/// * 4 bytes, one per channel, 0 = none, 1 = Y, 2 = Cb, 3 = Cr, 4 = R, 5 = G, 6 = B
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentConfiguration(pub Vec<u8>);
+
+impl From<Vec<u8>> for ComponentConfiguration {
+    fn from(val: Vec<u8>) -> Self {
+        ComponentConfiguration(val)
+    }
+}
+
+impl Display for ComponentConfiguration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let channels: Vec<String> = self
+            .0
+            .iter()
+            .map(|x| match x {
+                0 => "-".to_string(),
+                1 => "Y".to_string(),
+                2 => "Cb".to_string(),
+                3 => "Cr".to_string(),
+                4 => "R".to_string(),
+                5 => "G".to_string(),
+                6 => "B".to_string(),
+                x => format!("Unknown ({})", x),
+            })
+            .collect();
+        write!(f, "{}", channels.join(", "))
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
-pub(crate) enum Contrast {
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Contrast {
     Normal, // 0
     Low,    // 1
     High,   // 2
@@ -0,0 +1,36 @@
+use std::fmt::Display;
+
+/// CustomRendered values
+/// https://exiftool.org/TagNames/EXIF.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CustomRendered {
+    Normal,       // 0
+    Custom,       // 1
+    Unknown(u16), // any other value
+}
+
+impl From<usize> for CustomRendered {
+    fn from(val: usize) -> Self {
+        CustomRendered::from(val as u16)
+    }
+}
+
+impl From<u16> for CustomRendered {
+    fn from(val: u16) -> Self {
+        match val {
+            0 => CustomRendered::Normal,
+            1 => CustomRendered::Custom,
+            x => CustomRendered::Unknown(x),
+        }
+    }
+}
+
+impl Display for CustomRendered {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CustomRendered::Normal => write!(f, "Normal"),
+            CustomRendered::Custom => write!(f, "Custom"),
+            CustomRendered::Unknown(x) => write!(f, "Unknown ({})", x),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use std::fmt::Display;
+
+/// ExposureMode values
+/// https://exiftool.org/TagNames/EXIF.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExposureMode {
+    Auto,         // 0
+    Manual,       // 1
+    AutoBracket,  // 2
+    Unknown(u16), // any other value
+}
+
+impl From<usize> for ExposureMode {
+    fn from(val: usize) -> Self {
+        ExposureMode::from(val as u16)
+    }
+}
+
+impl From<u16> for ExposureMode {
+    fn from(val: u16) -> Self {
+        match val {
+            0 => ExposureMode::Auto,
+            1 => ExposureMode::Manual,
+            2 => ExposureMode::AutoBracket,
+            x => ExposureMode::Unknown(x),
+        }
+    }
+}
+
+impl Display for ExposureMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExposureMode::Auto => write!(f, "Auto"),
+            ExposureMode::Manual => write!(f, "Manual"),
+            ExposureMode::AutoBracket => write!(f, "Auto bracket"),
+            ExposureMode::Unknown(x) => write!(f, "Unknown ({})", x),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt::Display;
+
+/// ExposureProgram values
+/// https://exiftool.org/TagNames/EXIF.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExposureProgram {
+    NotDefined,       // 0
+    Manual,           // 1
+    ProgramAE,        // 2
+    AperturePriority, // 3
+    ShutterPriority,  // 4
+    Creative,         // 5
+    Action,           // 6
+    Portrait,         // 7
+    Landscape,        // 8
+    Bulb,             // 9
+    Unknown(u16),     // any other value
+}
+
+impl From<usize> for ExposureProgram {
+    fn from(val: usize) -> Self {
+        ExposureProgram::from(val as u16)
+    }
+}
+
+impl From<u16> for ExposureProgram {
+    fn from(val: u16) -> Self {
+        match val {
+            0 => ExposureProgram::NotDefined,
+            1 => ExposureProgram::Manual,
+            2 => ExposureProgram::ProgramAE,
+            3 => ExposureProgram::AperturePriority,
+            4 => ExposureProgram::ShutterPriority,
+            5 => ExposureProgram::Creative,
+            6 => ExposureProgram::Action,
+            7 => ExposureProgram::Portrait,
+            8 => ExposureProgram::Landscape,
+            9 => ExposureProgram::Bulb,
+            x => ExposureProgram::Unknown(x),
+        }
+    }
+}
+
+impl Display for ExposureProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExposureProgram::NotDefined => write!(f, "Not Defined"),
+            ExposureProgram::Manual => write!(f, "Manual"),
+            ExposureProgram::ProgramAE => write!(f, "Program AE"),
+            ExposureProgram::AperturePriority => write!(f, "Aperture-priority AE"),
+            ExposureProgram::ShutterPriority => write!(f, "Shutter speed priority AE"),
+            ExposureProgram::Creative => write!(f, "Creative (Slow speed)"),
+            ExposureProgram::Action => write!(f, "Action (High speed)"),
+            ExposureProgram::Portrait => write!(f, "Portrait"),
+            ExposureProgram::Landscape => write!(f, "Landscape"),
+            ExposureProgram::Bulb => write!(f, "Bulb"),
+            ExposureProgram::Unknown(x) => write!(f, "Unknown ({})", x),
+        }
+    }
+}
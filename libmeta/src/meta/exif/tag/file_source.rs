@@ -0,0 +1,45 @@
+use std::fmt::Display;
+
+/// FileSource values
+/// https://exiftool.org/TagNames/EXIF.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FileSource {
+    FilmScanner,            // 1
+    ReflectionPrintScanner, // 2
+    DigitalCamera,          // 3
+    Unknown(u16),           // any other value
+}
+
+impl From<usize> for FileSource {
+    fn from(val: usize) -> Self {
+        FileSource::from(val as u16)
+    }
+}
+
+impl From<u8> for FileSource {
+    fn from(val: u8) -> Self {
+        FileSource::from(val as u16)
+    }
+}
+
+impl From<u16> for FileSource {
+    fn from(val: u16) -> Self {
+        match val {
+            1 => FileSource::FilmScanner,
+            2 => FileSource::ReflectionPrintScanner,
+            3 => FileSource::DigitalCamera,
+            x => FileSource::Unknown(x),
+        }
+    }
+}
+
+impl Display for FileSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FileSource::FilmScanner => write!(f, "Film Scanner"),
+            FileSource::ReflectionPrintScanner => write!(f, "Reflection Print Scanner"),
+            FileSource::DigitalCamera => write!(f, "Digital Camera"),
+            FileSource::Unknown(x) => write!(f, "Unknown ({})", x),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt::Display;
+
+/// Flash status bit field
+/// https://exiftool.org/TagNames/EXIF.html#Flash
+/// * Bit 0 flash fired
+/// * Bits 1-2 return light detection, 2 = not detected, 3 = detected
+/// * Bits 3-4 flash mode, 1 = on, 2 = off, 3 = auto
+/// * Bit 5 no flash function
+/// * Bit 6 red-eye reduction
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Flash {
+    pub fired: bool,
+    pub return_light: u8,
+    pub mode: u8,
+    pub function: bool,
+    pub red_eye: bool,
+}
+
+impl From<usize> for Flash {
+    fn from(val: usize) -> Self {
+        Flash::from(val as u16)
+    }
+}
+
+impl From<u16> for Flash {
+    fn from(val: u16) -> Self {
+        Flash {
+            fired: val & 0x01 != 0,
+            return_light: ((val >> 1) & 0x03) as u8,
+            mode: ((val >> 3) & 0x03) as u8,
+            function: val & 0x20 == 0,
+            red_eye: val & 0x40 != 0,
+        }
+    }
+}
+
+impl Display for Flash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.function && !self.fired && self.mode == 0 {
+            return write!(f, "No flash function");
+        }
+
+        let mut parts = vec![];
+        match self.mode {
+            1 => parts.push("On"),
+            2 => parts.push("Off"),
+            3 => parts.push("Auto"),
+            _ => (),
+        }
+        match (self.fired, self.mode) {
+            (true, _) => parts.push("Fired"),
+            (false, 0) => parts.push("No Flash"),
+            (false, _) => parts.push("Did not fire"),
+        }
+        match self.return_light {
+            2 => parts.push("Return not detected"),
+            3 => parts.push("Return detected"),
+            _ => (),
+        }
+        if !self.function {
+            parts.push("No flash function");
+        }
+        if self.red_eye {
+            parts.push("Red-eye reduction");
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flash() {
+        assert_eq!(Flash::from(0x00u16).to_string(), "No Flash");
+        assert_eq!(Flash::from(0x01u16).to_string(), "Fired");
+        assert_eq!(Flash::from(0x07u16).to_string(), "Fired, Return detected");
+        assert_eq!(Flash::from(0x10u16).to_string(), "Off, Did not fire");
+        assert_eq!(Flash::from(0x20u16).to_string(), "No flash function");
+        assert_eq!(Flash::from(0x59u16).to_string(), "Auto, Fired, Red-eye reduction");
+
+        let flash = Flash::from(0x1Fu16);
+        assert!(flash.fired);
+        assert_eq!(flash.return_light, 3);
+        assert_eq!(flash.mode, 3);
+        assert!(flash.function);
+        assert!(!flash.red_eye);
+    }
+}
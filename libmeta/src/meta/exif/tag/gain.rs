@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
-pub(crate) enum Gain {
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gain {
     None,         // 0
     LowGainUp,    // 1
     HighGainUp,   // 2
@@ -0,0 +1,112 @@
+use std::fmt::Display;
+
+use super::Rational;
+
+/// GPS position in signed decimal degrees with the altitude in metres
+/// * Latitude is negative south of the equator
+/// * Longitude is negative west of the prime meridian
+/// * Altitude is negative below sea level
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GpsPosition {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub altitude: Option<f64>,
+}
+
+impl GpsPosition {
+    /// Create a new position from the raw GPS IFD values
+    /// * **latitude** and **longitude** are degrees, minutes and seconds
+    /// * **lat_ref** is `N` or `S` and **lon_ref** is `E` or `W`
+    /// * **alt_ref** is 0 for above sea level and 1 for below sea level
+    pub(crate) fn new(
+        latitude: &[Rational],
+        lat_ref: &str,
+        longitude: &[Rational],
+        lon_ref: &str,
+        altitude: Option<Rational>,
+        alt_ref: Option<u8>,
+    ) -> Option<Self> {
+        let latitude = to_degrees(latitude)? * sign(lat_ref, "S")?;
+        let longitude = to_degrees(longitude)? * sign(lon_ref, "W")?;
+        if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+            return None;
+        }
+        let altitude = altitude.and_then(|x| x.to_f64()).map(|x| match alt_ref {
+            Some(1) => -x,
+            _ => x,
+        });
+        Some(Self { latitude, longitude, altitude })
+    }
+}
+
+impl Display for GpsPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.6}, {:.6}", self.latitude, self.longitude)?;
+        if let Some(altitude) = self.altitude {
+            write!(f, ", {:.1} m", altitude)?;
+        }
+        Ok(())
+    }
+}
+
+// Convert the reference to a sign where the negative reference flips it
+fn sign(reference: &str, negative: &str) -> Option<f64> {
+    match reference.trim() {
+        x if x.eq_ignore_ascii_case(negative) => Some(-1.0),
+        "" => None,
+        _ => Some(1.0),
+    }
+}
+
+// Convert degrees, minutes and seconds to decimal degrees
+// * Some writers only store degrees or degrees and decimal minutes
+fn to_degrees(dms: &[Rational]) -> Option<f64> {
+    let mut degrees = 0.0;
+    for (i, value) in dms.iter().take(3).enumerate() {
+        degrees += value.to_f64()? / 60f64.powi(i as i32);
+    }
+    match dms.is_empty() {
+        true => None,
+        false => Some(degrees),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let lat = [
+            Rational::new(37, 1),
+            Rational::new(46, 1),
+            Rational::new(3000, 100),
+        ];
+        let lon = [
+            Rational::new(122, 1),
+            Rational::new(25, 1),
+            Rational::new(900, 100),
+        ];
+        let pos =
+            GpsPosition::new(&lat, "N", &lon, "W", Some(Rational::new(105, 2)), Some(1)).unwrap();
+        assert_eq!(pos.latitude, 37.775);
+        assert!((pos.longitude + 122.419166).abs() < 0.000001);
+        assert_eq!(pos.altitude, Some(-52.5));
+        assert_eq!(pos.to_string(), "37.775000, -122.419167, -52.5 m");
+
+        // Decimal minutes with no altitude
+        let lat = [Rational::new(33, 1), Rational::new(5150, 100)];
+        let pos = GpsPosition::new(&lat, "S", &lat, "E", None, None).unwrap();
+        assert_eq!(pos.latitude, -33.858333333333334);
+        assert_eq!(pos.altitude, None);
+    }
+
+    #[test]
+    fn test_new_invalid() {
+        let lat = [Rational::new(37, 0)];
+        assert_eq!(GpsPosition::new(&lat, "N", &lat, "W", None, None), None);
+        let lat = [Rational::new(91, 1)];
+        assert_eq!(GpsPosition::new(&lat, "N", &lat, "W", None, None), None);
+        assert_eq!(GpsPosition::new(&[], "N", &[], "W", None, None), None);
+    }
+}
@@ -1,13 +1,44 @@
 use std::fmt::Display;
 
-pub(crate) enum LensSpec {
-    None,         // 0
+use super::Rational;
+
+/// LensSpecification min/max focal length in mm and min/max f-number
+/// * Unknown values are stored as 0/0
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct LensSpec {
+    focal: (Option<f64>, Option<f64>),
+    aperture: (Option<f64>, Option<f64>),
+}
+
+impl LensSpec {
+    /// Create the lens specification from its 4 rational components
+    pub(crate) fn new(values: &[Rational]) -> Option<Self> {
+        let value = |i: usize| values.get(i).and_then(|x| x.to_f64());
+        match values.len() {
+            4 => Some(Self { focal: (value(0), value(1)), aperture: (value(2), value(3)) }),
+            _ => None,
+        }
+    }
 }
 
-impl Display for LensSpec{
+// Render a range collapsing it to the known value when min and max match or one is unknown
+fn range(min: Option<f64>, max: Option<f64>) -> Option<String> {
+    match (min, max) {
+        (Some(min), Some(max)) if min != max => Some(format!("{}-{}", min, max)),
+        (Some(x), _) | (_, Some(x)) => Some(x.to_string()),
+        _ => None,
+    }
+}
+
+impl Display for LensSpec {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            LensSpec::None => write!(f, "None"),
+        match range(self.focal.0, self.focal.1) {
+            Some(focal) => write!(f, "{} mm", focal)?,
+            None => write!(f, "Unknown")?,
+        }
+        if let Some(aperture) = range(self.aperture.0, self.aperture.1) {
+            write!(f, " f/{}", aperture)?;
         }
+        Ok(())
     }
 }
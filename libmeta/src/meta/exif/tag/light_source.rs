@@ -0,0 +1,96 @@
+use std::fmt::Display;
+
+/// LightSource values
+/// https://exiftool.org/TagNames/EXIF.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LightSource {
+    Unknown,              // 0
+    Daylight,             // 1
+    Fluorescent,          // 2
+    Tungsten,             // 3
+    Flash,                // 4
+    FineWeather,          // 9
+    Cloudy,               // 10
+    Shade,                // 11
+    DaylightFluorescent,  // 12
+    DayWhiteFluorescent,  // 13
+    CoolWhiteFluorescent, // 14
+    WhiteFluorescent,     // 15
+    WarmWhiteFluorescent, // 16
+    StandardLightA,       // 17
+    StandardLightB,       // 18
+    StandardLightC,       // 19
+    D55,                  // 20
+    D65,                  // 21
+    D75,                  // 22
+    D50,                  // 23
+    IsoStudioTungsten,    // 24
+    Other,                // 255
+    Invalid(u16),         // any other value
+}
+
+impl From<usize> for LightSource {
+    fn from(val: usize) -> Self {
+        LightSource::from(val as u16)
+    }
+}
+
+impl From<u16> for LightSource {
+    fn from(val: u16) -> Self {
+        match val {
+            0 => LightSource::Unknown,
+            1 => LightSource::Daylight,
+            2 => LightSource::Fluorescent,
+            3 => LightSource::Tungsten,
+            4 => LightSource::Flash,
+            9 => LightSource::FineWeather,
+            10 => LightSource::Cloudy,
+            11 => LightSource::Shade,
+            12 => LightSource::DaylightFluorescent,
+            13 => LightSource::DayWhiteFluorescent,
+            14 => LightSource::CoolWhiteFluorescent,
+            15 => LightSource::WhiteFluorescent,
+            16 => LightSource::WarmWhiteFluorescent,
+            17 => LightSource::StandardLightA,
+            18 => LightSource::StandardLightB,
+            19 => LightSource::StandardLightC,
+            20 => LightSource::D55,
+            21 => LightSource::D65,
+            22 => LightSource::D75,
+            23 => LightSource::D50,
+            24 => LightSource::IsoStudioTungsten,
+            255 => LightSource::Other,
+            x => LightSource::Invalid(x),
+        }
+    }
+}
+
+impl Display for LightSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LightSource::Unknown => write!(f, "Unknown"),
+            LightSource::Daylight => write!(f, "Daylight"),
+            LightSource::Fluorescent => write!(f, "Fluorescent"),
+            LightSource::Tungsten => write!(f, "Tungsten (Incandescent)"),
+            LightSource::Flash => write!(f, "Flash"),
+            LightSource::FineWeather => write!(f, "Fine Weather"),
+            LightSource::Cloudy => write!(f, "Cloudy"),
+            LightSource::Shade => write!(f, "Shade"),
+            LightSource::DaylightFluorescent => write!(f, "Daylight Fluorescent"),
+            LightSource::DayWhiteFluorescent => write!(f, "Day White Fluorescent"),
+            LightSource::CoolWhiteFluorescent => write!(f, "Cool White Fluorescent"),
+            LightSource::WhiteFluorescent => write!(f, "White Fluorescent"),
+            LightSource::WarmWhiteFluorescent => write!(f, "Warm White Fluorescent"),
+            LightSource::StandardLightA => write!(f, "Standard Light A"),
+            LightSource::StandardLightB => write!(f, "Standard Light B"),
+            LightSource::StandardLightC => write!(f, "Standard Light C"),
+            LightSource::D55 => write!(f, "D55"),
+            LightSource::D65 => write!(f, "D65"),
+            LightSource::D75 => write!(f, "D75"),
+            LightSource::D50 => write!(f, "D50"),
+            LightSource::IsoStudioTungsten => write!(f, "ISO Studio Tungsten"),
+            LightSource::Other => write!(f, "Other"),
+            LightSource::Invalid(x) => write!(f, "Invalid ({})", x),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use std::fmt::Display;
+
+/// MeteringMode values
+/// https://exiftool.org/TagNames/EXIF.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MeteringMode {
+    Unknown,               // 0
+    Average,               // 1
+    CenterWeightedAverage, // 2
+    Spot,                  // 3
+    MultiSpot,             // 4
+    MultiSegment,          // 5
+    Partial,               // 6
+    Other,                 // 255
+    Invalid(u16),          // any other value
+}
+
+impl From<usize> for MeteringMode {
+    fn from(val: usize) -> Self {
+        MeteringMode::from(val as u16)
+    }
+}
+
+impl From<u16> for MeteringMode {
+    fn from(val: u16) -> Self {
+        match val {
+            0 => MeteringMode::Unknown,
+            1 => MeteringMode::Average,
+            2 => MeteringMode::CenterWeightedAverage,
+            3 => MeteringMode::Spot,
+            4 => MeteringMode::MultiSpot,
+            5 => MeteringMode::MultiSegment,
+            6 => MeteringMode::Partial,
+            255 => MeteringMode::Other,
+            x => MeteringMode::Invalid(x),
+        }
+    }
+}
+
+impl Display for MeteringMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MeteringMode::Unknown => write!(f, "Unknown"),
+            MeteringMode::Average => write!(f, "Average"),
+            MeteringMode::CenterWeightedAverage => write!(f, "Center-weighted average"),
+            MeteringMode::Spot => write!(f, "Spot"),
+            MeteringMode::MultiSpot => write!(f, "Multi-spot"),
+            MeteringMode::MultiSegment => write!(f, "Multi-segment"),
+            MeteringMode::Partial => write!(f, "Partial"),
+            MeteringMode::Other => write!(f, "Other"),
+            MeteringMode::Invalid(x) => write!(f, "Invalid ({})", x),
+        }
+    }
+}
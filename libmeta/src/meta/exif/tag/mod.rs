@@ -1,23 +1,55 @@
+mod capture_time;
+mod color_space;
+mod component_configuration;
 mod contrast;
+mod custom_rendered;
+mod exposure_mode;
+mod exposure_program;
+mod file_source;
+mod flash;
 mod gain;
+mod gps_position;
+mod lens_spec;
+mod light_source;
+mod metering_mode;
 mod orientation;
 mod rational;
 mod resolution_unit;
 mod saturation;
 mod scene;
-mod lens_spec;
+mod scene_type;
+mod sensing_method;
 mod sharpness;
+mod subject_distance_range;
 mod tag;
+mod vendor;
+mod white_balance;
 mod y_cb_cr_coefficients;
 
+pub use capture_time::*;
+pub use color_space::*;
+pub use component_configuration::*;
+pub use contrast::*;
+pub use custom_rendered::*;
+pub use exposure_mode::*;
+pub use exposure_program::*;
+pub use file_source::*;
+pub use flash::*;
+pub use gain::*;
+pub use gps_position::*;
 pub(crate) use lens_spec::*;
-pub(crate) use contrast::*;
-pub(crate) use gain::*;
-pub(crate) use orientation::*;
-pub(crate) use rational::*;
-pub(crate) use resolution_unit::*;
-pub(crate) use saturation::*;
-pub(crate) use scene::*;
-pub(crate) use sharpness::*;
-pub(crate) use tag::*;
-pub(crate) use y_cb_cr_coefficients::*;
+pub use light_source::*;
+pub use metering_mode::*;
+pub use orientation::*;
+pub use rational::*;
+pub use resolution_unit::*;
+pub use saturation::*;
+pub use scene::*;
+pub use scene_type::*;
+pub use sensing_method::*;
+pub use sharpness::*;
+pub use subject_distance_range::*;
+pub use tag::*;
+pub use vendor::*;
+pub use white_balance::*;
+pub use y_cb_cr_coefficients::*;
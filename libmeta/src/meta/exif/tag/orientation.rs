@@ -2,7 +2,8 @@ use std::fmt::Display;
 
 /// Orientation values
 /// https://exiftool.org/TagNames/EXIF.html
-pub(crate) enum Orientation {
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Orientation {
     Horizontal,                     // 1, normal
     MirrorHorizontal,               // 2,
     Rotate180,                      // 3,
@@ -1,15 +1,21 @@
-use std::fmt::Display;
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
 
 use crate::{errors::ExifError, Endian, ExifResult};
 
-#[derive(Debug, PartialEq)]
-pub(crate) struct Rational {
-    pub(crate) num: u32, // numerator
-    pub(crate) den: u32, // denominator
+/// Largest denominator used when parsing decimal strings e.g. `2.8`
+const MAX_PARSE_DENOMINATOR: u32 = 10000;
+
+/// Unsigned rational number made up of two u32 values
+/// * Equality and ordering compare the values e.g. `1/2 == 2/4`
+/// * A zero denominator is undefined and only equals the identical rational
+#[derive(Debug, Clone, Copy)]
+pub struct Rational {
+    pub num: u32, // numerator
+    pub den: u32, // denominator
 }
 
 impl Rational {
-    pub(crate) fn new(num: u32, den: u32) -> Self {
+    pub fn new(num: u32, den: u32) -> Self {
         Self { num, den }
     }
 
@@ -28,6 +34,114 @@ impl Rational {
             }),
         }
     }
+
+    /// Create the closest rational to the given value with a denominator no larger than **max_den**
+    /// * Returns: None for negative, non finite or out of range values
+    pub fn from_f64(value: f64, max_den: u32) -> Option<Self> {
+        if !value.is_finite() || value < 0.0 || value > u32::MAX as f64 {
+            return None;
+        }
+        let (num, den) = approximate(value, u32::MAX as u64, max_den as u64)?;
+        Some(Self::new(num.try_into().ok()?, den.try_into().ok()?))
+    }
+
+    /// Convert to a floating point value
+    /// * Returns: None when the denominator is zero
+    pub fn to_f64(&self) -> Option<f64> {
+        match self.den {
+            0 => None,
+            den => Some(self.num as f64 / den as f64),
+        }
+    }
+
+    /// Reduce to the lowest terms e.g. `10/250` becomes `1/25`
+    /// * Zero denominators are left as is
+    pub fn reduce(&self) -> Self {
+        match (self.den, gcd(self.num as u128, self.den as u128)) {
+            (0, _) | (_, 0) => *self,
+            (_, x) => Self::new(self.num / x as u32, self.den / x as u32),
+        }
+    }
+
+    /// Add the rationals returning None for zero denominators or overflow
+    pub fn checked_add(&self, other: Rational) -> Option<Self> {
+        let (a, b) = (self.widen()?, other.widen()?);
+        Self::narrow(a.0 * b.1 + b.0 * a.1, a.1 * b.1)
+    }
+
+    /// Subtract the rationals returning None for zero denominators, overflow or negative results
+    pub fn checked_sub(&self, other: Rational) -> Option<Self> {
+        let (a, b) = (self.widen()?, other.widen()?);
+        Self::narrow((a.0 * b.1).checked_sub(b.0 * a.1)?, a.1 * b.1)
+    }
+
+    /// Multiply the rationals returning None for zero denominators or overflow
+    pub fn checked_mul(&self, other: Rational) -> Option<Self> {
+        let (a, b) = (self.widen()?, other.widen()?);
+        Self::narrow(a.0 * b.0, a.1 * b.1)
+    }
+
+    /// Divide the rationals returning None for zero denominators, a zero divisor or overflow
+    pub fn checked_div(&self, other: Rational) -> Option<Self> {
+        let (a, b) = (self.widen()?, other.widen()?);
+        Self::narrow(a.0 * b.1, a.1 * b.0)
+    }
+
+    // Widen to u128 for overflow free intermediate math
+    fn widen(&self) -> Option<(u128, u128)> {
+        match self.den {
+            0 => None,
+            den => Some((self.num as u128, den as u128)),
+        }
+    }
+
+    // Reduce and narrow back down to u32 values
+    fn narrow(num: u128, den: u128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+        let x = gcd(num, den);
+        Some(Self::new((num / x).try_into().ok()?, (den / x).try_into().ok()?))
+    }
+}
+
+impl PartialEq for Rational {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.den, other.den) {
+            (0, _) | (_, 0) => self.num == other.num && self.den == other.den,
+            _ => self.num as u64 * other.den as u64 == other.num as u64 * self.den as u64,
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.den, other.den) {
+            (0, _) | (_, 0) => (self == other).then_some(Ordering::Equal),
+            _ => Some(
+                (self.num as u64 * other.den as u64).cmp(&(other.num as u64 * self.den as u64)),
+            ),
+        }
+    }
+}
+
+impl FromStr for Rational {
+    type Err = ExifError;
+
+    /// Parse from `num/den`, a whole number or a decimal e.g. `1/250`, `72` or `2.8`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ExifError::parse(": rational string invalid").with_data(s.as_bytes());
+        match s.split_once('/') {
+            Some((num, den)) => Ok(Self::new(
+                num.trim().parse().map_err(|_| err())?,
+                den.trim().parse().map_err(|_| err())?,
+            )),
+            None => {
+                let value: f64 = s.trim().parse().map_err(|_| err())?;
+                Self::from_f64(value, MAX_PARSE_DENOMINATOR).ok_or_else(err)
+            }
+        }
+    }
 }
 
 impl Display for Rational {
@@ -39,6 +153,203 @@ impl Display for Rational {
     }
 }
 
+/// Signed rational number made up of two i32 values
+/// * Equality and ordering compare the values e.g. `-1/2 == 1/-2`
+/// * A zero denominator is undefined and only equals the identical rational
+#[derive(Debug, Clone, Copy)]
+pub struct SRational {
+    pub num: i32, // numerator
+    pub den: i32, // denominator
+}
+
+impl SRational {
+    pub fn new(num: i32, den: i32) -> Self {
+        Self { num, den }
+    }
+
+    pub(crate) fn try_from(val: &[u8], endian: Endian) -> ExifResult<Self> {
+        if val.len() < 8 {
+            return Err(ExifError::parse(": signed rational must be 8 bytes long"));
+        }
+        match endian {
+            Endian::Little => Ok(Self {
+                num: i32::from_le_bytes(val[0..4].try_into().unwrap()),
+                den: i32::from_le_bytes(val[4..8].try_into().unwrap()),
+            }),
+            Endian::Big => Ok(Self {
+                num: i32::from_be_bytes(val[0..4].try_into().unwrap()),
+                den: i32::from_be_bytes(val[4..8].try_into().unwrap()),
+            }),
+        }
+    }
+
+    /// Create the closest rational to the given value with a denominator no larger than **max_den**
+    /// * Returns: None for non finite or out of range values
+    pub fn from_f64(value: f64, max_den: u32) -> Option<Self> {
+        if !value.is_finite() || value.abs() > i32::MAX as f64 {
+            return None;
+        }
+        let max_den = max_den.min(i32::MAX as u32) as u64;
+        let (num, den) = approximate(value.abs(), i32::MAX as u64, max_den)?;
+        let num: i32 = num.try_into().ok()?;
+        Some(Self::new(if value < 0.0 { -num } else { num }, den.try_into().ok()?))
+    }
+
+    /// Convert to a floating point value
+    /// * Returns: None when the denominator is zero
+    pub fn to_f64(&self) -> Option<f64> {
+        match self.den {
+            0 => None,
+            den => Some(self.num as f64 / den as f64),
+        }
+    }
+
+    /// Reduce to the lowest terms with a positive denominator e.g. `10/-250` becomes `-1/25`
+    /// * Zero denominators are left as is
+    pub fn reduce(&self) -> Self {
+        match self.widen() {
+            Some((num, den)) => Self::narrow(num, den).unwrap_or(*self),
+            None => *self,
+        }
+    }
+
+    /// Add the rationals returning None for zero denominators or overflow
+    pub fn checked_add(&self, other: SRational) -> Option<Self> {
+        let (a, b) = (self.widen()?, other.widen()?);
+        Self::narrow(a.0 * b.1 + b.0 * a.1, a.1 * b.1)
+    }
+
+    /// Subtract the rationals returning None for zero denominators or overflow
+    pub fn checked_sub(&self, other: SRational) -> Option<Self> {
+        let (a, b) = (self.widen()?, other.widen()?);
+        Self::narrow(a.0 * b.1 - b.0 * a.1, a.1 * b.1)
+    }
+
+    /// Multiply the rationals returning None for zero denominators or overflow
+    pub fn checked_mul(&self, other: SRational) -> Option<Self> {
+        let (a, b) = (self.widen()?, other.widen()?);
+        Self::narrow(a.0 * b.0, a.1 * b.1)
+    }
+
+    /// Divide the rationals returning None for zero denominators, a zero divisor or overflow
+    pub fn checked_div(&self, other: SRational) -> Option<Self> {
+        let (a, b) = (self.widen()?, other.widen()?);
+        Self::narrow(a.0 * b.1, a.1 * b.0)
+    }
+
+    // Widen to i128 with a positive denominator for overflow free intermediate math
+    fn widen(&self) -> Option<(i128, i128)> {
+        match self.den {
+            0 => None,
+            den if den < 0 => Some((-(self.num as i128), -(den as i128))),
+            den => Some((self.num as i128, den as i128)),
+        }
+    }
+
+    // Reduce with a positive denominator and narrow back down to i32 values
+    fn narrow(num: i128, den: i128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+        let x = gcd(num.unsigned_abs(), den.unsigned_abs()) as i128;
+        let sign = if den < 0 { -1 } else { 1 };
+        Some(Self::new((sign * num / x).try_into().ok()?, (sign * den / x).try_into().ok()?))
+    }
+}
+
+impl PartialEq for SRational {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.widen(), other.widen()) {
+            (Some(a), Some(b)) => a.0 * b.1 == b.0 * a.1,
+            _ => self.num == other.num && self.den == other.den,
+        }
+    }
+}
+
+impl PartialOrd for SRational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.widen(), other.widen()) {
+            (Some(a), Some(b)) => Some((a.0 * b.1).cmp(&(b.0 * a.1))),
+            _ => (self == other).then_some(Ordering::Equal),
+        }
+    }
+}
+
+impl FromStr for SRational {
+    type Err = ExifError;
+
+    /// Parse from `num/den`, a whole number or a decimal e.g. `-1/3`, `-2` or `0.7`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ExifError::parse(": signed rational string invalid").with_data(s.as_bytes());
+        match s.split_once('/') {
+            Some((num, den)) => Ok(Self::new(
+                num.trim().parse().map_err(|_| err())?,
+                den.trim().parse().map_err(|_| err())?,
+            )),
+            None => {
+                let value: f64 = s.trim().parse().map_err(|_| err())?;
+                Self::from_f64(value, MAX_PARSE_DENOMINATOR).ok_or_else(err)
+            }
+        }
+    }
+}
+
+impl Display for SRational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.den {
+            1 => write!(f, "{}", self.num), // common understanding is out of 1
+            _ => write!(f, "{}/{}", self.num, self.den),
+        }
+    }
+}
+
+// Greatest common divisor where gcd(0, 0) is 0
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+// Best rational approximation of a positive value using continued fractions
+// * Stops at the last convergent or semiconvergent within the numerator and denominator bounds
+// * The value must not be larger than **max_num** so the first convergent always fits
+fn approximate(value: f64, max_num: u64, max_den: u64) -> Option<(u64, u64)> {
+    if max_den == 0 {
+        return None;
+    }
+    let (mut h0, mut h1, mut k0, mut k1) = (0u64, 1u64, 1u64, 0u64);
+    let mut x = value;
+    loop {
+        let a = x.floor();
+        if a > u32::MAX as f64 {
+            break;
+        }
+        let a = a as u64;
+        let (h2, k2) = (a * h1 + h0, a * k1 + k0);
+        if h2 > max_num || k2 > max_den {
+            // Semiconvergent with the largest numerator and denominator allowed if it's closer
+            let n = match h1 {
+                0 => (max_den - k0) / k1,
+                _ => ((max_den - k0) / k1).min((max_num - h0) / h1),
+            };
+            let (h, k) = (n * h1 + h0, n * k1 + k0);
+            let error = |h: u64, k: u64| (value - h as f64 / k as f64).abs();
+            if n > 0 && error(h, k) < error(h1, k1) {
+                return Some((h, k));
+            }
+            break;
+        }
+        (h0, h1, k0, k1) = (h1, h2, k1, k2);
+        let fract = x - a as f64;
+        if fract < 1e-12 || h1 as f64 / k1 as f64 == value {
+            break;
+        }
+        x = 1.0 / fract;
+    }
+    Some((h1, k1))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,4 +379,92 @@ mod tests {
         assert_eq!(r.num, 1);
         assert_eq!(r.den, 2);
     }
+
+    #[test]
+    fn test_srational_negative() {
+        let r =
+            SRational::try_from(&[0xFF, 0xFF, 0xFF, 0xFD, 0x00, 0x00, 0x00, 0x03][..], Endian::Big)
+                .unwrap();
+        assert_eq!(r, SRational::new(-3, 3));
+        assert_eq!(r.to_string(), "-3/3");
+    }
+
+    #[test]
+    fn test_rational_math() {
+        let a = Rational::new(1, 4);
+        let b = Rational::new(2, 8);
+        assert_eq!(a, b);
+        assert_eq!(b.reduce().den, 4);
+        assert_eq!(a.checked_add(b), Some(Rational::new(1, 2)));
+        assert_eq!(a.checked_sub(Rational::new(1, 2)), None);
+        assert_eq!(a.checked_mul(Rational::new(2, 1)), Some(Rational::new(1, 2)));
+        assert_eq!(a.checked_div(Rational::new(0, 1)), None);
+        assert_eq!(a.checked_add(Rational::new(1, 0)), None);
+        assert_eq!(Rational::new(u32::MAX, 1).checked_add(Rational::new(1, 1)), None);
+        let max = Rational::new(u32::MAX, u32::MAX - 2);
+        assert_eq!(max.checked_add(max), None);
+        let min = SRational::new(i32::MIN, i32::MAX);
+        assert_eq!(min.checked_add(min), None);
+        assert_eq!(Rational::new(30, 1).to_f64(), Some(30.0));
+        assert_eq!(Rational::new(30, 0).to_f64(), None);
+        assert_eq!(Rational::new(0, 0).reduce(), Rational::new(0, 0));
+
+        // Minutes and seconds to degrees
+        let minutes = Rational::new(46, 1)
+            .checked_div(Rational::new(60, 1))
+            .unwrap();
+        assert_eq!(minutes, Rational::new(23, 30));
+    }
+
+    #[test]
+    fn test_rational_ordering() {
+        assert!(Rational::new(1, 250) < Rational::new(1, 125));
+        assert!(Rational::new(28, 10) > Rational::new(2, 1));
+        assert_ne!(Rational::new(1, 0), Rational::new(2, 0));
+        assert_eq!(Rational::new(1, 0), Rational::new(1, 0));
+        assert_eq!(Rational::new(1, 0).partial_cmp(&Rational::new(1, 2)), None);
+        assert!(SRational::new(-1, 3) < SRational::new(1, -4));
+        assert_eq!(SRational::new(1, -2), SRational::new(-1, 2));
+    }
+
+    #[test]
+    fn test_rational_from_str() {
+        assert_eq!("1/250".parse::<Rational>().unwrap(), Rational::new(1, 250));
+        assert_eq!(" 72 ".parse::<Rational>().unwrap(), Rational::new(72, 1));
+        let r = "2.8".parse::<Rational>().unwrap();
+        assert_eq!((r.num, r.den), (14, 5));
+        let err = "f/2.8".parse::<Rational>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Exif parse failed: rational string invalid [66, 2f, 32, 2e, 38]"
+        );
+        assert!("-1/3".parse::<Rational>().is_err());
+        assert_eq!("-1/3".parse::<SRational>().unwrap(), SRational::new(-1, 3));
+        assert_eq!("-0.5".parse::<SRational>().unwrap(), SRational::new(-1, 2));
+    }
+
+    #[test]
+    fn test_rational_from_f64() {
+        let r = Rational::from_f64(std::f64::consts::PI, 1000).unwrap();
+        assert_eq!((r.num, r.den), (355, 113));
+        let r = Rational::from_f64(0.004, 1000).unwrap();
+        assert_eq!((r.num, r.den), (1, 250));
+        let r = Rational::from_f64(1.0 / 3.0, 2).unwrap();
+        assert_eq!((r.num, r.den), (1, 2));
+        assert_eq!(Rational::from_f64(-1.0, 10), None);
+        assert_eq!(Rational::from_f64(f64::NAN, 10), None);
+        assert_eq!(Rational::from_f64(1.5, 0), None);
+
+        // Numerator overflow stops at the last convergent that fits
+        let r = Rational::from_f64(123456789.98765433, u32::MAX);
+        assert_eq!(r, Some(Rational::new(123456790, 1)));
+        let r = SRational::from_f64(-123456789.98765433, u32::MAX);
+        assert_eq!(r, Some(SRational::new(-123456790, 1)));
+        let r = Rational::from_f64(1234.5678, u32::MAX).unwrap();
+        assert_eq!(r.to_f64(), Some(1234.5678));
+
+        let r = SRational::from_f64(-0.7, 100).unwrap();
+        assert_eq!((r.num, r.den), (-7, 10));
+        assert_eq!(SRational::new(10, -250).reduce().to_string(), "-1/25");
+    }
 }
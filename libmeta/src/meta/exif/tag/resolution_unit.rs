@@ -1,7 +1,7 @@
 use std::fmt::Display;
 
 /// Jfif Density Units
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ResolutionUnit {
     PixelsPerInch,
     PixelsPerCm,
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
-pub(crate) enum Saturation {
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Saturation {
     Normal, // 0
     Low,    // 1
     High,   // 2
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
-pub(crate) enum Scene {
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scene {
     Standard,  // 0
     Landscape, // 1
     Portrait,  // 2
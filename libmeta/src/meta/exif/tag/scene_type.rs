@@ -0,0 +1,39 @@
+use std::fmt::Display;
+
+/// SceneType values
+/// https://exiftool.org/TagNames/EXIF.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SceneType {
+    DirectlyPhotographed, // 1
+    Unknown(u16),         // any other value
+}
+
+impl From<usize> for SceneType {
+    fn from(val: usize) -> Self {
+        SceneType::from(val as u16)
+    }
+}
+
+impl From<u8> for SceneType {
+    fn from(val: u8) -> Self {
+        SceneType::from(val as u16)
+    }
+}
+
+impl From<u16> for SceneType {
+    fn from(val: u16) -> Self {
+        match val {
+            1 => SceneType::DirectlyPhotographed,
+            x => SceneType::Unknown(x),
+        }
+    }
+}
+
+impl Display for SceneType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SceneType::DirectlyPhotographed => write!(f, "Directly photographed"),
+            SceneType::Unknown(x) => write!(f, "Unknown ({})", x),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::fmt::Display;
+
+/// SensingMethod values
+/// https://exiftool.org/TagNames/EXIF.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SensingMethod {
+    NotDefined,            // 1
+    OneChipColorArea,      // 2
+    TwoChipColorArea,      // 3
+    ThreeChipColorArea,    // 4
+    ColorSequentialArea,   // 5
+    Trilinear,             // 7
+    ColorSequentialLinear, // 8
+    Unknown(u16),          // any other value
+}
+
+impl From<usize> for SensingMethod {
+    fn from(val: usize) -> Self {
+        SensingMethod::from(val as u16)
+    }
+}
+
+impl From<u16> for SensingMethod {
+    fn from(val: u16) -> Self {
+        match val {
+            1 => SensingMethod::NotDefined,
+            2 => SensingMethod::OneChipColorArea,
+            3 => SensingMethod::TwoChipColorArea,
+            4 => SensingMethod::ThreeChipColorArea,
+            5 => SensingMethod::ColorSequentialArea,
+            7 => SensingMethod::Trilinear,
+            8 => SensingMethod::ColorSequentialLinear,
+            x => SensingMethod::Unknown(x),
+        }
+    }
+}
+
+impl Display for SensingMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SensingMethod::NotDefined => write!(f, "Not defined"),
+            SensingMethod::OneChipColorArea => write!(f, "One-chip color area"),
+            SensingMethod::TwoChipColorArea => write!(f, "Two-chip color area"),
+            SensingMethod::ThreeChipColorArea => write!(f, "Three-chip color area"),
+            SensingMethod::ColorSequentialArea => write!(f, "Color sequential area"),
+            SensingMethod::Trilinear => write!(f, "Trilinear"),
+            SensingMethod::ColorSequentialLinear => write!(f, "Color sequential linear"),
+            SensingMethod::Unknown(x) => write!(f, "Unknown ({})", x),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
-pub(crate) enum Sharpness {
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sharpness {
     Normal, // 0
     Soft,   // 1
     Hard,   // 2
@@ -0,0 +1,42 @@
+use std::fmt::Display;
+
+/// SubjectDistanceRange values
+/// https://exiftool.org/TagNames/EXIF.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SubjectDistanceRange {
+    Unknown,      // 0
+    Macro,        // 1
+    Close,        // 2
+    Distant,      // 3
+    Invalid(u16), // any other value
+}
+
+impl From<usize> for SubjectDistanceRange {
+    fn from(val: usize) -> Self {
+        SubjectDistanceRange::from(val as u16)
+    }
+}
+
+impl From<u16> for SubjectDistanceRange {
+    fn from(val: u16) -> Self {
+        match val {
+            0 => SubjectDistanceRange::Unknown,
+            1 => SubjectDistanceRange::Macro,
+            2 => SubjectDistanceRange::Close,
+            3 => SubjectDistanceRange::Distant,
+            x => SubjectDistanceRange::Invalid(x),
+        }
+    }
+}
+
+impl Display for SubjectDistanceRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubjectDistanceRange::Unknown => write!(f, "Unknown"),
+            SubjectDistanceRange::Macro => write!(f, "Macro"),
+            SubjectDistanceRange::Close => write!(f, "Close"),
+            SubjectDistanceRange::Distant => write!(f, "Distant"),
+            SubjectDistanceRange::Invalid(x) => write!(f, "Invalid ({})", x),
+        }
+    }
+}
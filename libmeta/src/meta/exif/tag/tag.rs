@@ -3,8 +3,18 @@
 
 use std::fmt::Display;
 
+use super::super::format::*;
+use super::Vendor;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Tag {
+    /// Type of data contained in this IFD
+    /// * Bit 0 = reduced resolution version of another image, bit 1 = single page of a multi-page
+    ///   image, bit 2 = transparency mask for another image
+    /// * **Format**: u32
+    /// * **Components**: 1
+    NewSubfileType,
+
     /// Image width
     /// * **Format**: u32
     /// * **Components**: 1
@@ -47,9 +57,9 @@ pub enum Tag {
     /// * **Components**: n
     Model,
 
-    /// Strip offsets
-    /// * **Format**: u32
-    /// * **Components**: 1
+    /// Byte offset of each strip of image data
+    /// * **Format**: u16 or u32
+    /// * **Components**: n i.e. number of strips
     StripOffsets,
 
     /// Shows orientation of the camera
@@ -70,6 +80,16 @@ pub enum Tag {
     /// * **Components**: 1
     SamplesPerPixel,
 
+    /// Number of rows in each strip, the last strip may contain fewer rows
+    /// * **Format**: u16 or u32
+    /// * **Components**: 1
+    RowsPerStrip,
+
+    /// Number of bytes in each strip of image data
+    /// * **Format**: u16 or u32
+    /// * **Components**: n i.e. number of strips
+    StripByteCounts,
+
     /// Shows resolution of the X axis, often 72/1 i.e. 72 pixels per inch, but this really has
     /// no meaning as computers don't use this value for display.
     /// * **Format**: Unsigned rational
@@ -82,12 +102,29 @@ pub enum Tag {
     /// * **Components**: 1
     YResolution,
 
+    /// How the components of each pixel are stored
+    /// * 1 = Chunky i.e. interleaved, 2 = Planar i.e. separate planes per component
+    /// * **Format**: u16
+    /// * **Components**: 1
+    PlanarConfiguration,
+
     /// Shows resolution unit
     /// * 1 = no-unit, 2 = inch, 3 = centimeter
     /// * **Format**: u16
     /// * **Components**: 1
     ResolutionUnit,
 
+    /// Page number of this image in a multi-page document
+    /// * First value is the page number starting at 0, second is the total pages or 0 if unknown
+    /// * **Format**: u16
+    /// * **Components**: 2
+    PageNumber,
+
+    /// Transfer function for the image in tabular style
+    /// * **Format**: u16
+    /// * **Components**: 768
+    TransferFunction,
+
     /// Shows software version
     /// * **Format**: ASCII
     /// * **Components**: n e.g. 12
@@ -100,6 +137,11 @@ pub enum Tag {
     /// * **Components**: 20
     DateTime,
 
+    /// Person who created the image
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    Artist,
+
     /// Defines chromaticity of white point of the image.
     /// * If the image uses CIE Standard Illumination D65(known as international standard of 'daylight'), the values are '3127/10000,3290/10000'.
     /// * **Format**: Unsigned rational
@@ -112,6 +154,26 @@ pub enum Tag {
     /// * **Components**: 6
     PrimaryChromaticities,
 
+    /// Tile width in pixels
+    /// * **Format**: u16 or u32
+    /// * **Components**: 1
+    TileWidth,
+
+    /// Tile length i.e. height in pixels
+    /// * **Format**: u16 or u32
+    /// * **Components**: 1
+    TileLength,
+
+    /// Byte offset of each tile of image data
+    /// * **Format**: u32
+    /// * **Components**: n i.e. number of tiles
+    TileOffsets,
+
+    /// Number of bytes in each tile of image data
+    /// * **Format**: u16 or u32
+    /// * **Components**: n i.e. number of tiles
+    TileByteCounts,
+
     /// Thumbnail offset
     /// * Data format is ordinary JPEG starting from 0xFFD8 and ending by 0xFFD9
     /// * Typically the recommended thumbnail size is 160x120 for Exif 2.1 or later
@@ -131,6 +193,12 @@ pub enum Tag {
     /// * **Components**: 3
     YCbCrCoefficients,
 
+    /// Sampling ratio of chrominance to luminance components
+    /// * [2, 1] = YCbCr4:2:2, [2, 2] = YCbCr4:2:0
+    /// * **Format**: u16
+    /// * **Components**: 2
+    YCbCrSubSampling,
+
     /// When image format is YCbCr and uses 'Subsampling'(cropping of chroma data, all the digicam do that), defines the chroma sample point of subsampling pixel array. '1' means the center of pixel array, '2' means the datum point.
     /// * **1** => Centered
     /// * **2** => Co-sited
@@ -171,6 +239,11 @@ pub enum Tag {
     /// * **Components**: 1
     ExposureProgram,
 
+    /// Spectral sensitivity of each channel of the camera used
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    SpectralSensitivity,
+
     /// Shows offset to GPS Info IFD
     /// * **Format**: u32
     /// * **Components**: 1
@@ -181,6 +254,42 @@ pub enum Tag {
     /// * **Components**: 2
     IsoSpeedRatings,
 
+    /// Opto-Electric Conversion Function as specified in ISO 14524
+    /// * **Format**: Undefined
+    /// * **Components**: n
+    Oecf,
+
+    /// Which of the sensitivity parameters is recorded in IsoSpeedRatings
+    /// * 0 = Unknown, 1 = Standard output sensitivity, 2 = Recommended exposure index, 3 = ISO speed
+    /// * **Format**: u16
+    /// * **Components**: 1
+    SensitivityType,
+
+    /// Standard output sensitivity value as specified in ISO 12232
+    /// * **Format**: u32
+    /// * **Components**: 1
+    StandardOutputSensitivity,
+
+    /// Recommended exposure index value as specified in ISO 12232
+    /// * **Format**: u32
+    /// * **Components**: 1
+    RecommendedExposureIndex,
+
+    /// ISO speed value as specified in ISO 12232
+    /// * **Format**: u32
+    /// * **Components**: 1
+    IsoSpeed,
+
+    /// ISO speed latitude yyy value as specified in ISO 12232
+    /// * **Format**: u32
+    /// * **Components**: 1
+    IsoSpeedLatitudeyyy,
+
+    /// ISO speed latitude zzz value as specified in ISO 12232
+    /// * **Format**: u32
+    /// * **Components**: 1
+    IsoSpeedLatitudezzz,
+
     /// Exif version number.
     /// * Stored as 4bytes of ASCII character (e.g. "0210")
     /// * **Format**: Undefined but turns out to be ASCII
@@ -199,6 +308,24 @@ pub enum Tag {
     /// * **Components**: 20
     DateTimeDigitized,
 
+    /// Time zone offset of DateTime from UTC
+    /// * Stored as `+HH:MM` or `-HH:MM`
+    /// * **Format**: ASCII
+    /// * **Components**: 7
+    OffsetTime,
+
+    /// Time zone offset of DateTimeOriginal from UTC
+    /// * Stored as `+HH:MM` or `-HH:MM`
+    /// * **Format**: ASCII
+    /// * **Components**: 7
+    OffsetTimeOriginal,
+
+    /// Time zone offset of DateTimeDigitized from UTC
+    /// * Stored as `+HH:MM` or `-HH:MM`
+    /// * **Format**: ASCII
+    /// * **Components**: 7
+    OffsetTimeDigitized,
+
     /// Unknown value
     /// * Seems to always be 0x00,0x01,0x02,0x03
     /// * **Format**: u32
@@ -269,6 +396,13 @@ pub enum Tag {
     /// * **Components**: 1
     FocalLength,
 
+    /// Location and area of the main subject in the scene.
+    /// * 2 components are a point, 3 a circle center and diameter and 4 a rectangle center,
+    ///   width and height
+    /// * **Format**: u16
+    /// * **Components**: 2, 3 or 4
+    SubjectArea,
+
     /// Maker dependent internal data.
     /// * Some of maker such as Olympus/Nikon/Sanyo etc. uses IFD format for this area.
     /// * **Format**: Undefined
@@ -279,23 +413,78 @@ pub enum Tag {
     /// * **Components**: ?
     UserComment,
 
-    /// Stores the XP comment
+    /// Fractions of a second for DateTime
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    SubSecTime,
+
+    /// Fractions of a second for DateTimeOriginal
     /// * **Format**: ASCII
     /// * **Components**: n
+    SubSecTimeOriginal,
+
+    /// Fractions of a second for DateTimeDigitized
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    SubSecTimeDigitized,
+
+    /// Ambient temperature in degrees Celsius when the image was taken
+    /// * **Format**: Signed rational
+    /// * **Components**: 1
+    Temperature,
+
+    /// Ambient relative humidity as a percentage when the image was taken
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 1
+    Humidity,
+
+    /// Ambient air pressure in hPa when the image was taken
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 1
+    Pressure,
+
+    /// Water depth in metres when the image was taken, negative above the surface
+    /// * **Format**: Signed rational
+    /// * **Components**: 1
+    WaterDepth,
+
+    /// Acceleration of the camera in mGal when the image was taken
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 1
+    Acceleration,
+
+    /// Elevation angle of the camera in degrees when the image was taken
+    /// * **Format**: Signed rational
+    /// * **Components**: 1
+    CameraElevationAngle,
+
+    /// Stores the XP title
+    /// * Windows XP UTF-16LE string written by Explorer
+    /// * **Format**: u8
+    /// * **Components**: n
+    XPTitle,
+
+    /// Stores the XP comment
+    /// * Windows XP UTF-16LE string written by Explorer
+    /// * **Format**: u8
+    /// * **Components**: n
     XPComment,
 
     /// Stores the XP author
-    /// * **Format**: ASCII
+    /// * Windows XP UTF-16LE string written by Explorer
+    /// * **Format**: u8
     /// * **Components**: n
     XPAuthor,
 
     /// Stores the XP keywords
-    /// * **Format**: ASCII
+    /// * Windows XP UTF-16LE string written by Explorer
+    /// * **Format**: u8
     /// * **Components**: n
     XPKeywords,
 
     /// Stores the XP subject
-    /// * **Format**: ASCII
+    /// * Windows XP UTF-16LE string written by Explorer
+    /// * **Format**: u8
     /// * **Components**: n
     XPSubject,
 
@@ -330,6 +519,16 @@ pub enum Tag {
     /// * **Components**: 1
     ExifInteroperabilityOffset,
 
+    /// Strobe energy in BCPS at the time the image was captured
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 1
+    FlashEnergy,
+
+    /// Spatial frequency table and response values as specified in ISO 12233
+    /// * **Format**: Undefined
+    /// * **Components**: n
+    SpatialFrequencyResponse,
+
     /// CCD's pixel density.
     /// * **Format**: Unsigned rational
     /// * **Components**: 1
@@ -346,6 +545,16 @@ pub enum Tag {
     /// * **Components**: 1
     FocalPlaneResolutionUnit,
 
+    /// Column and row of the main subject in the scene
+    /// * **Format**: u16
+    /// * **Components**: 2
+    SubjectLocation,
+
+    /// Exposure index selected on the camera when the image was captured
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 1
+    ExposureIndex,
+
     /// Show type of image sensor unit.
     /// * '2' means 1 chip color area sensor, most of all digicam use this type.
     /// * **Format**: u16
@@ -362,6 +571,17 @@ pub enum Tag {
     /// * **Components**: 1
     SceneType,
 
+    /// Color filter array geometric pattern of the image sensor
+    /// * **Format**: Undefined
+    /// * **Components**: n
+    CfaPattern,
+
+    /// Special processing applied to the image data.
+    /// * 0 = Normal, 1 = Custom
+    /// * **Format**: u16
+    /// * **Components**: 1
+    CustomRendered,
+
     /// Exposure Mode
     /// * 0 = Auto, 1 = Manual, 2 = Auto bracket
     /// * **Format**: u16
@@ -431,13 +651,17 @@ pub enum Tag {
     ImageUniqueID,
 
     /// Camera owner name
+    /// * Named CameraOwnerName in the EXIF 2.32 spec
     /// * **Format**: ASCII
     /// * **Components**: n
+    #[doc(alias = "CameraOwnerName")]
     OwnerName,
 
-    /// Serial number
+    /// Serial number of the camera body
+    /// * Named BodySerialNumber in the EXIF 2.32 spec
     /// * **Format**: ASCII
     /// * **Components**: n
+    #[doc(alias = "BodySerialNumber")]
     SerialNumber,
 
     /// Lens specification
@@ -465,10 +689,628 @@ pub enum Tag {
     /// * **Components**: n
     Title,
 
+    /// Whether the image is a composite of several captured images
+    /// * 0 = Unknown, 1 = Not a composite image, 2 = General composite image, 3 = Composite image captured while shooting
+    /// * **Format**: u16
+    /// * **Components**: 1
+    CompositeImage,
+
+    /// Number of source images captured and used for the composite image
+    /// * **Format**: u16
+    /// * **Components**: 2
+    SourceImageNumberOfCompositeImage,
+
+    /// Exposure times of the source images used for the composite image
+    /// * **Format**: Undefined
+    /// * **Components**: n
+    SourceExposureTimesOfCompositeImage,
+
+    /// Gamma coefficient of the transfer function
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 1
+    Gamma,
+
+    /// GPS tag version e.g. 2.3.0.0
+    /// * **Format**: u8
+    /// * **Components**: 4
+    GpsVersionId,
+
+    /// North or south latitude
+    /// * `N` = North, `S` = South
+    /// * **Format**: ASCII
+    /// * **Components**: 2
+    GpsLatitudeRef,
+
+    /// Latitude as degrees, minutes and seconds
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 3
+    GpsLatitude,
+
+    /// East or west longitude
+    /// * `E` = East, `W` = West
+    /// * **Format**: ASCII
+    /// * **Components**: 2
+    GpsLongitudeRef,
+
+    /// Longitude as degrees, minutes and seconds
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 3
+    GpsLongitude,
+
+    /// Altitude reference
+    /// * 0 = Above sea level, 1 = Below sea level
+    /// * **Format**: u8
+    /// * **Components**: 1
+    GpsAltitudeRef,
+
+    /// Altitude in metres relative to the altitude reference
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 1
+    GpsAltitude,
+
+    /// UTC time as hours, minutes and seconds
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 3
+    GpsTimeStamp,
+
+    /// Satellites used for the measurement
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    GpsSatellites,
+
+    /// Status of the receiver when the image was recorded
+    /// * `A` = Measurement in progress, `V` = Measurement interrupted
+    /// * **Format**: ASCII
+    /// * **Components**: 2
+    GpsStatus,
+
+    /// Measurement mode
+    /// * `2` = 2-dimensional, `3` = 3-dimensional
+    /// * **Format**: ASCII
+    /// * **Components**: 2
+    GpsMeasureMode,
+
+    /// Data degree of precision i.e. HDOP for 2D and PDOP for 3D measurements
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 1
+    GpsDop,
+
+    /// Unit of the receiver speed
+    /// * `K` = km/h, `M` = mph, `N` = knots
+    /// * **Format**: ASCII
+    /// * **Components**: 2
+    GpsSpeedRef,
+
+    /// Speed of the receiver
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 1
+    GpsSpeed,
+
+    /// Reference for the direction of receiver movement
+    /// * `T` = True north, `M` = Magnetic north
+    /// * **Format**: ASCII
+    /// * **Components**: 2
+    GpsTrackRef,
+
+    /// Direction of receiver movement from 0.00 to 359.99
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 1
+    GpsTrack,
+
+    /// Reference for the image direction
+    /// * `T` = True north, `M` = Magnetic north
+    /// * **Format**: ASCII
+    /// * **Components**: 2
+    GpsImgDirectionRef,
+
+    /// Direction of the image when captured from 0.00 to 359.99
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 1
+    GpsImgDirection,
+
+    /// Geodetic survey data used by the receiver e.g. WGS-84
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    GpsMapDatum,
+
+    /// North or south latitude of the destination point
+    /// * `N` = North, `S` = South
+    /// * **Format**: ASCII
+    /// * **Components**: 2
+    GpsDestLatitudeRef,
+
+    /// Latitude of the destination point as degrees, minutes and seconds
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 3
+    GpsDestLatitude,
+
+    /// East or west longitude of the destination point
+    /// * `E` = East, `W` = West
+    /// * **Format**: ASCII
+    /// * **Components**: 2
+    GpsDestLongitudeRef,
+
+    /// Longitude of the destination point as degrees, minutes and seconds
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 3
+    GpsDestLongitude,
+
+    /// Reference for the bearing to the destination point
+    /// * `T` = True north, `M` = Magnetic north
+    /// * **Format**: ASCII
+    /// * **Components**: 2
+    GpsDestBearingRef,
+
+    /// Bearing to the destination point from 0.00 to 359.99
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 1
+    GpsDestBearing,
+
+    /// Unit of the distance to the destination point
+    /// * `K` = Kilometers, `M` = Miles, `N` = Nautical miles
+    /// * **Format**: ASCII
+    /// * **Components**: 2
+    GpsDestDistanceRef,
+
+    /// Distance to the destination point
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 1
+    GpsDestDistance,
+
+    /// Name of the method used for location finding
+    /// * First 8 bytes are the character code as with UserComment
+    /// * **Format**: Undefined
+    /// * **Components**: n
+    GpsProcessingMethod,
+
+    /// Name of the GPS area
+    /// * First 8 bytes are the character code as with UserComment
+    /// * **Format**: Undefined
+    /// * **Components**: n
+    GpsAreaInformation,
+
+    /// UTC date as `YYYY:MM:DD`
+    /// * **Format**: ASCII
+    /// * **Components**: 11
+    GpsDateStamp,
+
+    /// Whether differential correction is applied to the receiver
+    /// * 0 = No correction, 1 = Differential corrected
+    /// * **Format**: u16
+    /// * **Components**: 1
+    GpsDifferential,
+
+    /// Horizontal positioning error in metres
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 1
+    GpsHPositioningError,
+
+    /// Interoperability rule the file conforms to
+    /// * `R98` = DCF basic file (sRGB), `R03` = DCF option file (Adobe RGB), `THM` = DCF thumbnail
+    /// * **Format**: ASCII
+    /// * **Components**: 4
+    InteropIndex,
+
+    /// Interoperability version
+    /// * Stored as 4bytes of ASCII character (e.g. "0100")
+    /// * **Format**: Undefined but turns out to be ASCII
+    /// * **Components**: 4
+    InteropVersion,
+
+    /// File format of the related image file e.g. `Exif JPEG Ver. 2.1`
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    RelatedImageFileFormat,
+
+    /// Width of the related image
+    /// * **Format**: u16 or u32
+    /// * **Components**: 1
+    RelatedImageWidth,
+
+    /// Height of the related image
+    /// * **Format**: u16 or u32
+    /// * **Components**: 1
+    RelatedImageHeight,
+
+    /// Canon image type e.g. `Canon EOS 5D Mark IV`
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    CanonImageType,
+
+    /// Canon firmware version e.g. `Firmware Version 1.0.4`
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    CanonFirmwareVersion,
+
+    /// Canon camera owner name
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    CanonOwnerName,
+
+    /// Canon camera body serial number
+    /// * **Format**: u32
+    /// * **Components**: 1
+    CanonSerialNumber,
+
+    /// Canon lens model e.g. `EF24-70mm f/2.8L II USM`
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    CanonLensModel,
+
+    /// Canon internal serial number
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    CanonInternalSerialNumber,
+
+    /// Nikon focus mode e.g. `AF-S`
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    NikonFocusMode,
+
+    /// Nikon camera body serial number
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    NikonSerialNumber,
+
+    /// Nikon lens min/max focal length in mm and min/max f-number
+    /// * **Format**: Unsigned rational
+    /// * **Components**: 4
+    NikonLens,
+
+    /// Nikon shutter actuation count
+    /// * **Format**: u32
+    /// * **Components**: 1
+    NikonShutterCount,
+
+    /// Sony camera model identifier
+    /// * **Format**: u16
+    /// * **Components**: 1
+    SonyModelId,
+
+    /// Sony lens type identifier
+    /// * **Format**: u32
+    /// * **Components**: 1
+    SonyLensType,
+
+    /// Sony focus mode identifier
+    /// * **Format**: u16 or u32
+    /// * **Components**: 1
+    SonyFocusMode,
+
+    /// Fujifilm internal serial number
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    FujifilmInternalSerialNumber,
+
+    /// Fujifilm image quality e.g. `FINE`
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    FujifilmQuality,
+
+    /// Fujifilm focus mode
+    /// * 0 = auto, 1 = manual
+    /// * **Format**: u16
+    /// * **Components**: 1
+    FujifilmFocusMode,
+
+    /// Fujifilm shutter actuation count
+    /// * **Format**: u16
+    /// * **Components**: 1
+    FujifilmImageCount,
+
+    /// Olympus camera type identifier e.g. `E-M1`
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    OlympusCameraType,
+
+    /// Olympus camera identifier
+    /// * **Format**: Undefined but turns out to be ASCII
+    /// * **Components**: n
+    OlympusCameraId,
+
+    /// Panasonic focus mode
+    /// * 1 = auto, 2 = manual
+    /// * **Format**: u16
+    /// * **Components**: 1
+    PanasonicFocusMode,
+
+    /// Panasonic internal serial number
+    /// * **Format**: Undefined but turns out to be ASCII
+    /// * **Components**: 16
+    PanasonicInternalSerialNumber,
+
+    /// Panasonic lens type e.g. `LUMIX G VARIO 12-35/F2.8`
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    PanasonicLensType,
+
+    /// Panasonic lens serial number
+    /// * **Format**: ASCII
+    /// * **Components**: n
+    PanasonicLensSerialNumber,
+
     /// Raw tag value for unknown tags
     Raw(u16),
 }
 
+impl Tag {
+    /// Map the given tag identifier from the GPS IFD namespace
+    /// * GPS tags reuse low identifiers so they are only valid inside the GPS IFD
+    pub(crate) fn from_gps(val: u16) -> Self {
+        match val {
+            0x0000 => Tag::GpsVersionId,
+            0x0001 => Tag::GpsLatitudeRef,
+            0x0002 => Tag::GpsLatitude,
+            0x0003 => Tag::GpsLongitudeRef,
+            0x0004 => Tag::GpsLongitude,
+            0x0005 => Tag::GpsAltitudeRef,
+            0x0006 => Tag::GpsAltitude,
+            0x0007 => Tag::GpsTimeStamp,
+            0x0008 => Tag::GpsSatellites,
+            0x0009 => Tag::GpsStatus,
+            0x000A => Tag::GpsMeasureMode,
+            0x000B => Tag::GpsDop,
+            0x000C => Tag::GpsSpeedRef,
+            0x000D => Tag::GpsSpeed,
+            0x000E => Tag::GpsTrackRef,
+            0x000F => Tag::GpsTrack,
+            0x0010 => Tag::GpsImgDirectionRef,
+            0x0011 => Tag::GpsImgDirection,
+            0x0012 => Tag::GpsMapDatum,
+            0x0013 => Tag::GpsDestLatitudeRef,
+            0x0014 => Tag::GpsDestLatitude,
+            0x0015 => Tag::GpsDestLongitudeRef,
+            0x0016 => Tag::GpsDestLongitude,
+            0x0017 => Tag::GpsDestBearingRef,
+            0x0018 => Tag::GpsDestBearing,
+            0x0019 => Tag::GpsDestDistanceRef,
+            0x001A => Tag::GpsDestDistance,
+            0x001B => Tag::GpsProcessingMethod,
+            0x001C => Tag::GpsAreaInformation,
+            0x001D => Tag::GpsDateStamp,
+            0x001E => Tag::GpsDifferential,
+            0x001F => Tag::GpsHPositioningError,
+            _ => Tag::Raw(val),
+        }
+    }
+
+    /// Map the given tag identifier from the interoperability IFD namespace
+    pub(crate) fn from_interop(val: u16) -> Self {
+        match val {
+            0x0001 => Tag::InteropIndex,
+            0x0002 => Tag::InteropVersion,
+            0x1000 => Tag::RelatedImageFileFormat,
+            0x1001 => Tag::RelatedImageWidth,
+            0x1002 => Tag::RelatedImageHeight,
+            _ => Tag::Raw(val),
+        }
+    }
+
+    /// Map the given tag identifier from the vendor's MakerNote IFD namespace
+    /// * Vendor tags reuse identifiers of the main IFDs and each other so are only valid inside
+    ///   the MakerNote of the given vendor
+    pub(crate) fn from_maker(vendor: Vendor, val: u16) -> Self {
+        match (vendor, val) {
+            (Vendor::Canon, 0x0006) => Tag::CanonImageType,
+            (Vendor::Canon, 0x0007) => Tag::CanonFirmwareVersion,
+            (Vendor::Canon, 0x0009) => Tag::CanonOwnerName,
+            (Vendor::Canon, 0x000C) => Tag::CanonSerialNumber,
+            (Vendor::Canon, 0x0095) => Tag::CanonLensModel,
+            (Vendor::Canon, 0x0096) => Tag::CanonInternalSerialNumber,
+            (Vendor::Nikon, 0x0007) => Tag::NikonFocusMode,
+            (Vendor::Nikon, 0x001D) => Tag::NikonSerialNumber,
+            (Vendor::Nikon, 0x0084) => Tag::NikonLens,
+            (Vendor::Nikon, 0x00A7) => Tag::NikonShutterCount,
+            (Vendor::Sony, 0xB001) => Tag::SonyModelId,
+            (Vendor::Sony, 0xB027) => Tag::SonyLensType,
+            (Vendor::Sony, 0xB042) => Tag::SonyFocusMode,
+            (Vendor::Fujifilm, 0x0010) => Tag::FujifilmInternalSerialNumber,
+            (Vendor::Fujifilm, 0x1000) => Tag::FujifilmQuality,
+            (Vendor::Fujifilm, 0x1021) => Tag::FujifilmFocusMode,
+            (Vendor::Fujifilm, 0x1438) => Tag::FujifilmImageCount,
+            (Vendor::Olympus, 0x0207) => Tag::OlympusCameraType,
+            (Vendor::Olympus, 0x0209) => Tag::OlympusCameraId,
+            (Vendor::Panasonic, 0x0007) => Tag::PanasonicFocusMode,
+            (Vendor::Panasonic, 0x0025) => Tag::PanasonicInternalSerialNumber,
+            (Vendor::Panasonic, 0x0051) => Tag::PanasonicLensType,
+            (Vendor::Panasonic, 0x0052) => Tag::PanasonicLensSerialNumber,
+            _ => Tag::Raw(val),
+        }
+    }
+
+    /// Expected data formats and component count range for the tag per the EXIF 2.32 spec
+    /// * Returns: (formats, components) where `None` components means any count
+    pub(crate) fn spec(&self) -> (&'static [u16], Option<(u32, u32)>) {
+        match self {
+            Tag::NewSubfileType
+            | Tag::ThumbnailOffset
+            | Tag::ThumbnailLength
+            | Tag::ExifSubIfdOffset
+            | Tag::GpsSubIfdOffset
+            | Tag::StandardOutputSensitivity
+            | Tag::RecommendedExposureIndex
+            | Tag::IsoSpeed
+            | Tag::IsoSpeedLatitudeyyy
+            | Tag::IsoSpeedLatitudezzz
+            | Tag::ExifInteroperabilityOffset => (&[UNSIGNED_LONG], Some((1, 1))),
+            Tag::ImageWidth
+            | Tag::ImageHeight
+            | Tag::RowsPerStrip
+            | Tag::TileWidth
+            | Tag::TileLength
+            | Tag::ExifImageWidth
+            | Tag::ExifImageHeight
+            | Tag::RelatedImageWidth
+            | Tag::RelatedImageHeight => (&[UNSIGNED_SHORT, UNSIGNED_LONG], Some((1, 1))),
+            Tag::BitsPerSample | Tag::IsoSpeedRatings => (&[UNSIGNED_SHORT], None),
+            Tag::Compression
+            | Tag::PhotometricInterpretation
+            | Tag::Orientation
+            | Tag::SamplesPerPixel
+            | Tag::PlanarConfiguration
+            | Tag::ResolutionUnit
+            | Tag::YCbCrPositioning
+            | Tag::ExposureProgram
+            | Tag::SensitivityType
+            | Tag::MeteringMode
+            | Tag::LightSource
+            | Tag::Flash
+            | Tag::ColorSpace
+            | Tag::FocalPlaneResolutionUnit
+            | Tag::SensingMethod
+            | Tag::CustomRendered
+            | Tag::ExposureMode
+            | Tag::WhiteBalance
+            | Tag::FocalLengthIn35mmFormat
+            | Tag::SceneCaptureType
+            | Tag::GainControl
+            | Tag::Contrast
+            | Tag::Saturation
+            | Tag::Sharpness
+            | Tag::SubjectDistanceRange
+            | Tag::CompositeImage
+            | Tag::GpsDifferential => (&[UNSIGNED_SHORT], Some((1, 1))),
+            Tag::ImageDescription
+            | Tag::Make
+            | Tag::Model
+            | Tag::Software
+            | Tag::Artist
+            | Tag::Copyright
+            | Tag::SpectralSensitivity
+            | Tag::SubSecTime
+            | Tag::SubSecTimeOriginal
+            | Tag::SubSecTimeDigitized
+            | Tag::OwnerName
+            | Tag::SerialNumber
+            | Tag::LensMake
+            | Tag::LensModel
+            | Tag::LensSerialNumber
+            | Tag::Title
+            | Tag::GpsSatellites
+            | Tag::GpsMapDatum
+            | Tag::RelatedImageFileFormat => (&[ASCII_STRING], None),
+            Tag::StripOffsets | Tag::StripByteCounts | Tag::TileByteCounts => {
+                (&[UNSIGNED_SHORT, UNSIGNED_LONG], None)
+            }
+            Tag::XResolution
+            | Tag::YResolution
+            | Tag::ExposureTime
+            | Tag::FNumber
+            | Tag::CompressedBitsPerPixel
+            | Tag::ApexApertureValue
+            | Tag::MaxApertureValue
+            | Tag::SubjectDistance
+            | Tag::FocalLength
+            | Tag::Humidity
+            | Tag::Pressure
+            | Tag::Acceleration
+            | Tag::FlashEnergy
+            | Tag::FocalPlaneXResolution
+            | Tag::FocalPlaneYResolution
+            | Tag::ExposureIndex
+            | Tag::DigitalZoomRatio
+            | Tag::Gamma
+            | Tag::GpsAltitude
+            | Tag::GpsDop
+            | Tag::GpsSpeed
+            | Tag::GpsTrack
+            | Tag::GpsImgDirection
+            | Tag::GpsDestBearing
+            | Tag::GpsDestDistance
+            | Tag::GpsHPositioningError => (&[UNSIGNED_RATIONAL], Some((1, 1))),
+            Tag::PageNumber
+            | Tag::YCbCrSubSampling
+            | Tag::SubjectLocation
+            | Tag::SourceImageNumberOfCompositeImage => (&[UNSIGNED_SHORT], Some((2, 2))),
+            Tag::TransferFunction => (&[UNSIGNED_SHORT], Some((768, 768))),
+            Tag::DateTime | Tag::DateTimeOriginal | Tag::DateTimeDigitized => {
+                (&[ASCII_STRING], Some((20, 20)))
+            }
+            Tag::WhitePoint => (&[UNSIGNED_RATIONAL], Some((2, 2))),
+            Tag::PrimaryChromaticities | Tag::ReferenceBlackWhite => {
+                (&[UNSIGNED_RATIONAL], Some((6, 6)))
+            }
+            Tag::TileOffsets => (&[UNSIGNED_LONG], None),
+            Tag::YCbCrCoefficients
+            | Tag::GpsLatitude
+            | Tag::GpsLongitude
+            | Tag::GpsTimeStamp
+            | Tag::GpsDestLatitude
+            | Tag::GpsDestLongitude => (&[UNSIGNED_RATIONAL], Some((3, 3))),
+            Tag::Oecf
+            | Tag::MakerNote
+            | Tag::UserComment
+            | Tag::SpatialFrequencyResponse
+            | Tag::CfaPattern
+            | Tag::DeviceSettingDescription
+            | Tag::SourceExposureTimesOfCompositeImage
+            | Tag::GpsProcessingMethod
+            | Tag::GpsAreaInformation => (&[UNDEFINED], None),
+            Tag::ExifVersion
+            | Tag::ComponentConfiguration
+            | Tag::FlashPixVersion
+            | Tag::InteropVersion => (&[UNDEFINED], Some((4, 4))),
+            Tag::OffsetTime | Tag::OffsetTimeOriginal | Tag::OffsetTimeDigitized => {
+                (&[ASCII_STRING], Some((7, 7)))
+            }
+            Tag::ShutterSpeedValue
+            | Tag::BrightnessValue
+            | Tag::ExposureBiasValue
+            | Tag::Temperature
+            | Tag::WaterDepth
+            | Tag::CameraElevationAngle => (&[SIGNED_RATIONAL], Some((1, 1))),
+            Tag::SubjectArea => (&[UNSIGNED_SHORT], Some((2, 4))),
+            Tag::XPTitle | Tag::XPComment | Tag::XPAuthor | Tag::XPKeywords | Tag::XPSubject => {
+                (&[UNSIGNED_BYTE], None)
+            }
+            Tag::RelatedSoundFile => (&[ASCII_STRING], Some((13, 13))),
+            Tag::FileSource | Tag::SceneType => (&[UNDEFINED], Some((1, 1))),
+            Tag::ImageUniqueID => (&[ASCII_STRING], Some((33, 33))),
+            Tag::LensSpecification => (&[UNSIGNED_RATIONAL], Some((4, 4))),
+            Tag::GpsVersionId => (&[UNSIGNED_BYTE], Some((4, 4))),
+            Tag::GpsLatitudeRef
+            | Tag::GpsLongitudeRef
+            | Tag::GpsStatus
+            | Tag::GpsMeasureMode
+            | Tag::GpsSpeedRef
+            | Tag::GpsTrackRef
+            | Tag::GpsImgDirectionRef
+            | Tag::GpsDestLatitudeRef
+            | Tag::GpsDestLongitudeRef
+            | Tag::GpsDestBearingRef
+            | Tag::GpsDestDistanceRef => (&[ASCII_STRING], Some((2, 2))),
+            Tag::GpsAltitudeRef => (&[UNSIGNED_BYTE], Some((1, 1))),
+            Tag::GpsDateStamp => (&[ASCII_STRING], Some((11, 11))),
+            Tag::InteropIndex => (&[ASCII_STRING], Some((4, 4))),
+            // Vendor tags are not part of the EXIF spec
+            Tag::CanonImageType
+            | Tag::CanonFirmwareVersion
+            | Tag::CanonOwnerName
+            | Tag::CanonSerialNumber
+            | Tag::CanonLensModel
+            | Tag::CanonInternalSerialNumber
+            | Tag::NikonFocusMode
+            | Tag::NikonSerialNumber
+            | Tag::NikonLens
+            | Tag::NikonShutterCount
+            | Tag::SonyModelId
+            | Tag::SonyLensType
+            | Tag::SonyFocusMode
+            | Tag::FujifilmInternalSerialNumber
+            | Tag::FujifilmQuality
+            | Tag::FujifilmFocusMode
+            | Tag::FujifilmImageCount
+            | Tag::OlympusCameraType
+            | Tag::OlympusCameraId
+            | Tag::PanasonicFocusMode
+            | Tag::PanasonicInternalSerialNumber
+            | Tag::PanasonicLensType
+            | Tag::PanasonicLensSerialNumber
+            | Tag::Raw(_) => (&[], None),
+        }
+    }
+}
+
 impl From<i32> for Tag {
     fn from(val: i32) -> Self {
         Tag::from(val as u16)
@@ -478,6 +1320,7 @@ impl From<i32> for Tag {
 impl From<u16> for Tag {
     fn from(val: u16) -> Self {
         match val {
+            0x00FE => Tag::NewSubfileType,
             0x0100 => Tag::ImageWidth,
             0x0101 => Tag::ImageHeight,
             0x0102 => Tag::BitsPerSample,
@@ -489,16 +1332,27 @@ impl From<u16> for Tag {
             0x0111 => Tag::StripOffsets,
             0x0112 => Tag::Orientation,
             0x0115 => Tag::SamplesPerPixel,
+            0x0116 => Tag::RowsPerStrip,
+            0x0117 => Tag::StripByteCounts,
             0x011A => Tag::XResolution,
             0x011B => Tag::YResolution,
+            0x011C => Tag::PlanarConfiguration,
             0x0128 => Tag::ResolutionUnit,
+            0x0129 => Tag::PageNumber,
+            0x012D => Tag::TransferFunction,
             0x0131 => Tag::Software,
             0x0132 => Tag::DateTime,
+            0x013B => Tag::Artist,
             0x013E => Tag::WhitePoint,
             0x013F => Tag::PrimaryChromaticities,
+            0x0142 => Tag::TileWidth,
+            0x0143 => Tag::TileLength,
+            0x0144 => Tag::TileOffsets,
+            0x0145 => Tag::TileByteCounts,
             0x0201 => Tag::ThumbnailOffset,
             0x0202 => Tag::ThumbnailLength,
             0x0211 => Tag::YCbCrCoefficients,
+            0x0212 => Tag::YCbCrSubSampling,
             0x0213 => Tag::YCbCrPositioning,
             0x0214 => Tag::ReferenceBlackWhite,
             0x8298 => Tag::Copyright,
@@ -506,11 +1360,22 @@ impl From<u16> for Tag {
             0x829D => Tag::FNumber,
             0x8769 => Tag::ExifSubIfdOffset,
             0x8822 => Tag::ExposureProgram,
+            0x8824 => Tag::SpectralSensitivity,
             0x8825 => Tag::GpsSubIfdOffset,
             0x8827 => Tag::IsoSpeedRatings,
+            0x8828 => Tag::Oecf,
+            0x8830 => Tag::SensitivityType,
+            0x8831 => Tag::StandardOutputSensitivity,
+            0x8832 => Tag::RecommendedExposureIndex,
+            0x8833 => Tag::IsoSpeed,
+            0x8834 => Tag::IsoSpeedLatitudeyyy,
+            0x8835 => Tag::IsoSpeedLatitudezzz,
             0x9000 => Tag::ExifVersion,
             0x9003 => Tag::DateTimeOriginal,
             0x9004 => Tag::DateTimeDigitized,
+            0x9010 => Tag::OffsetTime,
+            0x9011 => Tag::OffsetTimeOriginal,
+            0x9012 => Tag::OffsetTimeDigitized,
             0x9101 => Tag::ComponentConfiguration,
             0x9102 => Tag::CompressedBitsPerPixel,
             0x9201 => Tag::ShutterSpeedValue,
@@ -523,24 +1388,41 @@ impl From<u16> for Tag {
             0x9208 => Tag::LightSource,
             0x9209 => Tag::Flash,
             0x920A => Tag::FocalLength,
+            0x9214 => Tag::SubjectArea,
             0x927C => Tag::MakerNote,
             0x9286 => Tag::UserComment,
-            0x9288 => Tag::XPComment,
-            0x9291 => Tag::XPAuthor,
-            0x9292 => Tag::XPKeywords,
-            0x9293 => Tag::XPSubject,
+            0x9290 => Tag::SubSecTime,
+            0x9291 => Tag::SubSecTimeOriginal,
+            0x9292 => Tag::SubSecTimeDigitized,
+            0x9400 => Tag::Temperature,
+            0x9401 => Tag::Humidity,
+            0x9402 => Tag::Pressure,
+            0x9403 => Tag::WaterDepth,
+            0x9404 => Tag::Acceleration,
+            0x9405 => Tag::CameraElevationAngle,
+            0x9C9B => Tag::XPTitle,
+            0x9C9C => Tag::XPComment,
+            0x9C9D => Tag::XPAuthor,
+            0x9C9E => Tag::XPKeywords,
+            0x9C9F => Tag::XPSubject,
             0xA000 => Tag::FlashPixVersion,
             0xA001 => Tag::ColorSpace,
             0xA002 => Tag::ExifImageWidth,
             0xA003 => Tag::ExifImageHeight,
             0xA004 => Tag::RelatedSoundFile,
             0xA005 => Tag::ExifInteroperabilityOffset,
+            0xA20B => Tag::FlashEnergy,
+            0xA20C => Tag::SpatialFrequencyResponse,
             0xA20E => Tag::FocalPlaneXResolution,
             0xA20F => Tag::FocalPlaneYResolution,
             0xA210 => Tag::FocalPlaneResolutionUnit,
+            0xA214 => Tag::SubjectLocation,
+            0xA215 => Tag::ExposureIndex,
             0xA217 => Tag::SensingMethod,
             0xA300 => Tag::FileSource,
             0xA301 => Tag::SceneType,
+            0xA302 => Tag::CfaPattern,
+            0xA401 => Tag::CustomRendered,
             0xA402 => Tag::ExposureMode,
             0xA403 => Tag::WhiteBalance,
             0xA404 => Tag::DigitalZoomRatio,
@@ -560,6 +1442,10 @@ impl From<u16> for Tag {
             0xA434 => Tag::LensModel,
             0xA435 => Tag::LensSerialNumber,
             0xA436 => Tag::Title,
+            0xA460 => Tag::CompositeImage,
+            0xA461 => Tag::SourceImageNumberOfCompositeImage,
+            0xA462 => Tag::SourceExposureTimesOfCompositeImage,
+            0xA500 => Tag::Gamma,
             _ => Tag::Raw(val),
         }
     }
@@ -568,6 +1454,7 @@ impl From<u16> for Tag {
 impl Display for Tag {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Tag::NewSubfileType => write!(f, "New Subfile Type"),
             Tag::ImageWidth => write!(f, "Image Width"),
             Tag::ImageHeight => write!(f, "Image Height"),
             Tag::BitsPerSample => write!(f, "Bits Per Sample"),
@@ -579,16 +1466,27 @@ impl Display for Tag {
             Tag::StripOffsets => write!(f, "Strip Offsets"),
             Tag::Orientation => write!(f, "Orientation"),
             Tag::SamplesPerPixel => write!(f, "Samples Per Pixel"),
+            Tag::RowsPerStrip => write!(f, "Rows Per Strip"),
+            Tag::StripByteCounts => write!(f, "Strip Byte Counts"),
             Tag::XResolution => write!(f, "X Resolution"),
             Tag::YResolution => write!(f, "Y Resolution"),
+            Tag::PlanarConfiguration => write!(f, "Planar Configuration"),
             Tag::ResolutionUnit => write!(f, "Resolution Unit"),
+            Tag::PageNumber => write!(f, "Page Number"),
+            Tag::TransferFunction => write!(f, "Transfer Function"),
             Tag::Software => write!(f, "Software"),
             Tag::DateTime => write!(f, "Date Time"),
+            Tag::Artist => write!(f, "Artist"),
             Tag::WhitePoint => write!(f, "White Point"),
             Tag::PrimaryChromaticities => write!(f, "Primary Chromaticities"),
+            Tag::TileWidth => write!(f, "Tile Width"),
+            Tag::TileLength => write!(f, "Tile Length"),
+            Tag::TileOffsets => write!(f, "Tile Offsets"),
+            Tag::TileByteCounts => write!(f, "Tile Byte Counts"),
             Tag::ThumbnailOffset => write!(f, "Thumbnail Offset"),
             Tag::ThumbnailLength => write!(f, "Thumbnail Length"),
             Tag::YCbCrCoefficients => write!(f, "Y Cb Cr Coefficients"),
+            Tag::YCbCrSubSampling => write!(f, "YCbCr Sub Sampling"),
             Tag::YCbCrPositioning => write!(f, "Y Cb Cr Positioning"),
             Tag::ReferenceBlackWhite => write!(f, "Reference Black White"),
             Tag::Copyright => write!(f, "Copyright"),
@@ -596,11 +1494,22 @@ impl Display for Tag {
             Tag::FNumber => write!(f, "F Number"),
             Tag::ExifSubIfdOffset => write!(f, "Exif Offset"),
             Tag::ExposureProgram => write!(f, "Exposure Program"),
+            Tag::SpectralSensitivity => write!(f, "Spectral Sensitivity"),
             Tag::GpsSubIfdOffset => write!(f, "GPS Offset"),
             Tag::IsoSpeedRatings => write!(f, "ISO Speed Ratings"),
+            Tag::Oecf => write!(f, "Opto-Electric Conversion Factor"),
+            Tag::SensitivityType => write!(f, "Sensitivity Type"),
+            Tag::StandardOutputSensitivity => write!(f, "Standard Output Sensitivity"),
+            Tag::RecommendedExposureIndex => write!(f, "Recommended Exposure Index"),
+            Tag::IsoSpeed => write!(f, "ISO Speed"),
+            Tag::IsoSpeedLatitudeyyy => write!(f, "ISO Speed Latitude yyy"),
+            Tag::IsoSpeedLatitudezzz => write!(f, "ISO Speed Latitude zzz"),
             Tag::ExifVersion => write!(f, "Exif Version"),
             Tag::DateTimeOriginal => write!(f, "Date Time Original"),
             Tag::DateTimeDigitized => write!(f, "Date Time Digitized"),
+            Tag::OffsetTime => write!(f, "Offset Time"),
+            Tag::OffsetTimeOriginal => write!(f, "Offset Time Original"),
+            Tag::OffsetTimeDigitized => write!(f, "Offset Time Digitized"),
             Tag::ComponentConfiguration => write!(f, "Component Configuration"),
             Tag::CompressedBitsPerPixel => write!(f, "Compressed Bits Per Pixel"),
             Tag::ShutterSpeedValue => write!(f, "Shutter Speed Value"),
@@ -613,8 +1522,19 @@ impl Display for Tag {
             Tag::LightSource => write!(f, "Light Source"),
             Tag::Flash => write!(f, "Flash"),
             Tag::FocalLength => write!(f, "Focal Length"),
+            Tag::SubjectArea => write!(f, "Subject Area"),
             Tag::MakerNote => write!(f, "Maker Note"),
             Tag::UserComment => write!(f, "User Comment"),
+            Tag::SubSecTime => write!(f, "Sub Sec Time"),
+            Tag::SubSecTimeOriginal => write!(f, "Sub Sec Time Original"),
+            Tag::SubSecTimeDigitized => write!(f, "Sub Sec Time Digitized"),
+            Tag::Temperature => write!(f, "Temperature"),
+            Tag::Humidity => write!(f, "Humidity"),
+            Tag::Pressure => write!(f, "Pressure"),
+            Tag::WaterDepth => write!(f, "Water Depth"),
+            Tag::Acceleration => write!(f, "Acceleration"),
+            Tag::CameraElevationAngle => write!(f, "Camera Elevation Angle"),
+            Tag::XPTitle => write!(f, "XP Title"),
             Tag::XPComment => write!(f, "XP Comment"),
             Tag::XPAuthor => write!(f, "XP Author"),
             Tag::XPKeywords => write!(f, "XP Keywords"),
@@ -625,12 +1545,18 @@ impl Display for Tag {
             Tag::ExifImageHeight => write!(f, "Exif Image Height"),
             Tag::RelatedSoundFile => write!(f, "Related Sound File"),
             Tag::ExifInteroperabilityOffset => write!(f, "Exif Interoperability Offset"),
+            Tag::FlashEnergy => write!(f, "Flash Energy"),
+            Tag::SpatialFrequencyResponse => write!(f, "Spatial Frequency Response"),
             Tag::FocalPlaneXResolution => write!(f, "Focal Plane X Resolution"),
             Tag::FocalPlaneYResolution => write!(f, "Focal Plane Y Resolution"),
             Tag::FocalPlaneResolutionUnit => write!(f, "Focal Plane Resolution Unit"),
+            Tag::SubjectLocation => write!(f, "Subject Location"),
+            Tag::ExposureIndex => write!(f, "Exposure Index"),
             Tag::SensingMethod => write!(f, "Sensing Method"),
             Tag::FileSource => write!(f, "File Source"),
             Tag::SceneType => write!(f, "Scene Type"),
+            Tag::CfaPattern => write!(f, "CFA Pattern"),
+            Tag::CustomRendered => write!(f, "Custom Rendered"),
             Tag::ExposureMode => write!(f, "Exposure Mode"),
             Tag::WhiteBalance => write!(f, "White Balance"),
             Tag::DigitalZoomRatio => write!(f, "Digital Zoom Ratio"),
@@ -650,7 +1576,75 @@ impl Display for Tag {
             Tag::LensModel => write!(f, "Lens Model"),
             Tag::LensSerialNumber => write!(f, "Lens Serial Number"),
             Tag::Title => write!(f, "Title"),
+            Tag::CompositeImage => write!(f, "Composite Image"),
+            Tag::SourceImageNumberOfCompositeImage => {
+                write!(f, "Source Image Number Of Composite Image")
+            }
+            Tag::SourceExposureTimesOfCompositeImage => {
+                write!(f, "Source Exposure Times Of Composite Image")
+            }
+            Tag::Gamma => write!(f, "Gamma"),
+            Tag::GpsVersionId => write!(f, "GPS Version ID"),
+            Tag::GpsLatitudeRef => write!(f, "GPS Latitude Ref"),
+            Tag::GpsLatitude => write!(f, "GPS Latitude"),
+            Tag::GpsLongitudeRef => write!(f, "GPS Longitude Ref"),
+            Tag::GpsLongitude => write!(f, "GPS Longitude"),
+            Tag::GpsAltitudeRef => write!(f, "GPS Altitude Ref"),
+            Tag::GpsAltitude => write!(f, "GPS Altitude"),
+            Tag::GpsTimeStamp => write!(f, "GPS Time Stamp"),
+            Tag::GpsSatellites => write!(f, "GPS Satellites"),
+            Tag::GpsStatus => write!(f, "GPS Status"),
+            Tag::GpsMeasureMode => write!(f, "GPS Measure Mode"),
+            Tag::GpsDop => write!(f, "GPS Dilution Of Precision"),
+            Tag::GpsSpeedRef => write!(f, "GPS Speed Ref"),
+            Tag::GpsSpeed => write!(f, "GPS Speed"),
+            Tag::GpsTrackRef => write!(f, "GPS Track Ref"),
+            Tag::GpsTrack => write!(f, "GPS Track"),
+            Tag::GpsImgDirectionRef => write!(f, "GPS Img Direction Ref"),
+            Tag::GpsImgDirection => write!(f, "GPS Img Direction"),
+            Tag::GpsMapDatum => write!(f, "GPS Map Datum"),
+            Tag::GpsDestLatitudeRef => write!(f, "GPS Dest Latitude Ref"),
+            Tag::GpsDestLatitude => write!(f, "GPS Dest Latitude"),
+            Tag::GpsDestLongitudeRef => write!(f, "GPS Dest Longitude Ref"),
+            Tag::GpsDestLongitude => write!(f, "GPS Dest Longitude"),
+            Tag::GpsDestBearingRef => write!(f, "GPS Dest Bearing Ref"),
+            Tag::GpsDestBearing => write!(f, "GPS Dest Bearing"),
+            Tag::GpsDestDistanceRef => write!(f, "GPS Dest Distance Ref"),
+            Tag::GpsDestDistance => write!(f, "GPS Dest Distance"),
+            Tag::GpsProcessingMethod => write!(f, "GPS Processing Method"),
+            Tag::GpsAreaInformation => write!(f, "GPS Area Information"),
+            Tag::GpsDateStamp => write!(f, "GPS Date Stamp"),
+            Tag::GpsDifferential => write!(f, "GPS Differential"),
+            Tag::GpsHPositioningError => write!(f, "GPS Horizontal Positioning Error"),
+            Tag::InteropIndex => write!(f, "Interop Index"),
+            Tag::InteropVersion => write!(f, "Interop Version"),
+            Tag::RelatedImageFileFormat => write!(f, "Related Image File Format"),
+            Tag::RelatedImageWidth => write!(f, "Related Image Width"),
+            Tag::RelatedImageHeight => write!(f, "Related Image Height"),
+            Tag::CanonImageType => write!(f, "Image Type"),
+            Tag::CanonFirmwareVersion => write!(f, "Firmware Version"),
+            Tag::CanonOwnerName => write!(f, "Owner Name"),
+            Tag::CanonSerialNumber => write!(f, "Serial Number"),
+            Tag::CanonLensModel => write!(f, "Lens Model"),
+            Tag::CanonInternalSerialNumber => write!(f, "Internal Serial Number"),
+            Tag::NikonFocusMode => write!(f, "Focus Mode"),
+            Tag::NikonSerialNumber => write!(f, "Serial Number"),
+            Tag::NikonLens => write!(f, "Lens"),
+            Tag::NikonShutterCount => write!(f, "Shutter Count"),
+            Tag::SonyModelId => write!(f, "Sony Model ID"),
+            Tag::SonyLensType => write!(f, "Lens Type"),
+            Tag::SonyFocusMode => write!(f, "Focus Mode"),
+            Tag::FujifilmInternalSerialNumber => write!(f, "Internal Serial Number"),
+            Tag::FujifilmQuality => write!(f, "Quality"),
+            Tag::FujifilmFocusMode => write!(f, "Focus Mode"),
+            Tag::FujifilmImageCount => write!(f, "Image Count"),
+            Tag::OlympusCameraType => write!(f, "Camera Type"),
+            Tag::OlympusCameraId => write!(f, "Camera ID"),
+            Tag::PanasonicFocusMode => write!(f, "Focus Mode"),
+            Tag::PanasonicInternalSerialNumber => write!(f, "Internal Serial Number"),
+            Tag::PanasonicLensType => write!(f, "Lens Type"),
+            Tag::PanasonicLensSerialNumber => write!(f, "Lens Serial Number"),
             Tag::Raw(val) => write!(f, "Unknown({:02x?})", val),
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,61 @@
+use std::fmt::Display;
+
+/// Camera vendor whose MakerNote layout is understood
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Vendor {
+    Canon,
+    Nikon,
+    Sony,
+    Fujifilm,
+    Olympus,
+    Panasonic,
+}
+
+impl Vendor {
+    /// Detect the vendor from the Exif Make value e.g. `NIKON CORPORATION`
+    /// * OM Digital Solutions continued the Olympus MakerNote so it is treated as Olympus
+    pub(crate) fn from_make(make: &str) -> Option<Self> {
+        let make = make
+            .trim_matches(|x: char| x.is_whitespace() || x == '\0')
+            .to_lowercase();
+        match make {
+            x if x.starts_with("canon") => Some(Vendor::Canon),
+            x if x.starts_with("nikon") => Some(Vendor::Nikon),
+            x if x.starts_with("sony") => Some(Vendor::Sony),
+            x if x.starts_with("fujifilm") => Some(Vendor::Fujifilm),
+            x if x.starts_with("olympus") || x.starts_with("om digital") => Some(Vendor::Olympus),
+            x if x.starts_with("panasonic") => Some(Vendor::Panasonic),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Vendor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Vendor::Canon => write!(f, "Canon"),
+            Vendor::Nikon => write!(f, "Nikon"),
+            Vendor::Sony => write!(f, "Sony"),
+            Vendor::Fujifilm => write!(f, "Fujifilm"),
+            Vendor::Olympus => write!(f, "Olympus"),
+            Vendor::Panasonic => write!(f, "Panasonic"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_make() {
+        assert_eq!(Vendor::from_make("Canon"), Some(Vendor::Canon));
+        assert_eq!(Vendor::from_make("NIKON CORPORATION\0"), Some(Vendor::Nikon));
+        assert_eq!(Vendor::from_make("SONY"), Some(Vendor::Sony));
+        assert_eq!(Vendor::from_make("FUJIFILM"), Some(Vendor::Fujifilm));
+        assert_eq!(Vendor::from_make("OLYMPUS IMAGING CORP.  "), Some(Vendor::Olympus));
+        assert_eq!(Vendor::from_make("OM Digital Solutions"), Some(Vendor::Olympus));
+        assert_eq!(Vendor::from_make("Panasonic"), Some(Vendor::Panasonic));
+        assert_eq!(Vendor::from_make("Apple"), None);
+    }
+}
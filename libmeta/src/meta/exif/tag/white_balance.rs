@@ -0,0 +1,36 @@
+use std::fmt::Display;
+
+/// WhiteBalance values
+/// https://exiftool.org/TagNames/EXIF.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WhiteBalance {
+    Auto,         // 0
+    Manual,       // 1
+    Unknown(u16), // any other value
+}
+
+impl From<usize> for WhiteBalance {
+    fn from(val: usize) -> Self {
+        WhiteBalance::from(val as u16)
+    }
+}
+
+impl From<u16> for WhiteBalance {
+    fn from(val: u16) -> Self {
+        match val {
+            0 => WhiteBalance::Auto,
+            1 => WhiteBalance::Manual,
+            x => WhiteBalance::Unknown(x),
+        }
+    }
+}
+
+impl Display for WhiteBalance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WhiteBalance::Auto => write!(f, "Auto"),
+            WhiteBalance::Manual => write!(f, "Manual"),
+            WhiteBalance::Unknown(x) => write!(f, "Unknown ({})", x),
+        }
+    }
+}
@@ -2,7 +2,8 @@ use std::fmt::Display;
 
 /// YCbCrPositioning values
 /// https://exiftool.org/TagNames/EXIF.html
-pub(crate) enum YCbCrPositioning {
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum YCbCrPositioning {
     Centered, // 1
     CoSited,  // 2
 }
@@ -175,3 +175,451 @@ pub(crate) const EXIF_TEST_DATA: [u8; 854] = [
     /* 846-851 */ 0xf7, 0xe6, 0x8a, 0x28, 0xa0, 0x0f, //
     /* 852-853 */ 0xff, 0xd9, // JPEG EOI
 ];
+
+// No Exif Header included, GPS IFD referenced from IFD 0
+#[cfg(test)]
+pub(crate) const GPS_TEST_DATA: [u8; 250] = [
+    // TIFF header
+    /* 000-001 */ 0x4d, 0x4d, // byte alignment
+    /* 002-003 */ 0x00, 0x2a, // version identifier
+    /* 004-007 */ 0x00, 0x00, 0x00, 0x08, // IFD 0: offset
+    //
+    // IFD 0
+    /* 008-009 */ 0x00, 0x01, // IFD 0: field count
+    //
+    /* 010-011 */ 0x88, 0x25, // Field 0: GPS Offset
+    /* 012-013 */ 0x00, 0x04, // Field 0: Unsigned Long
+    /* 014-017 */ 0x00, 0x00, 0x00, 0x01, // Field 0: components (1)
+    /* 018-021 */ 0x00, 0x00, 0x00, 0x1a, // Field 0: data (26)
+    //
+    /* 022-025 */ 0x00, 0x00, 0x00, 0x00, // End of IFD 0
+    //
+    // GPS IFD
+    /* 026-027 */ 0x00, 0x0a, // GPS IFD: field count
+    //
+    /* 028-029 */ 0x00, 0x00, // Field 0: GPS Version ID
+    /* 030-031 */ 0x00, 0x01, // Field 0: Unsigned Byte
+    /* 032-035 */ 0x00, 0x00, 0x00, 0x04, // Field 0: components (4)
+    /* 036-039 */ 0x02, 0x03, 0x00, 0x00, // Field 0: data (2.3.0.0)
+    //
+    /* 040-041 */ 0x00, 0x01, // Field 1: GPS Latitude Ref
+    /* 042-043 */ 0x00, 0x02, // Field 1: ASCII
+    /* 044-047 */ 0x00, 0x00, 0x00, 0x02, // Field 1: components (2)
+    /* 048-051 */ 0x4e, 0x00, 0x00, 0x00, // Field 1: data (N)
+    //
+    /* 052-053 */ 0x00, 0x02, // Field 2: GPS Latitude
+    /* 054-055 */ 0x00, 0x05, // Field 2: Unsigned Rational
+    /* 056-059 */ 0x00, 0x00, 0x00, 0x03, // Field 2: components (3)
+    /* 060-063 */ 0x00, 0x00, 0x00, 0x98, // Field 2: offset (152), length (24)
+    //
+    /* 064-065 */ 0x00, 0x03, // Field 3: GPS Longitude Ref
+    /* 066-067 */ 0x00, 0x02, // Field 3: ASCII
+    /* 068-071 */ 0x00, 0x00, 0x00, 0x02, // Field 3: components (2)
+    /* 072-075 */ 0x57, 0x00, 0x00, 0x00, // Field 3: data (W)
+    //
+    /* 076-077 */ 0x00, 0x04, // Field 4: GPS Longitude
+    /* 078-079 */ 0x00, 0x05, // Field 4: Unsigned Rational
+    /* 080-083 */ 0x00, 0x00, 0x00, 0x03, // Field 4: components (3)
+    /* 084-087 */ 0x00, 0x00, 0x00, 0xb0, // Field 4: offset (176), length (24)
+    //
+    /* 088-089 */ 0x00, 0x05, // Field 5: GPS Altitude Ref
+    /* 090-091 */ 0x00, 0x01, // Field 5: Unsigned Byte
+    /* 092-095 */ 0x00, 0x00, 0x00, 0x01, // Field 5: components (1)
+    /* 096-099 */ 0x01, 0x00, 0x00, 0x00, // Field 5: data (below sea level)
+    //
+    /* 100-101 */ 0x00, 0x06, // Field 6: GPS Altitude
+    /* 102-103 */ 0x00, 0x05, // Field 6: Unsigned Rational
+    /* 104-107 */ 0x00, 0x00, 0x00, 0x01, // Field 6: components (1)
+    /* 108-111 */ 0x00, 0x00, 0x00, 0xc8, // Field 6: offset (200), length (8)
+    //
+    /* 112-113 */ 0x00, 0x07, // Field 7: GPS Time Stamp
+    /* 114-115 */ 0x00, 0x05, // Field 7: Unsigned Rational
+    /* 116-119 */ 0x00, 0x00, 0x00, 0x03, // Field 7: components (3)
+    /* 120-123 */ 0x00, 0x00, 0x00, 0xd0, // Field 7: offset (208), length (24)
+    //
+    /* 124-125 */ 0x00, 0x12, // Field 8: GPS Map Datum
+    /* 126-127 */ 0x00, 0x02, // Field 8: ASCII
+    /* 128-131 */ 0x00, 0x00, 0x00, 0x07, // Field 8: components (7)
+    /* 132-135 */ 0x00, 0x00, 0x00, 0xe8, // Field 8: offset (232), length (7)
+    //
+    /* 136-137 */ 0x00, 0x1d, // Field 9: GPS Date Stamp
+    /* 138-139 */ 0x00, 0x02, // Field 9: ASCII
+    /* 140-143 */ 0x00, 0x00, 0x00, 0x0b, // Field 9: components (11)
+    /* 144-147 */ 0x00, 0x00, 0x00, 0xef, // Field 9: offset (239), length (11)
+    //
+    /* 148-151 */ 0x00, 0x00, 0x00, 0x00, // End of GPS IFD
+    //
+    // GPS data
+    /* 152-159 */ 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x01, // Latitude degrees (37/1)
+    /* 160-167 */ 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x01, // Latitude minutes (46/1)
+    /* 168-175 */ 0x00, 0x00, 0x0b, 0xb8, 0x00, 0x00, 0x00, 0x64, // Latitude seconds (3000/100)
+    /* 176-183 */ 0x00, 0x00, 0x00, 0x7a, 0x00, 0x00, 0x00, 0x01, // Longitude degrees (122/1)
+    /* 184-191 */ 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x01, // Longitude minutes (25/1)
+    /* 192-199 */ 0x00, 0x00, 0x03, 0x84, 0x00, 0x00, 0x00, 0x64, // Longitude seconds (900/100)
+    /* 200-207 */ 0x00, 0x00, 0x00, 0x69, 0x00, 0x00, 0x00, 0x02, // Altitude metres (105/2)
+    /* 208-215 */ 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x01, // Time Stamp hours (13/1)
+    /* 216-223 */ 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x01, // Time Stamp minutes (5/1)
+    /* 224-231 */ 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x01, // Time Stamp seconds (30/1)
+    /* 232-238 */ 0x57, 0x47, 0x53, 0x2d, 0x38, 0x34, 0x00, // Map Datum (WGS-84)
+    /* 239-249 */ 0x32, 0x30, 0x32, 0x34, 0x3a, 0x30, 0x35, 0x3a, 0x30, 0x34, 0x00, // Date Stamp
+];
+
+// No Exif Header included, Interop IFD referenced from the Exif IFD
+#[cfg(test)]
+pub(crate) const INTEROP_TEST_DATA: [u8; 74] = [
+    // TIFF header
+    /* 000-001 */ 0x4d, 0x4d, // byte alignment
+    /* 002-003 */ 0x00, 0x2a, // version identifier
+    /* 004-007 */ 0x00, 0x00, 0x00, 0x08, // IFD 0: offset
+    //
+    // IFD 0
+    /* 008-009 */ 0x00, 0x01, // IFD 0: field count
+    //
+    /* 010-011 */ 0x87, 0x69, // Field 0: Exif Offset
+    /* 012-013 */ 0x00, 0x04, // Field 0: Unsigned Long
+    /* 014-017 */ 0x00, 0x00, 0x00, 0x01, // Field 0: components (1)
+    /* 018-021 */ 0x00, 0x00, 0x00, 0x1a, // Field 0: data (26)
+    //
+    /* 022-025 */ 0x00, 0x00, 0x00, 0x00, // End of IFD 0
+    //
+    // Exif IFD
+    /* 026-027 */ 0x00, 0x01, // Exif IFD: field count
+    //
+    /* 028-029 */ 0xa0, 0x05, // Field 0: Exif Interoperability Offset
+    /* 030-031 */ 0x00, 0x04, // Field 0: Unsigned Long
+    /* 032-035 */ 0x00, 0x00, 0x00, 0x01, // Field 0: components (1)
+    /* 036-039 */ 0x00, 0x00, 0x00, 0x2c, // Field 0: data (44)
+    //
+    /* 040-043 */ 0x00, 0x00, 0x00, 0x00, // End of Exif IFD
+    //
+    // Interop IFD
+    /* 044-045 */ 0x00, 0x02, // Interop IFD: field count
+    //
+    /* 046-047 */ 0x00, 0x01, // Field 0: Interop Index
+    /* 048-049 */ 0x00, 0x02, // Field 0: ASCII
+    /* 050-053 */ 0x00, 0x00, 0x00, 0x04, // Field 0: components (4)
+    /* 054-057 */ 0x52, 0x39, 0x38, 0x00, // Field 0: data (R98)
+    //
+    /* 058-059 */ 0x00, 0x02, // Field 1: Interop Version
+    /* 060-061 */ 0x00, 0x07, // Field 1: Undefined
+    /* 062-065 */ 0x00, 0x00, 0x00, 0x04, // Field 1: components (4)
+    /* 066-069 */ 0x30, 0x31, 0x30, 0x30, // Field 1: data (0100)
+    //
+    /* 070-073 */ 0x00, 0x00, 0x00, 0x00, // End of Interop IFD
+];
+
+// Canon MakerNote with a bare IFD using offsets from the TIFF header
+#[cfg(test)]
+pub(crate) const CANON_TEST_DATA: [u8; 109] = [
+    // TIFF header
+    /* 000-001 */ 0x49, 0x49, // byte alignment
+    /* 002-003 */ 0x2a, 0x00, // version identifier
+    /* 004-007 */ 0x08, 0x00, 0x00, 0x00, // IFD 0: offset
+    //
+    // IFD 0
+    /* 008-009 */ 0x02, 0x00, // IFD 0: field count
+    //
+    /* 010-011 */ 0x0f, 0x01, // Field 0: Make
+    /* 012-013 */ 0x02, 0x00, // Field 0: ASCII
+    /* 014-017 */ 0x06, 0x00, 0x00, 0x00, // Field 0: components (6)
+    /* 018-021 */ 0x38, 0x00, 0x00, 0x00, // Field 0: offset (56)
+    //
+    /* 022-023 */ 0x69, 0x87, // Field 1: Exif Offset
+    /* 024-025 */ 0x04, 0x00, // Field 1: Unsigned Long
+    /* 026-029 */ 0x01, 0x00, 0x00, 0x00, // Field 1: components (1)
+    /* 030-033 */ 0x26, 0x00, 0x00, 0x00, // Field 1: data (38)
+    //
+    /* 034-037 */ 0x00, 0x00, 0x00, 0x00, // End of IFDs
+    //
+    // Exif IFD
+    /* 038-039 */ 0x01, 0x00, // Exif IFD: field count
+    //
+    /* 040-041 */ 0x7c, 0x92, // Field 0: MakerNote
+    /* 042-043 */ 0x07, 0x00, // Field 0: Undefined
+    /* 044-047 */ 0x2f, 0x00, 0x00, 0x00, // Field 0: components (47)
+    /* 048-051 */ 0x3e, 0x00, 0x00, 0x00, // Field 0: offset (62)
+    //
+    /* 052-055 */ 0x00, 0x00, 0x00, 0x00, // End of Exif IFD
+    //
+    /* 056-061 */ 0x43, 0x61, 0x6e, 0x6f, 0x6e, 0x00, // Make (Canon)
+    //
+    // MakerNote
+    /* 062-063 */ 0x02, 0x00, // Canon IFD: field count
+    /* 064-065 */ 0x95, 0x00, // Field 0: Lens Model
+    /* 066-067 */ 0x02, 0x00, // Field 0: ASCII
+    /* 068-071 */ 0x11, 0x00, 0x00, 0x00, // Field 0: components (17)
+    /* 072-075 */ 0x5c, 0x00, 0x00, 0x00, // Field 0: offset (92)
+    /* 076-077 */ 0x0c, 0x00, // Field 1: Serial Number
+    /* 078-079 */ 0x04, 0x00, // Field 1: Unsigned Long
+    /* 080-083 */ 0x01, 0x00, 0x00, 0x00, // Field 1: components (1)
+    /* 084-087 */ 0x40, 0xe2, 0x01, 0x00, // Field 1: data (123456)
+    /* 088-091 */ 0x00, 0x00, 0x00, 0x00, // End of Canon IFD
+    /* 092-097 */ 0x45, 0x46, 0x35, 0x30, 0x6d, 0x6d, // Lens Model (EF50mm f/1.8 STM)
+    /* 098-103 */ 0x20, 0x66, 0x2f, 0x31, 0x2e, 0x38, //
+    /* 104-108 */ 0x20, 0x53, 0x54, 0x4d, 0x00, //
+];
+
+// Nikon type 3 MakerNote with an embedded big endian TIFF header
+#[cfg(test)]
+pub(crate) const NIKON_TEST_DATA: [u8; 154] = [
+    // TIFF header
+    /* 000-001 */ 0x49, 0x49, // byte alignment
+    /* 002-003 */ 0x2a, 0x00, // version identifier
+    /* 004-007 */ 0x08, 0x00, 0x00, 0x00, // IFD 0: offset
+    //
+    // IFD 0
+    /* 008-009 */ 0x02, 0x00, // IFD 0: field count
+    //
+    /* 010-011 */ 0x0f, 0x01, // Field 0: Make
+    /* 012-013 */ 0x02, 0x00, // Field 0: ASCII
+    /* 014-017 */ 0x12, 0x00, 0x00, 0x00, // Field 0: components (18)
+    /* 018-021 */ 0x38, 0x00, 0x00, 0x00, // Field 0: offset (56)
+    //
+    /* 022-023 */ 0x69, 0x87, // Field 1: Exif Offset
+    /* 024-025 */ 0x04, 0x00, // Field 1: Unsigned Long
+    /* 026-029 */ 0x01, 0x00, 0x00, 0x00, // Field 1: components (1)
+    /* 030-033 */ 0x26, 0x00, 0x00, 0x00, // Field 1: data (38)
+    //
+    /* 034-037 */ 0x00, 0x00, 0x00, 0x00, // End of IFDs
+    //
+    // Exif IFD
+    /* 038-039 */ 0x01, 0x00, // Exif IFD: field count
+    //
+    /* 040-041 */ 0x7c, 0x92, // Field 0: MakerNote
+    /* 042-043 */ 0x07, 0x00, // Field 0: Undefined
+    /* 044-047 */ 0x50, 0x00, 0x00, 0x00, // Field 0: components (80)
+    /* 048-051 */ 0x4a, 0x00, 0x00, 0x00, // Field 0: offset (74)
+    //
+    /* 052-055 */ 0x00, 0x00, 0x00, 0x00, // End of Exif IFD
+    //
+    /* 056-061 */ 0x4e, 0x49, 0x4b, 0x4f, 0x4e, 0x20, // Make (NIKON CORPORATION)
+    /* 062-067 */ 0x43, 0x4f, 0x52, 0x50, 0x4f, 0x52, //
+    /* 068-073 */ 0x41, 0x54, 0x49, 0x4f, 0x4e, 0x00, //
+    //
+    // MakerNote
+    /* 074-077 */ 0x4e, 0x69, 0x6b, 0x6f, // Nikon signature
+    /* 078-079 */ 0x6e, 0x00, //
+    /* 080-083 */ 0x02, 0x10, 0x00, 0x00, // version (2.10)
+    /* 084-085 */ 0x4d, 0x4d, // embedded TIFF: byte alignment
+    /* 086-087 */ 0x00, 0x2a, // embedded TIFF: version identifier
+    /* 088-091 */ 0x00, 0x00, 0x00, 0x08, // embedded TIFF: IFD offset (8)
+    /* 092-093 */ 0x00, 0x02, // Nikon IFD: field count
+    /* 094-095 */ 0x00, 0xa7, // Field 0: Shutter Count
+    /* 096-097 */ 0x00, 0x04, // Field 0: Unsigned Long
+    /* 098-101 */ 0x00, 0x00, 0x00, 0x01, // Field 0: components (1)
+    /* 102-105 */ 0x00, 0x00, 0x30, 0x39, // Field 0: data (12345)
+    /* 106-107 */ 0x00, 0x84, // Field 1: Lens
+    /* 108-109 */ 0x00, 0x05, // Field 1: Unsigned Rational
+    /* 110-113 */ 0x00, 0x00, 0x00, 0x04, // Field 1: components (4)
+    /* 114-117 */ 0x00, 0x00, 0x00, 0x26, // Field 1: offset (38)
+    /* 118-121 */ 0x00, 0x00, 0x00, 0x00, // End of Nikon IFD
+    /* 122-129 */ 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x01, // Lens (24)
+    /* 130-137 */ 0x00, 0x00, 0x00, 0x46, 0x00, 0x00, 0x00, 0x01, // Lens (70)
+    /* 138-145 */ 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x0a, // Lens (2.8)
+    /* 146-153 */ 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x0a, // Lens (2.8)
+];
+
+// Fujifilm MakerNote with offsets from the start of the MakerNote
+#[cfg(test)]
+pub(crate) const FUJIFILM_TEST_DATA: [u8; 115] = [
+    // TIFF header
+    /* 000-001 */ 0x49, 0x49, // byte alignment
+    /* 002-003 */ 0x2a, 0x00, // version identifier
+    /* 004-007 */ 0x08, 0x00, 0x00, 0x00, // IFD 0: offset
+    //
+    // IFD 0
+    /* 008-009 */ 0x02, 0x00, // IFD 0: field count
+    //
+    /* 010-011 */ 0x0f, 0x01, // Field 0: Make
+    /* 012-013 */ 0x02, 0x00, // Field 0: ASCII
+    /* 014-017 */ 0x09, 0x00, 0x00, 0x00, // Field 0: components (9)
+    /* 018-021 */ 0x38, 0x00, 0x00, 0x00, // Field 0: offset (56)
+    //
+    /* 022-023 */ 0x69, 0x87, // Field 1: Exif Offset
+    /* 024-025 */ 0x04, 0x00, // Field 1: Unsigned Long
+    /* 026-029 */ 0x01, 0x00, 0x00, 0x00, // Field 1: components (1)
+    /* 030-033 */ 0x26, 0x00, 0x00, 0x00, // Field 1: data (38)
+    //
+    /* 034-037 */ 0x00, 0x00, 0x00, 0x00, // End of IFDs
+    //
+    // Exif IFD
+    /* 038-039 */ 0x01, 0x00, // Exif IFD: field count
+    //
+    /* 040-041 */ 0x7c, 0x92, // Field 0: MakerNote
+    /* 042-043 */ 0x07, 0x00, // Field 0: Undefined
+    /* 044-047 */ 0x32, 0x00, 0x00, 0x00, // Field 0: components (50)
+    /* 048-051 */ 0x41, 0x00, 0x00, 0x00, // Field 0: offset (65)
+    //
+    /* 052-055 */ 0x00, 0x00, 0x00, 0x00, // End of Exif IFD
+    //
+    /* 056-062 */ 0x46, 0x55, 0x4a, 0x49, 0x46, 0x49, 0x4c, // Make (FUJIFILM)
+    /* 063-064 */ 0x4d, 0x00, //
+    //
+    // MakerNote
+    /* 065-068 */ 0x46, 0x55, 0x4a, 0x49, // Fujifilm signature
+    /* 069-072 */ 0x46, 0x49, 0x4c, 0x4d, //
+    /* 073-076 */ 0x0c, 0x00, 0x00, 0x00, // Fujifilm IFD offset (12)
+    /* 077-078 */ 0x02, 0x00, // Fujifilm IFD: field count
+    /* 079-080 */ 0x10, 0x00, // Field 0: Internal Serial Number
+    /* 081-082 */ 0x02, 0x00, // Field 0: ASCII
+    /* 083-086 */ 0x08, 0x00, 0x00, 0x00, // Field 0: components (8)
+    /* 087-090 */ 0x2a, 0x00, 0x00, 0x00, // Field 0: offset (42)
+    /* 091-092 */ 0x21, 0x10, // Field 1: Focus Mode
+    /* 093-094 */ 0x03, 0x00, // Field 1: Unsigned Short
+    /* 095-098 */ 0x01, 0x00, 0x00, 0x00, // Field 1: components (1)
+    /* 099-102 */ 0x01, 0x00, 0x00, 0x00, // Field 1: data (1)
+    /* 103-106 */ 0x00, 0x00, 0x00, 0x00, // End of Fujifilm IFD
+    /* 107-112 */ 0x46, 0x46, 0x30, 0x31, 0x32, 0x33, // Internal Serial Number (FF01234)
+    /* 113-114 */ 0x34, 0x00, //
+];
+
+// Olympus type 2 MakerNote with its own byte order
+#[cfg(test)]
+pub(crate) const OLYMPUS_TEST_DATA: [u8; 108] = [
+    // TIFF header
+    /* 000-001 */ 0x49, 0x49, // byte alignment
+    /* 002-003 */ 0x2a, 0x00, // version identifier
+    /* 004-007 */ 0x08, 0x00, 0x00, 0x00, // IFD 0: offset
+    //
+    // IFD 0
+    /* 008-009 */ 0x02, 0x00, // IFD 0: field count
+    //
+    /* 010-011 */ 0x0f, 0x01, // Field 0: Make
+    /* 012-013 */ 0x02, 0x00, // Field 0: ASCII
+    /* 014-017 */ 0x16, 0x00, 0x00, 0x00, // Field 0: components (22)
+    /* 018-021 */ 0x38, 0x00, 0x00, 0x00, // Field 0: offset (56)
+    //
+    /* 022-023 */ 0x69, 0x87, // Field 1: Exif Offset
+    /* 024-025 */ 0x04, 0x00, // Field 1: Unsigned Long
+    /* 026-029 */ 0x01, 0x00, 0x00, 0x00, // Field 1: components (1)
+    /* 030-033 */ 0x26, 0x00, 0x00, 0x00, // Field 1: data (38)
+    //
+    /* 034-037 */ 0x00, 0x00, 0x00, 0x00, // End of IFDs
+    //
+    // Exif IFD
+    /* 038-039 */ 0x01, 0x00, // Exif IFD: field count
+    //
+    /* 040-041 */ 0x7c, 0x92, // Field 0: MakerNote
+    /* 042-043 */ 0x07, 0x00, // Field 0: Undefined
+    /* 044-047 */ 0x1e, 0x00, 0x00, 0x00, // Field 0: components (30)
+    /* 048-051 */ 0x4e, 0x00, 0x00, 0x00, // Field 0: offset (78)
+    //
+    /* 052-055 */ 0x00, 0x00, 0x00, 0x00, // End of Exif IFD
+    //
+    /* 056-060 */ 0x4f, 0x4c, 0x59, 0x4d, 0x50, // Make (OLYMPUS IMAGING CORP.)
+    /* 061-065 */ 0x55, 0x53, 0x20, 0x49, 0x4d, //
+    /* 066-070 */ 0x41, 0x47, 0x49, 0x4e, 0x47, //
+    /* 071-075 */ 0x20, 0x43, 0x4f, 0x52, 0x50, //
+    /* 076-077 */ 0x2e, 0x00, //
+    //
+    // MakerNote
+    /* 078-081 */ 0x4f, 0x4c, 0x59, 0x4d, // Olympus signature
+    /* 082-085 */ 0x50, 0x55, 0x53, 0x00, //
+    /* 086-087 */ 0x49, 0x49, // byte alignment
+    /* 088-089 */ 0x03, 0x00, // version
+    /* 090-091 */ 0x01, 0x00, // Olympus IFD: field count
+    /* 092-093 */ 0x07, 0x02, // Field 0: Camera Type
+    /* 094-095 */ 0x02, 0x00, // Field 0: ASCII
+    /* 096-099 */ 0x04, 0x00, 0x00, 0x00, // Field 0: components (4)
+    /* 100-103 */ 0x44, 0x34, 0x00, 0x00, // Field 0: data (D4)
+    /* 104-107 */ 0x00, 0x00, 0x00, 0x00, // End of Olympus IFD
+];
+
+// Panasonic MakerNote with offsets from the TIFF header
+#[cfg(test)]
+pub(crate) const PANASONIC_TEST_DATA: [u8; 96] = [
+    // TIFF header
+    /* 000-001 */ 0x49, 0x49, // byte alignment
+    /* 002-003 */ 0x2a, 0x00, // version identifier
+    /* 004-007 */ 0x08, 0x00, 0x00, 0x00, // IFD 0: offset
+    //
+    // IFD 0
+    /* 008-009 */ 0x02, 0x00, // IFD 0: field count
+    //
+    /* 010-011 */ 0x0f, 0x01, // Field 0: Make
+    /* 012-013 */ 0x02, 0x00, // Field 0: ASCII
+    /* 014-017 */ 0x0a, 0x00, 0x00, 0x00, // Field 0: components (10)
+    /* 018-021 */ 0x38, 0x00, 0x00, 0x00, // Field 0: offset (56)
+    //
+    /* 022-023 */ 0x69, 0x87, // Field 1: Exif Offset
+    /* 024-025 */ 0x04, 0x00, // Field 1: Unsigned Long
+    /* 026-029 */ 0x01, 0x00, 0x00, 0x00, // Field 1: components (1)
+    /* 030-033 */ 0x26, 0x00, 0x00, 0x00, // Field 1: data (38)
+    //
+    /* 034-037 */ 0x00, 0x00, 0x00, 0x00, // End of IFDs
+    //
+    // Exif IFD
+    /* 038-039 */ 0x01, 0x00, // Exif IFD: field count
+    //
+    /* 040-041 */ 0x7c, 0x92, // Field 0: MakerNote
+    /* 042-043 */ 0x07, 0x00, // Field 0: Undefined
+    /* 044-047 */ 0x1e, 0x00, 0x00, 0x00, // Field 0: components (30)
+    /* 048-051 */ 0x42, 0x00, 0x00, 0x00, // Field 0: offset (66)
+    //
+    /* 052-055 */ 0x00, 0x00, 0x00, 0x00, // End of Exif IFD
+    //
+    /* 056-062 */ 0x50, 0x61, 0x6e, 0x61, 0x73, 0x6f, 0x6e, // Make (Panasonic)
+    /* 063-065 */ 0x69, 0x63, 0x00, //
+    //
+    // MakerNote
+    /* 066-069 */ 0x50, 0x61, 0x6e, 0x61, // Panasonic signature
+    /* 070-073 */ 0x73, 0x6f, 0x6e, 0x69, //
+    /* 074-077 */ 0x63, 0x00, 0x00, 0x00, //
+    /* 078-079 */ 0x01, 0x00, // Panasonic IFD: field count
+    /* 080-081 */ 0x07, 0x00, // Field 0: Focus Mode
+    /* 082-083 */ 0x03, 0x00, // Field 0: Unsigned Short
+    /* 084-087 */ 0x01, 0x00, 0x00, 0x00, // Field 0: components (1)
+    /* 088-091 */ 0x02, 0x00, 0x00, 0x00, // Field 0: data (2)
+    /* 092-095 */ 0x00, 0x00, 0x00, 0x00, // End of Panasonic IFD
+];
+
+// Sony MakerNote with offsets from the TIFF header
+#[cfg(test)]
+pub(crate) const SONY_TEST_DATA: [u8; 103] = [
+    // TIFF header
+    /* 000-001 */ 0x49, 0x49, // byte alignment
+    /* 002-003 */ 0x2a, 0x00, // version identifier
+    /* 004-007 */ 0x08, 0x00, 0x00, 0x00, // IFD 0: offset
+    //
+    // IFD 0
+    /* 008-009 */ 0x02, 0x00, // IFD 0: field count
+    //
+    /* 010-011 */ 0x0f, 0x01, // Field 0: Make
+    /* 012-013 */ 0x02, 0x00, // Field 0: ASCII
+    /* 014-017 */ 0x05, 0x00, 0x00, 0x00, // Field 0: components (5)
+    /* 018-021 */ 0x38, 0x00, 0x00, 0x00, // Field 0: offset (56)
+    //
+    /* 022-023 */ 0x69, 0x87, // Field 1: Exif Offset
+    /* 024-025 */ 0x04, 0x00, // Field 1: Unsigned Long
+    /* 026-029 */ 0x01, 0x00, 0x00, 0x00, // Field 1: components (1)
+    /* 030-033 */ 0x26, 0x00, 0x00, 0x00, // Field 1: data (38)
+    //
+    /* 034-037 */ 0x00, 0x00, 0x00, 0x00, // End of IFDs
+    //
+    // Exif IFD
+    /* 038-039 */ 0x01, 0x00, // Exif IFD: field count
+    //
+    /* 040-041 */ 0x7c, 0x92, // Field 0: MakerNote
+    /* 042-043 */ 0x07, 0x00, // Field 0: Undefined
+    /* 044-047 */ 0x2a, 0x00, 0x00, 0x00, // Field 0: components (42)
+    /* 048-051 */ 0x3d, 0x00, 0x00, 0x00, // Field 0: offset (61)
+    //
+    /* 052-055 */ 0x00, 0x00, 0x00, 0x00, // End of Exif IFD
+    //
+    /* 056-060 */ 0x53, 0x4f, 0x4e, 0x59, 0x00, // Make (SONY)
+    //
+    // MakerNote
+    /* 061-064 */ 0x53, 0x4f, 0x4e, 0x59, // Sony signature
+    /* 065-068 */ 0x20, 0x44, 0x53, 0x43, //
+    /* 069-072 */ 0x20, 0x00, 0x00, 0x00, //
+    /* 073-074 */ 0x02, 0x00, // Sony IFD: field count
+    /* 075-076 */ 0x01, 0xb0, // Field 0: Model ID
+    /* 077-078 */ 0x03, 0x00, // Field 0: Unsigned Short
+    /* 079-082 */ 0x01, 0x00, 0x00, 0x00, // Field 0: components (1)
+    /* 083-086 */ 0x66, 0x01, 0x00, 0x00, // Field 0: data (358)
+    /* 087-088 */ 0x27, 0xb0, // Field 1: Lens Type
+    /* 089-090 */ 0x04, 0x00, // Field 1: Unsigned Long
+    /* 091-094 */ 0x01, 0x00, 0x00, 0x00, // Field 1: components (1)
+    /* 095-098 */ 0x10, 0x80, 0x00, 0x00, // Field 1: data (32784)
+    /* 099-102 */ 0x00, 0x00, 0x00, 0x00, // End of Sony IFD
+];
@@ -0,0 +1,74 @@
+use super::IccResult;
+use crate::errors::IccError;
+
+/// Identifier prefixing each ICC profile chunk in a JPEG APP2 segment
+pub(crate) const ICC_IDENTIFIER: &[u8] = b"ICC_PROFILE\0";
+
+/// Reassemble the ICC profile from its chunks
+/// * **chunks** are the APP2 payloads following the ICC identifier in any order
+/// * **Field**    | **Bytes** | **Description**
+/// * *Sequence*   | 1         | 1 based sequence number of this chunk
+/// * *Count*      | 1         | Total number of chunks
+/// * *Data*       | n         | Chunk of the profile
+/// * Repeated chunks are ignored while missing chunks and differing counts fail
+pub(crate) fn reassemble_profile<'a>(chunks: impl Iterator<Item = &'a [u8]>) -> IccResult<Vec<u8>> {
+    let mut count = None;
+    let mut parts: Vec<Option<&[u8]>> = Vec::new();
+    for chunk in chunks {
+        let (sequence, total, data) = match chunk {
+            [sequence, total, data @ ..] => (*sequence as usize, *total as usize, data),
+            _ => return Err(IccError::parse(": chunk header too short")),
+        };
+        if *count.get_or_insert(total) != total {
+            return Err(IccError::parse(": chunk counts differ").with_data(&[total as u8]));
+        }
+        if sequence == 0 || sequence > total {
+            return Err(IccError::parse(": invalid chunk sequence").with_data(&[sequence as u8]));
+        }
+        parts.resize(total, None);
+        parts[sequence - 1].get_or_insert(data);
+    }
+
+    // Stitch the chunks together in sequence order
+    let mut data = Vec::new();
+    for (i, part) in parts.iter().enumerate() {
+        let part =
+            part.ok_or_else(|| IccError::parse(": chunk missing").with_data(&[i as u8 + 1]))?;
+        data.extend_from_slice(part);
+    }
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reassemble() {
+        let chunks: [&[u8]; 4] = [
+            &[2, 3, 0x03, 0x04],
+            &[1, 3, 0x01, 0x02],
+            &[1, 3, 0xFF],
+            &[3, 3, 0x05],
+        ];
+        let data = reassemble_profile(chunks.into_iter()).unwrap();
+        assert_eq!(data, vec![0x01, 0x02, 0x03, 0x04, 0x05]);
+        assert_eq!(reassemble_profile(std::iter::empty()).unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_reassemble_invalid() {
+        let chunks: [&[u8]; 2] = [&[1, 3, 0x01], &[3, 3, 0x03]];
+        let err = reassemble_profile(chunks.into_iter()).unwrap_err();
+        assert_eq!(err.to_string(), "ICC parse failed: chunk missing [02]");
+
+        let chunks: [&[u8]; 2] = [&[1, 2, 0x01], &[2, 3, 0x03]];
+        let err = reassemble_profile(chunks.into_iter()).unwrap_err();
+        assert_eq!(err.to_string(), "ICC parse failed: chunk counts differ [03]");
+
+        let err = reassemble_profile([&[0u8, 1][..]].into_iter()).unwrap_err();
+        assert_eq!(err.to_string(), "ICC parse failed: invalid chunk sequence [00]");
+        let err = reassemble_profile([&[1u8][..]].into_iter()).unwrap_err();
+        assert_eq!(err.to_string(), "ICC parse failed: chunk header too short");
+    }
+}
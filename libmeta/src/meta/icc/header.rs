@@ -0,0 +1,158 @@
+// The ICC specification is the source for the header signatures
+// https://www.color.org/specification/ICC.1-2022-05.pdf
+
+use std::fmt::Display;
+
+/// Profile or device class i.e. what the profile converts between
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IccProfileClass {
+    Input,        // `scnr` e.g. scanners and cameras
+    Display,      // `mntr` e.g. monitors
+    Output,       // `prtr` e.g. printers
+    DeviceLink,   // `link`
+    ColorSpace,   // `spac`
+    Abstract,     // `abst`
+    NamedColor,   // `nmcl`
+    Raw([u8; 4]), // unknown signature
+}
+
+impl From<[u8; 4]> for IccProfileClass {
+    fn from(val: [u8; 4]) -> Self {
+        match &val {
+            b"scnr" => Self::Input,
+            b"mntr" => Self::Display,
+            b"prtr" => Self::Output,
+            b"link" => Self::DeviceLink,
+            b"spac" => Self::ColorSpace,
+            b"abst" => Self::Abstract,
+            b"nmcl" => Self::NamedColor,
+            _ => Self::Raw(val),
+        }
+    }
+}
+
+impl Display for IccProfileClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Input => write!(f, "Input Device Profile"),
+            Self::Display => write!(f, "Display Device Profile"),
+            Self::Output => write!(f, "Output Device Profile"),
+            Self::DeviceLink => write!(f, "DeviceLink Profile"),
+            Self::ColorSpace => write!(f, "ColorSpace Conversion Profile"),
+            Self::Abstract => write!(f, "Abstract Profile"),
+            Self::NamedColor => write!(f, "Named Color Profile"),
+            Self::Raw(x) => write!(f, "Unknown({})", String::from_utf8_lossy(x)),
+        }
+    }
+}
+
+/// Data color space of the profile or its profile connection space (PCS)
+/// * The PCS is always either `Xyz` or `Lab` except for device link profiles
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IccColorSpace {
+    Xyz,          // `XYZ `
+    Lab,          // `Lab `
+    Luv,          // `Luv `
+    YCbCr,        // `YCbr`
+    Yxy,          // `Yxy `
+    Rgb,          // `RGB `
+    Gray,         // `GRAY`
+    Hsv,          // `HSV `
+    Hls,          // `HLS `
+    Cmyk,         // `CMYK`
+    Cmy,          // `CMY `
+    Color(u8),    // `2CLR` to `FCLR` i.e. 2 to 15 generic color channels
+    Raw([u8; 4]), // unknown signature
+}
+
+impl From<[u8; 4]> for IccColorSpace {
+    fn from(val: [u8; 4]) -> Self {
+        match &val {
+            b"XYZ " => Self::Xyz,
+            b"Lab " => Self::Lab,
+            b"Luv " => Self::Luv,
+            b"YCbr" => Self::YCbCr,
+            b"Yxy " => Self::Yxy,
+            b"RGB " => Self::Rgb,
+            b"GRAY" => Self::Gray,
+            b"HSV " => Self::Hsv,
+            b"HLS " => Self::Hls,
+            b"CMYK" => Self::Cmyk,
+            b"CMY " => Self::Cmy,
+            [x @ (b'2'..=b'9' | b'A'..=b'F'), b'C', b'L', b'R'] => {
+                Self::Color((*x as char).to_digit(16).unwrap_or_default() as u8)
+            }
+            _ => Self::Raw(val),
+        }
+    }
+}
+
+impl Display for IccColorSpace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Xyz => write!(f, "XYZ"),
+            Self::Lab => write!(f, "Lab"),
+            Self::Luv => write!(f, "Luv"),
+            Self::YCbCr => write!(f, "YCbCr"),
+            Self::Yxy => write!(f, "Yxy"),
+            Self::Rgb => write!(f, "RGB"),
+            Self::Gray => write!(f, "Grayscale"),
+            Self::Hsv => write!(f, "HSV"),
+            Self::Hls => write!(f, "HLS"),
+            Self::Cmyk => write!(f, "CMYK"),
+            Self::Cmy => write!(f, "CMY"),
+            Self::Color(x) => write!(f, "{} Color", x),
+            Self::Raw(x) => write!(f, "Unknown({})", String::from_utf8_lossy(x)),
+        }
+    }
+}
+
+/// Rendering intent the profile is meant to be used with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IccRenderingIntent {
+    Perceptual,           // 0
+    RelativeColorimetric, // 1
+    Saturation,           // 2
+    AbsoluteColorimetric, // 3
+    Unknown(u32),         // anything else
+}
+
+impl From<u32> for IccRenderingIntent {
+    fn from(val: u32) -> Self {
+        match val {
+            0 => Self::Perceptual,
+            1 => Self::RelativeColorimetric,
+            2 => Self::Saturation,
+            3 => Self::AbsoluteColorimetric,
+            _ => Self::Unknown(val),
+        }
+    }
+}
+
+impl Display for IccRenderingIntent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Perceptual => write!(f, "Perceptual"),
+            Self::RelativeColorimetric => write!(f, "Media-Relative Colorimetric"),
+            Self::Saturation => write!(f, "Saturation"),
+            Self::AbsoluteColorimetric => write!(f, "ICC-Absolute Colorimetric"),
+            Self::Unknown(x) => write!(f, "Unknown({})", x),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signatures() {
+        assert_eq!(IccProfileClass::from(*b"mntr"), IccProfileClass::Display);
+        assert_eq!(IccProfileClass::from(*b"zzzz").to_string(), "Unknown(zzzz)");
+        assert_eq!(IccColorSpace::from(*b"RGB "), IccColorSpace::Rgb);
+        assert_eq!(IccColorSpace::from(*b"CCLR"), IccColorSpace::Color(12));
+        assert_eq!(IccColorSpace::from(*b"1CLR"), IccColorSpace::Raw(*b"1CLR"));
+        assert_eq!(IccRenderingIntent::from(1).to_string(), "Media-Relative Colorimetric");
+        assert_eq!(IccRenderingIntent::from(7), IccRenderingIntent::Unknown(7));
+    }
+}
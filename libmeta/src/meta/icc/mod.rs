@@ -0,0 +1,16 @@
+// ICC profiles describe the color space of the image data. They are embedded as is in PNG iCCP
+// chunks and split across JPEG APP2 segments when larger than a single segment.
+mod chunk;
+mod header;
+mod profile;
+mod test_data;
+
+// Surface types from modules directly in the meta module
+pub(crate) use chunk::*;
+pub use header::*;
+pub use profile::IccProfile;
+pub(crate) use profile::*;
+
+// Expose testing data to other modules
+#[cfg(test)]
+pub(crate) use test_data::ICC_V2_TEST_DATA;
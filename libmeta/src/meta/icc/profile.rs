@@ -0,0 +1,244 @@
+use std::fmt::Display;
+
+use super::{IccColorSpace, IccProfileClass, IccRenderingIntent};
+use crate::errors::IccError;
+
+/// Simplify the ICC return type slightly
+pub(crate) type IccResult<T> = Result<T, IccError>;
+
+/// Size of the fixed profile header ahead of the tag table
+const HEADER_SIZE: usize = 128;
+
+/// Size of each tag table entry i.e. signature, offset and size
+const TAG_SIZE: usize = 12;
+
+/// ICC color profile header and descriptive tags
+#[derive(Debug, Clone, PartialEq)]
+pub struct IccProfile {
+    pub version: (u8, u8, u8),      // major, minor and bug fix e.g. 4.3.0
+    pub class: IccProfileClass,     // profile or device class
+    pub color_space: IccColorSpace, // data color space e.g. RGB
+    pub pcs: IccColorSpace,         // profile connection space i.e. XYZ or Lab
+    pub rendering_intent: IccRenderingIntent, // intended rendering intent
+    pub description: Option<String>, // `desc` tag e.g. `Display P3`
+    pub copyright: Option<String>,  // `cprt` tag
+}
+
+impl IccProfile {
+    /// Parse the given ICC profile
+    /// * **Field**           | **Bytes** | **Description**
+    /// * *Size*              | 4         | Big Endian size of the profile
+    /// * *CMM*               | 4         | Preferred color management module
+    /// * *Version*           | 4         | Major version, minor and bug fix nibbles then 2 reserved bytes
+    /// * *Class*             | 4         | Profile or device class e.g. `mntr`
+    /// * *Color space*       | 4         | Data color space e.g. `RGB `
+    /// * *PCS*               | 4         | Profile connection space i.e. `XYZ ` or `Lab `
+    /// * *Date*              | 12        | Creation date and time
+    /// * *Signature*         | 4         | `acsp`
+    /// * ...                 | 24        | Platform, flags, manufacturer, model and attributes
+    /// * *Rendering intent*  | 4         | Big Endian intent e.g. `0` = perceptual
+    /// * ...                 | 60        | Illuminant, creator, ID and reserved bytes
+    /// * *Tag count*         | 4         | Big Endian number of tag table entries
+    /// * *Tag table*         | 12 x n    | Tag signature, Big Endian offset from the profile start and size
+    pub(crate) fn parse(input: &[u8]) -> IccResult<Self> {
+        let header = input
+            .get(..HEADER_SIZE)
+            .ok_or_else(|| IccError::parse(": header truncated"))?;
+        if &header[36..40] != b"acsp" {
+            return Err(IccError::parse(": invalid signature").with_data(&header[36..40]));
+        }
+        let signature = |i: usize| [header[i], header[i + 1], header[i + 2], header[i + 3]];
+
+        Ok(Self {
+            version: (header[8], header[9] >> 4, header[9] & 0x0F),
+            class: IccProfileClass::from(signature(12)),
+            color_space: IccColorSpace::from(signature(16)),
+            pcs: IccColorSpace::from(signature(20)),
+            rendering_intent: IccRenderingIntent::from(u32::from_be_bytes(signature(64))),
+            description: find_tag(input, b"desc").and_then(parse_text),
+            copyright: find_tag(input, b"cprt").and_then(parse_text),
+        })
+    }
+}
+
+impl Display for IccProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (major, minor, bugfix) = self.version;
+        writeln!(f, "  {: <32}: {}.{}.{}", "Profile Version", major, minor, bugfix)?;
+        writeln!(f, "  {: <32}: {}", "Profile Class", self.class)?;
+        writeln!(f, "  {: <32}: {}", "Color Space Data", self.color_space)?;
+        writeln!(f, "  {: <32}: {}", "Profile Connection Space", self.pcs)?;
+        write!(f, "  {: <32}: {}", "Rendering Intent", self.rendering_intent)?;
+        if let Some(description) = &self.description {
+            write!(f, "\n  {: <32}: {}", "Profile Description", description)?;
+        }
+        if let Some(copyright) = &self.copyright {
+            write!(f, "\n  {: <32}: {}", "Profile Copyright", copyright)?;
+        }
+        Ok(())
+    }
+}
+
+/// Find the data of the tag with the given signature in the tag table
+/// * A truncated table or out of bounds tag data is treated as the tag being missing so that a
+///   damaged description or copyright doesn't lose the header details
+fn find_tag<'a>(input: &'a [u8], signature: &[u8; 4]) -> Option<&'a [u8]> {
+    let u32 = |i: usize| {
+        input
+            .get(i..i + 4)
+            .map(|x| u32::from_be_bytes([x[0], x[1], x[2], x[3]]) as usize)
+    };
+    for i in 0..u32(HEADER_SIZE)? {
+        let entry = HEADER_SIZE + 4 + i * TAG_SIZE;
+        let (offset, size) = (u32(entry + 4)?, u32(entry + 8)?);
+        if &input[entry..entry + 4] == signature {
+            return input.get(offset..offset.checked_add(size)?);
+        }
+    }
+    None
+}
+
+/// Parse the text of a `desc`, `text` or `mluc` tag type
+/// * **Type**  | **Layout after the type signature and 4 reserved bytes**
+/// * *desc*    | Big Endian ASCII length including the null then the ASCII text, ICC v2
+/// * *text*    | ASCII text, ICC v2
+/// * *mluc*    | Big Endian record count and record size then records of language, country,
+///   Big Endian length and offset of the UTF-16BE text, ICC v4
+/// * Multi-localized text prefers `en` falling back on the first record
+fn parse_text(data: &[u8]) -> Option<String> {
+    let u32 = |i: usize| {
+        data.get(i..i + 4)
+            .map(|x| u32::from_be_bytes([x[0], x[1], x[2], x[3]]) as usize)
+    };
+    let text = match data.get(..4)? {
+        b"desc" => data
+            .get(12..12usize.checked_add(u32(8)?)?)?
+            .iter()
+            .map(|x| *x as char)
+            .collect::<String>(),
+        b"text" => data.get(8..)?.iter().map(|x| *x as char).collect(),
+        b"mluc" => {
+            // Records are at least 12 bytes and can't outnumber what fits in the tag data
+            let (count, size) = (u32(8)?, u32(12)?);
+            if size < 12 {
+                return None;
+            }
+            let count = count.min(data.len().saturating_sub(16) / size);
+            let record = |i: usize| {
+                let start = i.checked_mul(size)?.checked_add(16)?;
+                data.get(start..start.checked_add(12)?)
+            };
+            let record = (0..count)
+                .filter_map(record)
+                .find(|x| x.starts_with(b"en"))
+                .or_else(|| (0..count).find_map(record))?;
+            let length = u32::from_be_bytes([record[4], record[5], record[6], record[7]]) as usize;
+            let offset =
+                u32::from_be_bytes([record[8], record[9], record[10], record[11]]) as usize;
+            let units: Vec<u16> = data
+                .get(offset..offset.checked_add(length)?)?
+                .chunks_exact(2)
+                .map(|x| u16::from_be_bytes([x[0], x[1]]))
+                .collect();
+            String::from_utf16_lossy(&units)
+        }
+        _ => return None,
+    };
+    Some(text.trim_end_matches('\0').to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meta::icc::test_data::{ICC_TEST_DATA, ICC_V2_TEST_DATA};
+
+    #[test]
+    fn test_parse_v4() {
+        let profile = IccProfile::parse(&ICC_TEST_DATA).unwrap();
+        assert_eq!(profile.version, (4, 3, 0));
+        assert_eq!(profile.class, IccProfileClass::Display);
+        assert_eq!(profile.color_space, IccColorSpace::Rgb);
+        assert_eq!(profile.pcs, IccColorSpace::Xyz);
+        assert_eq!(profile.rendering_intent, IccRenderingIntent::RelativeColorimetric);
+        assert_eq!(profile.description.as_deref(), Some("Display P3"));
+        assert_eq!(profile.copyright.as_deref(), Some("Copyright Apple"));
+        assert!(profile
+            .to_string()
+            .contains("Profile Description             : Display P3"));
+    }
+
+    #[test]
+    fn test_parse_v2() {
+        let profile = IccProfile::parse(&ICC_V2_TEST_DATA).unwrap();
+        assert_eq!(profile.version, (2, 1, 0));
+        assert_eq!(profile.rendering_intent, IccRenderingIntent::Perceptual);
+        assert_eq!(profile.description.as_deref(), Some("sRGB IEC61966-2.1"));
+        assert_eq!(
+            profile.copyright.as_deref(),
+            Some("Copyright (c) 1998 Hewlett-Packard Company")
+        );
+
+        // Missing and unknown tag types are left empty
+        let mut data = ICC_V2_TEST_DATA;
+        data[12..20].copy_from_slice(b"scnrGRAY");
+        data[144..148].copy_from_slice(b"xxxx");
+        data[156..160].copy_from_slice(b"XXXX");
+        let profile = IccProfile::parse(&data).unwrap();
+        assert_eq!(profile.class, IccProfileClass::Input);
+        assert_eq!(profile.description, None);
+        assert_eq!(profile.copyright, None);
+    }
+
+    #[test]
+    fn test_parse_hostile_mluc() {
+        let description = |patch: &[(usize, [u8; 4])]| {
+            let mut data = ICC_TEST_DATA;
+            for (i, bytes) in patch {
+                data[*i..*i + 4].copy_from_slice(bytes);
+            }
+            IccProfile::parse(&data).unwrap().description
+        };
+
+        // Record count far beyond the tag data
+        assert_eq!(description(&[(164, [0xFF; 4])]).as_deref(), Some("Display P3"));
+
+        // Record size too small or too large for the tag data
+        assert_eq!(description(&[(168, [0x00; 4])]), None);
+        assert_eq!(description(&[(168, [0x00, 0x00, 0x00, 0x0b])]), None);
+        assert_eq!(description(&[(164, [0xFF; 4]), (168, [0xFF; 4])]), None);
+
+        // Record text length and offset overflowing
+        assert_eq!(description(&[(176, [0xFF; 4]), (180, [0xFF; 4])]), None);
+    }
+
+    #[test]
+    fn test_parse_bad_tag_table() {
+        // Tag data out of bounds
+        let profile = IccProfile::parse(&ICC_TEST_DATA[..150]).unwrap();
+        assert_eq!(profile.class, IccProfileClass::Display);
+        assert_eq!(profile.description, None);
+        assert_eq!(profile.copyright, None);
+
+        // Tag table truncated
+        let profile = IccProfile::parse(&ICC_TEST_DATA[..130]).unwrap();
+        assert_eq!(profile.color_space, IccColorSpace::Rgb);
+        assert_eq!(profile.description, None);
+
+        // Tag count far beyond the table and tag offset overflowing
+        let mut data = ICC_TEST_DATA;
+        data[128..132].copy_from_slice(&[0xFF; 4]);
+        data[136..140].copy_from_slice(&[0xFF; 4]);
+        let profile = IccProfile::parse(&data).unwrap();
+        assert_eq!(profile.description, None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let mut data = ICC_TEST_DATA;
+        let err = IccProfile::parse(&data[..100]).unwrap_err();
+        assert_eq!(err.to_string(), "ICC parse failed: header truncated");
+        data[36] = b'x';
+        let err = IccProfile::parse(&data).unwrap_err();
+        assert_eq!(err.to_string(), "ICC parse failed: invalid signature [78, 63, 73, 70]");
+    }
+}
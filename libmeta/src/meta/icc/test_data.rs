@@ -0,0 +1,118 @@
+// ICC v4 display profile with multi-localized description and copyright tags
+#[cfg(test)]
+pub(crate) const ICC_TEST_DATA: [u8; 262] = [
+    // Profile header
+    /* 000-003 */ 0x00, 0x00, 0x01, 0x06, // Size (262)
+    /* 004-007 */ 0x00, 0x00, 0x00, 0x00, // CMM
+    /* 008-011 */ 0x04, 0x30, 0x00, 0x00, // Version (4.3.0)
+    /* 012-015 */ 0x6d, 0x6e, 0x74, 0x72, // Class (mntr)
+    /* 016-019 */ 0x52, 0x47, 0x42, 0x20, // Color space (RGB )
+    /* 020-023 */ 0x58, 0x59, 0x5a, 0x20, // PCS (XYZ )
+    /* 024-029 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Date
+    /* 030-035 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    /* 036-039 */ 0x61, 0x63, 0x73, 0x70, // Signature (acsp)
+    /* 040-046 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Platform to attributes
+    /* 047-054 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    /* 055-062 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    /*     063 */ 0x00, //
+    /* 064-067 */ 0x00, 0x00, 0x00, 0x01, // Rendering intent (1)
+    /* 068-074 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Illuminant to reserved
+    /* 075-082 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    /* 083-090 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    /* 091-098 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    /* 099-106 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    /* 107-114 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    /* 115-122 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    /* 123-127 */ 0x00, 0x00, 0x00, 0x00, 0x00, //
+    //
+    // Tag table
+    /* 128-131 */ 0x00, 0x00, 0x00, 0x02, // Tag count (2)
+    /* 132-135 */ 0x64, 0x65, 0x73, 0x63, // Tag 0: signature (desc)
+    /* 136-139 */ 0x00, 0x00, 0x00, 0x9c, // Tag 0: offset (156)
+    /* 140-143 */ 0x00, 0x00, 0x00, 0x30, // Tag 0: size (48)
+    /* 144-147 */ 0x63, 0x70, 0x72, 0x74, // Tag 1: signature (cprt)
+    /* 148-151 */ 0x00, 0x00, 0x00, 0xcc, // Tag 1: offset (204)
+    /* 152-155 */ 0x00, 0x00, 0x00, 0x3a, // Tag 1: size (58)
+    //
+    // Description tag
+    /* 156-159 */ 0x6d, 0x6c, 0x75, 0x63, // Type (mluc)
+    /* 160-163 */ 0x00, 0x00, 0x00, 0x00, // Reserved
+    /* 164-167 */ 0x00, 0x00, 0x00, 0x01, // Record count (1)
+    /* 168-171 */ 0x00, 0x00, 0x00, 0x0c, // Record size (12)
+    /* 172-175 */ 0x65, 0x6e, 0x55, 0x53, // Record 0: language (enUS)
+    /* 176-179 */ 0x00, 0x00, 0x00, 0x14, // Record 0: length (20)
+    /* 180-183 */ 0x00, 0x00, 0x00, 0x1c, // Record 0: offset (28)
+    /* 184-190 */ 0x00, 0x44, 0x00, 0x69, 0x00, 0x73, 0x00, // Record 0: UTF-16BE text
+    /* 191-198 */ 0x70, 0x00, 0x6c, 0x00, 0x61, 0x00, 0x79, 0x00, //
+    /* 199-203 */ 0x20, 0x00, 0x50, 0x00, 0x33, //
+    //
+    // Copyright tag
+    /* 204-207 */ 0x6d, 0x6c, 0x75, 0x63, // Type (mluc)
+    /* 208-211 */ 0x00, 0x00, 0x00, 0x00, // Reserved
+    /* 212-215 */ 0x00, 0x00, 0x00, 0x01, // Record count (1)
+    /* 216-219 */ 0x00, 0x00, 0x00, 0x0c, // Record size (12)
+    /* 220-223 */ 0x65, 0x6e, 0x55, 0x53, // Record 0: language (enUS)
+    /* 224-227 */ 0x00, 0x00, 0x00, 0x1e, // Record 0: length (30)
+    /* 228-231 */ 0x00, 0x00, 0x00, 0x1c, // Record 0: offset (28)
+    /* 232-238 */ 0x00, 0x43, 0x00, 0x6f, 0x00, 0x70, 0x00, // Record 0: UTF-16BE text
+    /* 239-246 */ 0x79, 0x00, 0x72, 0x00, 0x69, 0x00, 0x67, 0x00, //
+    /* 247-254 */ 0x68, 0x00, 0x74, 0x00, 0x20, 0x00, 0x41, 0x00, //
+    /* 255-261 */ 0x70, 0x00, 0x70, 0x00, 0x6c, 0x00, 0x65, //
+];
+
+// ICC v2 display profile with the older description and text tag types
+#[cfg(test)]
+pub(crate) const ICC_V2_TEST_DATA: [u8; 245] = [
+    // Profile header
+    /* 000-003 */ 0x00, 0x00, 0x00, 0xf5, // Size (245)
+    /* 004-007 */ 0x00, 0x00, 0x00, 0x00, // CMM
+    /* 008-011 */ 0x02, 0x10, 0x00, 0x00, // Version (2.1.0)
+    /* 012-015 */ 0x6d, 0x6e, 0x74, 0x72, // Class (mntr)
+    /* 016-019 */ 0x52, 0x47, 0x42, 0x20, // Color space (RGB )
+    /* 020-023 */ 0x58, 0x59, 0x5a, 0x20, // PCS (XYZ )
+    /* 024-029 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Date
+    /* 030-035 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    /* 036-039 */ 0x61, 0x63, 0x73, 0x70, // Signature (acsp)
+    /* 040-046 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Platform to attributes
+    /* 047-054 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    /* 055-062 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    /*     063 */ 0x00, //
+    /* 064-067 */ 0x00, 0x00, 0x00, 0x00, // Rendering intent (0)
+    /* 068-074 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Illuminant to reserved
+    /* 075-082 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    /* 083-090 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    /* 091-098 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    /* 099-106 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    /* 107-114 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    /* 115-122 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    /* 123-127 */ 0x00, 0x00, 0x00, 0x00, 0x00, //
+    //
+    // Tag table
+    /* 128-131 */ 0x00, 0x00, 0x00, 0x02, // Tag count (2)
+    /* 132-135 */ 0x64, 0x65, 0x73, 0x63, // Tag 0: signature (desc)
+    /* 136-139 */ 0x00, 0x00, 0x00, 0x9c, // Tag 0: offset (156)
+    /* 140-143 */ 0x00, 0x00, 0x00, 0x26, // Tag 0: size (38)
+    /* 144-147 */ 0x63, 0x70, 0x72, 0x74, // Tag 1: signature (cprt)
+    /* 148-151 */ 0x00, 0x00, 0x00, 0xc2, // Tag 1: offset (194)
+    /* 152-155 */ 0x00, 0x00, 0x00, 0x33, // Tag 1: size (51)
+    //
+    // Description tag
+    /* 156-159 */ 0x64, 0x65, 0x73, 0x63, // Type (desc)
+    /* 160-163 */ 0x00, 0x00, 0x00, 0x00, // Reserved
+    /* 164-167 */ 0x00, 0x00, 0x00, 0x12, // ASCII length (18)
+    /* 168-175 */ 0x73, 0x52, 0x47, 0x42, 0x20, 0x49, 0x45, 0x43, // ASCII text
+    /* 176-183 */ 0x36, 0x31, 0x39, 0x36, 0x36, 0x2d, 0x32, 0x2e, //
+    /* 184-185 */ 0x31, 0x00, //
+    /* 186-192 */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Unicode and ScriptCode
+    /*     193 */ 0x00, //
+    //
+    // Copyright tag
+    /* 194-197 */ 0x74, 0x65, 0x78, 0x74, // Type (text)
+    /* 198-201 */ 0x00, 0x00, 0x00, 0x00, // Reserved
+    /* 202-209 */ 0x43, 0x6f, 0x70, 0x79, 0x72, 0x69, 0x67, 0x68, // ASCII text
+    /* 210-217 */ 0x74, 0x20, 0x28, 0x63, 0x29, 0x20, 0x31, 0x39, //
+    /* 218-225 */ 0x39, 0x38, 0x20, 0x48, 0x65, 0x77, 0x6c, 0x65, //
+    /* 226-233 */ 0x74, 0x74, 0x2d, 0x50, 0x61, 0x63, 0x6b, 0x61, //
+    /* 234-241 */ 0x72, 0x64, 0x20, 0x43, 0x6f, 0x6d, 0x70, 0x61, //
+    /* 242-244 */ 0x6e, 0x79, 0x00, //
+];
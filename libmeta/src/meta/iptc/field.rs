@@ -0,0 +1,135 @@
+use std::fmt::Display;
+
+use super::UTF8_CHARACTER_SET;
+
+/// Typed value of a parsed IPTC dataset
+/// * Each variant mirrors the `IptcTag` of the same name
+/// * Repeatable datasets collect every occurrence in order
+#[derive(Debug, Clone, PartialEq)]
+pub enum IptcField {
+    ModelVersion(u16),
+    Destination(Vec<String>),
+    FileFormat(u16),
+    FileFormatVersion(u16),
+    ServiceIdentifier(String),
+    EnvelopeNumber(String),
+    ProductId(Vec<String>),
+    EnvelopePriority(u8),
+    DateSent(String),
+    TimeSent(String),
+    CodedCharacterSet(Vec<u8>),
+    UniqueObjectName(String),
+    ApplicationRecordVersion(u16),
+    ObjectName(String),
+    Urgency(u8),
+    Category(String),
+    SupplementalCategories(Vec<String>),
+    Keywords(Vec<String>),
+    SpecialInstructions(String),
+    DateCreated(String),
+    TimeCreated(String),
+    DigitalCreationDate(String),
+    DigitalCreationTime(String),
+    OriginatingProgram(String),
+    ProgramVersion(String),
+    Byline(Vec<String>),
+    BylineTitle(Vec<String>),
+    City(String),
+    SubLocation(String),
+    ProvinceState(String),
+    CountryCode(String),
+    CountryName(String),
+    OriginalTransmissionReference(String),
+    Headline(String),
+    Credit(String),
+    Source(String),
+    CopyrightNotice(String),
+    Contact(Vec<String>),
+    Caption(String),
+    Writer(Vec<String>),
+    Raw(u8, u8, Vec<u8>),
+}
+
+impl Display for IptcField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IptcField::ModelVersion(x)
+            | IptcField::FileFormat(x)
+            | IptcField::FileFormatVersion(x)
+            | IptcField::ApplicationRecordVersion(x) => write!(f, "{}", x),
+            IptcField::EnvelopePriority(x) | IptcField::Urgency(x) => write!(f, "{}", x),
+            IptcField::Destination(x)
+            | IptcField::ProductId(x)
+            | IptcField::SupplementalCategories(x)
+            | IptcField::Keywords(x)
+            | IptcField::Byline(x)
+            | IptcField::BylineTitle(x)
+            | IptcField::Contact(x)
+            | IptcField::Writer(x) => write!(f, "{}", x.join(", ")),
+            IptcField::DateSent(x)
+            | IptcField::DateCreated(x)
+            | IptcField::DigitalCreationDate(x) => write!(f, "{}", format_date(x)),
+            IptcField::TimeSent(x)
+            | IptcField::TimeCreated(x)
+            | IptcField::DigitalCreationTime(x) => write!(f, "{}", format_time(x)),
+            IptcField::CodedCharacterSet(x) if x == UTF8_CHARACTER_SET => write!(f, "UTF8"),
+            IptcField::CodedCharacterSet(x) | IptcField::Raw(_, _, x) => write!(f, "{:02x?}", x),
+            IptcField::ServiceIdentifier(x)
+            | IptcField::EnvelopeNumber(x)
+            | IptcField::UniqueObjectName(x)
+            | IptcField::ObjectName(x)
+            | IptcField::Category(x)
+            | IptcField::SpecialInstructions(x)
+            | IptcField::OriginatingProgram(x)
+            | IptcField::ProgramVersion(x)
+            | IptcField::City(x)
+            | IptcField::SubLocation(x)
+            | IptcField::ProvinceState(x)
+            | IptcField::CountryCode(x)
+            | IptcField::CountryName(x)
+            | IptcField::OriginalTransmissionReference(x)
+            | IptcField::Headline(x)
+            | IptcField::Credit(x)
+            | IptcField::Source(x)
+            | IptcField::CopyrightNotice(x)
+            | IptcField::Caption(x) => write!(f, "{}", x),
+        }
+    }
+}
+
+// Format a `CCYYMMDD` date as `CCYY:MM:DD` leaving anything else as is
+fn format_date(date: &str) -> String {
+    match date.len() == 8 && date.bytes().all(|x| x.is_ascii_digit()) {
+        true => format!("{}:{}:{}", &date[..4], &date[4..6], &date[6..]),
+        false => date.to_string(),
+    }
+}
+
+// Format a `HHMMSS±HHMM` time as `HH:MM:SS±HH:MM` leaving anything else as is
+fn format_time(time: &str) -> String {
+    let bytes = time.as_bytes();
+    let digits = |x: &[u8]| x.iter().all(|x| x.is_ascii_digit());
+    match bytes.len() {
+        6 if digits(bytes) => format!("{}:{}:{}", &time[..2], &time[2..4], &time[4..]),
+        11 if digits(&bytes[..6]) && matches!(bytes[6], b'+' | b'-') && digits(&bytes[7..]) => {
+            format!("{}:{}:{}{}:{}", &time[..2], &time[2..4], &time[4..6], &time[6..9], &time[9..])
+        }
+        _ => time.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let field = IptcField::Keywords(vec!["harbor".into(), "storm".into()]);
+        assert_eq!(field.to_string(), "harbor, storm");
+        assert_eq!(IptcField::DateCreated("20240315".into()).to_string(), "2024:03:15");
+        assert_eq!(IptcField::TimeCreated("143000+0100".into()).to_string(), "14:30:00+01:00");
+        assert_eq!(IptcField::TimeSent("1430".into()).to_string(), "1430");
+        assert_eq!(IptcField::CodedCharacterSet(UTF8_CHARACTER_SET.to_vec()).to_string(), "UTF8");
+        assert_eq!(IptcField::Raw(2, 200, vec![0x01]).to_string(), "[01]");
+    }
+}
@@ -0,0 +1,10 @@
+// IPTC-IIM is stored in the Photoshop image resource blocks as a list of datasets each identified by
+// a record and dataset number e.g. `2:120` for the caption
+mod field;
+mod record;
+mod tag;
+
+// Surface types from modules directly in the meta module
+pub use field::*;
+pub(crate) use record::*;
+pub use tag::*;
@@ -0,0 +1,262 @@
+use std::fmt::Display;
+
+use super::{IptcField, IptcTag};
+use crate::errors::IptcError;
+
+/// Simplify the IPTC return type slightly
+pub(crate) type IptcResult<T> = Result<T, IptcError>;
+
+/// CodedCharacterSet value flagging UTF-8 text i.e. the ISO 2022 escape sequence `ESC % G`
+pub(crate) const UTF8_CHARACTER_SET: &[u8] = &[0x1B, 0x25, 0x47];
+
+/// Marker starting every IPTC-IIM dataset
+const TAG_MARKER: u8 = 0x1C;
+
+/// IPTC-IIM datasets from the envelope and application records
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Iptc {
+    datasets: Vec<(IptcTag, Vec<u8>)>, // datasets in file order
+    utf8: bool,                        // text is UTF-8 rather than Latin-1
+}
+
+impl Iptc {
+    /// Parse the given IPTC-IIM data into its datasets
+    /// * **Field**   | **Bytes** | **Description**
+    /// * *Marker*    | 1         | `0x1C` tag marker
+    /// * *Record*    | 1         | `1` = envelope record, `2` = application record
+    /// * *Dataset*   | 1         | Dataset number within the record
+    /// * *Length*    | 2         | Big Endian data length
+    /// * *Data*      | n         | Dataset data
+    /// * Lengths with the high bit set are extended i.e. the lower 15 bits give the number of
+    ///   following bytes holding the Big Endian data length
+    /// * Trailing null padding ends the datasets
+    pub(crate) fn parse(input: &[u8]) -> IptcResult<Self> {
+        let mut datasets = Vec::new();
+        let mut remain = input;
+        while let Some((&marker, rest)) = remain.split_first() {
+            if marker != TAG_MARKER {
+                if remain.iter().all(|x| *x == 0x00) {
+                    break;
+                }
+                return Err(IptcError::parse(": invalid tag marker").with_data(&[marker]));
+            }
+            let (record, dataset, length) = match rest {
+                [record, dataset, a, b, ..] => (*record, *dataset, u16::from_be_bytes([*a, *b])),
+                _ => return Err(IptcError::parse(": dataset header truncated")),
+            };
+            let mut rest = &rest[4..];
+
+            let length = match length & 0x8000 != 0 {
+                true => {
+                    let size = (length & 0x7FFF) as usize;
+                    let bytes = rest.get(..size).filter(|_| size <= 4).ok_or_else(|| {
+                        IptcError::parse(": invalid extended length").with_data(&[record, dataset])
+                    })?;
+                    rest = &rest[size..];
+                    bytes.iter().fold(0, |acc, x| acc << 8 | *x as usize)
+                }
+                false => length as usize,
+            };
+            let data = rest.get(..length).ok_or_else(|| {
+                IptcError::parse(": dataset truncated").with_data(&[record, dataset])
+            })?;
+            datasets.push((IptcTag::from((record, dataset)), data.to_vec()));
+            remain = &rest[length..];
+        }
+
+        let utf8 = datasets
+            .iter()
+            .any(|(tag, data)| *tag == IptcTag::CodedCharacterSet && data == UTF8_CHARACTER_SET);
+        Ok(Self { datasets, utf8 })
+    }
+
+    /// Get the typed value for the given tag if it exists
+    /// * Repeatable tags collect the text of every occurrence
+    pub(crate) fn get_field(&self, tag: IptcTag) -> Option<IptcField> {
+        let data: Vec<&[u8]> = self
+            .datasets
+            .iter()
+            .filter(|(x, _)| *x == tag)
+            .map(|(_, x)| x.as_slice())
+            .collect();
+        let first = *data.first()?;
+
+        let text = || self.to_text(first);
+        let texts = || data.iter().map(|x| self.to_text(x)).collect();
+        let u16 = || <[u8; 2]>::try_from(first).ok().map(u16::from_be_bytes);
+        let digit = || match first {
+            [x @ b'0'..=b'9'] => Some(x - b'0'),
+            _ => None,
+        };
+
+        match tag {
+            IptcTag::ModelVersion => u16().map(IptcField::ModelVersion),
+            IptcTag::Destination => Some(IptcField::Destination(texts())),
+            IptcTag::FileFormat => u16().map(IptcField::FileFormat),
+            IptcTag::FileFormatVersion => u16().map(IptcField::FileFormatVersion),
+            IptcTag::ServiceIdentifier => Some(IptcField::ServiceIdentifier(text())),
+            IptcTag::EnvelopeNumber => Some(IptcField::EnvelopeNumber(text())),
+            IptcTag::ProductId => Some(IptcField::ProductId(texts())),
+            IptcTag::EnvelopePriority => digit().map(IptcField::EnvelopePriority),
+            IptcTag::DateSent => Some(IptcField::DateSent(text())),
+            IptcTag::TimeSent => Some(IptcField::TimeSent(text())),
+            IptcTag::CodedCharacterSet => Some(IptcField::CodedCharacterSet(first.to_vec())),
+            IptcTag::UniqueObjectName => Some(IptcField::UniqueObjectName(text())),
+            IptcTag::ApplicationRecordVersion => u16().map(IptcField::ApplicationRecordVersion),
+            IptcTag::ObjectName => Some(IptcField::ObjectName(text())),
+            IptcTag::Urgency => digit().map(IptcField::Urgency),
+            IptcTag::Category => Some(IptcField::Category(text())),
+            IptcTag::SupplementalCategories => Some(IptcField::SupplementalCategories(texts())),
+            IptcTag::Keywords => Some(IptcField::Keywords(texts())),
+            IptcTag::SpecialInstructions => Some(IptcField::SpecialInstructions(text())),
+            IptcTag::DateCreated => Some(IptcField::DateCreated(text())),
+            IptcTag::TimeCreated => Some(IptcField::TimeCreated(text())),
+            IptcTag::DigitalCreationDate => Some(IptcField::DigitalCreationDate(text())),
+            IptcTag::DigitalCreationTime => Some(IptcField::DigitalCreationTime(text())),
+            IptcTag::OriginatingProgram => Some(IptcField::OriginatingProgram(text())),
+            IptcTag::ProgramVersion => Some(IptcField::ProgramVersion(text())),
+            IptcTag::Byline => Some(IptcField::Byline(texts())),
+            IptcTag::BylineTitle => Some(IptcField::BylineTitle(texts())),
+            IptcTag::City => Some(IptcField::City(text())),
+            IptcTag::SubLocation => Some(IptcField::SubLocation(text())),
+            IptcTag::ProvinceState => Some(IptcField::ProvinceState(text())),
+            IptcTag::CountryCode => Some(IptcField::CountryCode(text())),
+            IptcTag::CountryName => Some(IptcField::CountryName(text())),
+            IptcTag::OriginalTransmissionReference => {
+                Some(IptcField::OriginalTransmissionReference(text()))
+            }
+            IptcTag::Headline => Some(IptcField::Headline(text())),
+            IptcTag::Credit => Some(IptcField::Credit(text())),
+            IptcTag::Source => Some(IptcField::Source(text())),
+            IptcTag::CopyrightNotice => Some(IptcField::CopyrightNotice(text())),
+            IptcTag::Contact => Some(IptcField::Contact(texts())),
+            IptcTag::Caption => Some(IptcField::Caption(text())),
+            IptcTag::Writer => Some(IptcField::Writer(texts())),
+            IptcTag::Raw(record, dataset) => Some(IptcField::Raw(record, dataset, first.to_vec())),
+        }
+    }
+
+    /// Get the distinct tags in file order
+    pub(crate) fn tags(&self) -> Vec<IptcTag> {
+        let mut tags: Vec<IptcTag> = Vec::new();
+        for (tag, _) in &self.datasets {
+            if !tags.contains(tag) {
+                tags.push(*tag);
+            }
+        }
+        tags
+    }
+
+    // Decode text with the CodedCharacterSet dropping any null terminator
+    fn to_text(&self, data: &[u8]) -> String {
+        let text = match self.utf8 {
+            true => String::from_utf8_lossy(data).into_owned(),
+            false => data.iter().map(|x| *x as char).collect(),
+        };
+        text.trim_end_matches('\0').to_string()
+    }
+}
+
+impl Display for Iptc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for tag in self.tags() {
+            if let Some(field) = self.get_field(tag) {
+                writeln!(f, "  {: <32}: {}", tag.to_string(), field)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_utf8() {
+        // Marker, record:dataset and Big Endian size ahead of each value
+        let data = [
+            /* 000-004 */ 0x1c, 0x01, 0x5a, 0x00, 0x03, // 1:90 size (3)
+            /* 005-007 */ 0x1b, 0x25, 0x47, // Charset (UTF-8)
+            //
+            /* 008-012 */ 0x1c, 0x02, 0x00, 0x00, 0x02, // 2:0 size (2)
+            /* 013-014 */ 0x00, 0x04, // Record version (4)
+            //
+            /* 015-019 */ 0x1c, 0x02, 0x19, 0x00, 0x07, // 2:25 size (7)
+            /* 020-026 */ 0x5a, 0xc3, 0xbc, 0x72, 0x69, 0x63, 0x68, // Keywords (Zürich)
+            /* 027-031 */ 0x1c, 0x02, 0x19, 0x00, 0x06, // 2:25 size (6)
+            /* 032-037 */ 0x68, 0x61, 0x72, 0x62, 0x6f, 0x72, // Keywords (harbor)
+            //
+            /* 038-042 */ 0x1c, 0x02, 0x50, 0x00, 0x08, // 2:80 size (8)
+            /* 043-050 */ 0x4a, 0x61, 0x6e, 0x65, 0x20, 0x44, 0x6f, 0x65, // By-line
+            //
+            /* 051-055 */ 0x1c, 0x02, 0x5a, 0x00, 0x07, // 2:90 size (7)
+            /* 056-062 */ 0x5a, 0xc3, 0xbc, 0x72, 0x69, 0x63, 0x68, // City (Zürich)
+            //
+            /* 063-067 */ 0x1c, 0x02, 0x0a, 0x00, 0x01, // 2:10 size (1)
+            /*     068 */ 0x35, // Urgency (5)
+            //
+            /* 069-073 */ 0x1c, 0x02, 0x78, 0x00, 0x16, // 2:120 size (22)
+            /* 074-081 */ 0x53, 0x74, 0x6f, 0x72, 0x6d, 0x20, 0x6f, 0x76, // Caption
+            /* 082-089 */ 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, //
+            /* 090-095 */ 0x61, 0x72, 0x62, 0x6f, 0x72, 0x00, //
+        ];
+        let iptc = Iptc::parse(&data).unwrap();
+        assert_eq!(
+            iptc.get_field(IptcTag::Keywords),
+            Some(IptcField::Keywords(vec!["Zürich".into(), "harbor".into()]))
+        );
+        assert_eq!(
+            iptc.get_field(IptcTag::Byline),
+            Some(IptcField::Byline(vec!["Jane Doe".into()]))
+        );
+        assert_eq!(iptc.get_field(IptcTag::City), Some(IptcField::City("Zürich".into())));
+        assert_eq!(iptc.get_field(IptcTag::Urgency), Some(IptcField::Urgency(5)));
+        assert_eq!(
+            iptc.get_field(IptcTag::ApplicationRecordVersion),
+            Some(IptcField::ApplicationRecordVersion(4))
+        );
+        assert_eq!(
+            iptc.get_field(IptcTag::Caption),
+            Some(IptcField::Caption("Storm over the harbor".into()))
+        );
+        assert_eq!(iptc.get_field(IptcTag::Headline), None);
+        assert_eq!(iptc.tags().len(), 7);
+    }
+
+    #[test]
+    fn test_parse_latin1() {
+        // Without the UTF-8 escape sequence text is Latin-1
+        let data = [
+            /* 000-004 */ 0x1c, 0x02, 0x5a, 0x00, 0x06, // 2:90 size (6)
+            /* 005-010 */ 0x5a, 0xfc, 0x72, 0x69, 0x63, 0x68, // City (Zürich)
+            /* 011-015 */ 0x1c, 0x02, 0xc8, 0x00, 0x01, // 2:200 size (1)
+            /*     016 */ 0x01, // Unknown
+        ];
+        let iptc = Iptc::parse(&data).unwrap();
+        assert_eq!(iptc.get_field(IptcTag::City), Some(IptcField::City("Zürich".into())));
+        assert_eq!(iptc.get_field(IptcTag::Raw(2, 200)), Some(IptcField::Raw(2, 200, vec![0x01])));
+    }
+
+    #[test]
+    fn test_parse_extended_length_and_padding() {
+        let mut data = vec![TAG_MARKER, 2, 120, 0x80, 0x02, 0x00, 0x03];
+        data.extend(b"Fog");
+        data.extend([0x00, 0x00]);
+        let iptc = Iptc::parse(&data).unwrap();
+        assert_eq!(iptc.get_field(IptcTag::Caption), Some(IptcField::Caption("Fog".into())));
+        assert_eq!(iptc.to_string(), format!("  {: <32}: Fog\n", "Caption-Abstract"));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let err = Iptc::parse(&[TAG_MARKER, 2, 120, 0x00, 0x05, b'a']).unwrap_err();
+        assert_eq!(err.to_string(), "IPTC parse failed: dataset truncated [02, 78]");
+        let err = Iptc::parse(&[TAG_MARKER, 2]).unwrap_err();
+        assert_eq!(err.to_string(), "IPTC parse failed: dataset header truncated");
+        let err = Iptc::parse(&[0xFF]).unwrap_err();
+        assert_eq!(err.to_string(), "IPTC parse failed: invalid tag marker [ff]");
+        let err = Iptc::parse(&[TAG_MARKER, 2, 120, 0x80, 0x05]).unwrap_err();
+        assert_eq!(err.to_string(), "IPTC parse failed: invalid extended length [02, 78]");
+    }
+}
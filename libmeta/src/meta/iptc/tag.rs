@@ -0,0 +1,344 @@
+// Exiftool IPTC definitions are an invaluable source
+// https://exiftool.org/TagNames/IPTC.html
+
+use std::fmt::Display;
+
+/// IPTC-IIM dataset identified by its record and dataset number e.g. `2:120` for the caption
+/// * Record 1 is the envelope record and record 2 the application record
+/// * Repeatable datasets may occur many times e.g. one `Keywords` dataset per keyword
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IptcTag {
+    /// Version of the envelope record
+    /// * **Record**: 1:0
+    /// * **Format**: u16
+    ModelVersion,
+
+    /// Routing destinations of the object
+    /// * **Record**: 1:5, repeatable
+    /// * **Format**: text
+    Destination,
+
+    /// File format of the object data
+    /// * **Record**: 1:20
+    /// * **Format**: u16
+    FileFormat,
+
+    /// Version of the file format
+    /// * **Record**: 1:22
+    /// * **Format**: u16
+    FileFormatVersion,
+
+    /// Provider and product identifier
+    /// * **Record**: 1:30
+    /// * **Format**: text
+    ServiceIdentifier,
+
+    /// Number unique for the date sent and service identifier
+    /// * **Record**: 1:40
+    /// * **Format**: text
+    EnvelopeNumber,
+
+    /// Subsets of the service the object is provided to
+    /// * **Record**: 1:50, repeatable
+    /// * **Format**: text
+    ProductId,
+
+    /// Envelope handling priority
+    /// * 1 = most urgent, 5 = normal, 8 = least urgent, 9 = user defined
+    /// * **Record**: 1:60
+    /// * **Format**: digit
+    EnvelopePriority,
+
+    /// Date the service sent the object
+    /// * **Record**: 1:70
+    /// * **Format**: text `CCYYMMDD`
+    DateSent,
+
+    /// Time the service sent the object
+    /// * **Record**: 1:80
+    /// * **Format**: text `HHMMSS±HHMM`
+    TimeSent,
+
+    /// Character set of the text datasets
+    /// * `ESC % G` = UTF-8, anything else is treated as Latin-1
+    /// * **Record**: 1:90
+    /// * **Format**: bytes
+    CodedCharacterSet,
+
+    /// Globally unique identifier of the object
+    /// * **Record**: 1:100
+    /// * **Format**: text
+    UniqueObjectName,
+
+    /// Version of the application record
+    /// * **Record**: 2:0
+    /// * **Format**: u16
+    ApplicationRecordVersion,
+
+    /// Shorthand reference for the object
+    /// * **Record**: 2:5
+    /// * **Format**: text
+    ObjectName,
+
+    /// Editorial urgency
+    /// * 1 = most urgent, 5 = normal, 8 = least urgent
+    /// * **Record**: 2:10
+    /// * **Format**: digit
+    Urgency,
+
+    /// Subject category
+    /// * **Record**: 2:15
+    /// * **Format**: text
+    Category,
+
+    /// Supplemental subject categories
+    /// * **Record**: 2:20, repeatable
+    /// * **Format**: text
+    SupplementalCategories,
+
+    /// Keywords describing the object
+    /// * **Record**: 2:25, repeatable
+    /// * **Format**: text
+    Keywords,
+
+    /// Editorial instructions e.g. embargoes
+    /// * **Record**: 2:40
+    /// * **Format**: text
+    SpecialInstructions,
+
+    /// Date the intellectual content was created
+    /// * **Record**: 2:55
+    /// * **Format**: text `CCYYMMDD`
+    DateCreated,
+
+    /// Time the intellectual content was created
+    /// * **Record**: 2:60
+    /// * **Format**: text `HHMMSS±HHMM`
+    TimeCreated,
+
+    /// Date the digital representation was created
+    /// * **Record**: 2:62
+    /// * **Format**: text `CCYYMMDD`
+    DigitalCreationDate,
+
+    /// Time the digital representation was created
+    /// * **Record**: 2:63
+    /// * **Format**: text `HHMMSS±HHMM`
+    DigitalCreationTime,
+
+    /// Program used to create the object
+    /// * **Record**: 2:65
+    /// * **Format**: text
+    OriginatingProgram,
+
+    /// Version of the originating program
+    /// * **Record**: 2:70
+    /// * **Format**: text
+    ProgramVersion,
+
+    /// Creators of the object e.g. the photographer
+    /// * **Record**: 2:80, repeatable
+    /// * **Format**: text
+    Byline,
+
+    /// Titles of the creators
+    /// * **Record**: 2:85, repeatable
+    /// * **Format**: text
+    BylineTitle,
+
+    /// City of origin
+    /// * **Record**: 2:90
+    /// * **Format**: text
+    City,
+
+    /// Location within the city of origin
+    /// * **Record**: 2:92
+    /// * **Format**: text
+    SubLocation,
+
+    /// Province or state of origin
+    /// * **Record**: 2:95
+    /// * **Format**: text
+    ProvinceState,
+
+    /// ISO 3166 country code of origin
+    /// * **Record**: 2:100
+    /// * **Format**: text
+    CountryCode,
+
+    /// Country name of origin
+    /// * **Record**: 2:101
+    /// * **Format**: text
+    CountryName,
+
+    /// Job identifier for transmission routing
+    /// * **Record**: 2:103
+    /// * **Format**: text
+    OriginalTransmissionReference,
+
+    /// Synopsis of the content
+    /// * **Record**: 2:105
+    /// * **Format**: text
+    Headline,
+
+    /// Provider of the object
+    /// * **Record**: 2:110
+    /// * **Format**: text
+    Credit,
+
+    /// Original owner of the intellectual content
+    /// * **Record**: 2:115
+    /// * **Format**: text
+    Source,
+
+    /// Copyright notice
+    /// * **Record**: 2:116
+    /// * **Format**: text
+    CopyrightNotice,
+
+    /// Contacts for further information
+    /// * **Record**: 2:118, repeatable
+    /// * **Format**: text
+    Contact,
+
+    /// Textual description of the object i.e. the caption
+    /// * **Record**: 2:120
+    /// * **Format**: text
+    Caption,
+
+    /// Writers or editors of the caption
+    /// * **Record**: 2:122, repeatable
+    /// * **Format**: text
+    Writer,
+
+    /// Unknown dataset as (record, dataset)
+    Raw(u8, u8),
+}
+
+impl IptcTag {
+    /// Check if the dataset may occur more than once
+    pub fn is_repeatable(&self) -> bool {
+        matches!(
+            self,
+            IptcTag::Destination
+                | IptcTag::ProductId
+                | IptcTag::SupplementalCategories
+                | IptcTag::Keywords
+                | IptcTag::Byline
+                | IptcTag::BylineTitle
+                | IptcTag::Contact
+                | IptcTag::Writer
+        )
+    }
+}
+
+impl From<(u8, u8)> for IptcTag {
+    fn from(val: (u8, u8)) -> Self {
+        match val {
+            (1, 0) => IptcTag::ModelVersion,
+            (1, 5) => IptcTag::Destination,
+            (1, 20) => IptcTag::FileFormat,
+            (1, 22) => IptcTag::FileFormatVersion,
+            (1, 30) => IptcTag::ServiceIdentifier,
+            (1, 40) => IptcTag::EnvelopeNumber,
+            (1, 50) => IptcTag::ProductId,
+            (1, 60) => IptcTag::EnvelopePriority,
+            (1, 70) => IptcTag::DateSent,
+            (1, 80) => IptcTag::TimeSent,
+            (1, 90) => IptcTag::CodedCharacterSet,
+            (1, 100) => IptcTag::UniqueObjectName,
+            (2, 0) => IptcTag::ApplicationRecordVersion,
+            (2, 5) => IptcTag::ObjectName,
+            (2, 10) => IptcTag::Urgency,
+            (2, 15) => IptcTag::Category,
+            (2, 20) => IptcTag::SupplementalCategories,
+            (2, 25) => IptcTag::Keywords,
+            (2, 40) => IptcTag::SpecialInstructions,
+            (2, 55) => IptcTag::DateCreated,
+            (2, 60) => IptcTag::TimeCreated,
+            (2, 62) => IptcTag::DigitalCreationDate,
+            (2, 63) => IptcTag::DigitalCreationTime,
+            (2, 65) => IptcTag::OriginatingProgram,
+            (2, 70) => IptcTag::ProgramVersion,
+            (2, 80) => IptcTag::Byline,
+            (2, 85) => IptcTag::BylineTitle,
+            (2, 90) => IptcTag::City,
+            (2, 92) => IptcTag::SubLocation,
+            (2, 95) => IptcTag::ProvinceState,
+            (2, 100) => IptcTag::CountryCode,
+            (2, 101) => IptcTag::CountryName,
+            (2, 103) => IptcTag::OriginalTransmissionReference,
+            (2, 105) => IptcTag::Headline,
+            (2, 110) => IptcTag::Credit,
+            (2, 115) => IptcTag::Source,
+            (2, 116) => IptcTag::CopyrightNotice,
+            (2, 118) => IptcTag::Contact,
+            (2, 120) => IptcTag::Caption,
+            (2, 122) => IptcTag::Writer,
+            (record, dataset) => IptcTag::Raw(record, dataset),
+        }
+    }
+}
+
+impl Display for IptcTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IptcTag::ModelVersion => write!(f, "Envelope Record Version"),
+            IptcTag::Destination => write!(f, "Destination"),
+            IptcTag::FileFormat => write!(f, "File Format"),
+            IptcTag::FileFormatVersion => write!(f, "File Version"),
+            IptcTag::ServiceIdentifier => write!(f, "Service Identifier"),
+            IptcTag::EnvelopeNumber => write!(f, "Envelope Number"),
+            IptcTag::ProductId => write!(f, "Product ID"),
+            IptcTag::EnvelopePriority => write!(f, "Envelope Priority"),
+            IptcTag::DateSent => write!(f, "Date Sent"),
+            IptcTag::TimeSent => write!(f, "Time Sent"),
+            IptcTag::CodedCharacterSet => write!(f, "Coded Character Set"),
+            IptcTag::UniqueObjectName => write!(f, "Unique Object Name"),
+            IptcTag::ApplicationRecordVersion => write!(f, "Application Record Version"),
+            IptcTag::ObjectName => write!(f, "Object Name"),
+            IptcTag::Urgency => write!(f, "Urgency"),
+            IptcTag::Category => write!(f, "Category"),
+            IptcTag::SupplementalCategories => write!(f, "Supplemental Categories"),
+            IptcTag::Keywords => write!(f, "Keywords"),
+            IptcTag::SpecialInstructions => write!(f, "Special Instructions"),
+            IptcTag::DateCreated => write!(f, "Date Created"),
+            IptcTag::TimeCreated => write!(f, "Time Created"),
+            IptcTag::DigitalCreationDate => write!(f, "Digital Creation Date"),
+            IptcTag::DigitalCreationTime => write!(f, "Digital Creation Time"),
+            IptcTag::OriginatingProgram => write!(f, "Originating Program"),
+            IptcTag::ProgramVersion => write!(f, "Program Version"),
+            IptcTag::Byline => write!(f, "By-line"),
+            IptcTag::BylineTitle => write!(f, "By-line Title"),
+            IptcTag::City => write!(f, "City"),
+            IptcTag::SubLocation => write!(f, "Sub-location"),
+            IptcTag::ProvinceState => write!(f, "Province-State"),
+            IptcTag::CountryCode => write!(f, "Country-Primary Location Code"),
+            IptcTag::CountryName => write!(f, "Country-Primary Location Name"),
+            IptcTag::OriginalTransmissionReference => write!(f, "Original Transmission Reference"),
+            IptcTag::Headline => write!(f, "Headline"),
+            IptcTag::Credit => write!(f, "Credit"),
+            IptcTag::Source => write!(f, "Source"),
+            IptcTag::CopyrightNotice => write!(f, "Copyright Notice"),
+            IptcTag::Contact => write!(f, "Contact"),
+            IptcTag::Caption => write!(f, "Caption-Abstract"),
+            IptcTag::Writer => write!(f, "Writer-Editor"),
+            IptcTag::Raw(record, dataset) => write!(f, "Unknown({}:{})", record, dataset),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_record_and_dataset() {
+        assert_eq!(IptcTag::from((2, 120)), IptcTag::Caption);
+        assert_eq!(IptcTag::from((1, 90)), IptcTag::CodedCharacterSet);
+        assert_eq!(IptcTag::from((2, 200)), IptcTag::Raw(2, 200));
+        assert_eq!(IptcTag::from((2, 200)).to_string(), "Unknown(2:200)");
+        assert!(IptcTag::Keywords.is_repeatable());
+        assert!(!IptcTag::Caption.is_repeatable());
+    }
+}
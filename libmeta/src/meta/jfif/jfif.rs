@@ -1,21 +1,22 @@
 use nom::number::streaming as nom_nums;
 use std::fmt::Display;
 
-use super::density::DensityUnit;
+use super::{density::DensityUnit, jfxx::JfifThumbnail};
 use crate::errors::JfifError;
 
 const JFIF_IDENTIFIER: [u8; 4] = [0x4A, 0x46, 0x49, 0x46];
 
 #[derive(Debug)]
 pub struct Jfif {
-    pub(crate) major: u8,                  // major version
-    pub(crate) minor: u8,                  // minor version
-    pub(crate) density: DensityUnit,       // density unit
-    pub(crate) x_density: u16,             // horizontal pixel density
-    pub(crate) y_density: u16,             // vertical pixel density
-    pub(crate) x_dimension: u8,            // horizontal pixels of the embedded RGB thumbnail
-    pub(crate) y_dimension: u8,            // vertical pixels of the embedded RGB thumbnail
-    pub(crate) thumbnail: Option<Vec<u8>>, // uncompressed 24 bit RGB raster thumbnail
+    pub(crate) major: u8,                        // major version
+    pub(crate) minor: u8,                        // minor version
+    pub(crate) density: DensityUnit,             // density unit
+    pub(crate) x_density: u16,                   // horizontal pixel density
+    pub(crate) y_density: u16,                   // vertical pixel density
+    pub(crate) x_dimension: u8,                  // horizontal pixels of the embedded RGB thumbnail
+    pub(crate) y_dimension: u8,                  // vertical pixels of the embedded RGB thumbnail
+    pub(crate) thumbnail: Option<Vec<u8>>,       // uncompressed 24 bit RGB raster thumbnail
+    pub(crate) extension: Option<JfifThumbnail>, // JFXX extension thumbnail
 }
 
 impl Jfif {
@@ -37,11 +38,11 @@ impl Jfif {
 
         let (remain, x_dimension, y_dimension) = parse_thumbnail_dimensions(remain)?;
 
-        // Check if a thumbnail was included
-        if x_dimension != 0 && y_dimension != 0 {
-            // TODO: Parse the thumbnail data
-            Err(JfifError::parse(": thumbnail invalid").with_data(remain))?;
-        }
+        // Check if a thumbnail was included, a truncated raster only loses the thumbnail
+        let thumbnail = match x_dimension != 0 && y_dimension != 0 {
+            true => parse_thumbnail(remain, x_dimension, y_dimension).ok(),
+            false => None,
+        };
 
         Ok(Self {
             major: major,
@@ -51,9 +52,23 @@ impl Jfif {
             y_density: y_density,
             x_dimension: x_dimension,
             y_dimension: y_dimension,
-            thumbnail: None,
+            thumbnail,
+            extension: None,
         })
     }
+
+    /// Get the thumbnail preferring the JFXX extension over the JFIF RGB raster
+    pub(crate) fn thumbnail(&self) -> Option<JfifThumbnail> {
+        match (&self.extension, &self.thumbnail) {
+            (Some(extension), _) => Some(extension.clone()),
+            (None, Some(data)) => Some(JfifThumbnail::Rgb {
+                width: self.x_dimension,
+                height: self.y_dimension,
+                data: data.clone(),
+            }),
+            _ => None,
+        }
+    }
 }
 
 impl Display for Jfif {
@@ -104,6 +119,15 @@ fn parse_thumbnail_dimensions(input: &[u8]) -> Result<(&[u8], u8, u8), JfifError
     Ok((remain, x_thumbnail, y_thumbnail))
 }
 
+// Parse the JFIF uncompressed 24 bit RGB raster thumbnail
+fn parse_thumbnail(input: &[u8], x_dimension: u8, y_dimension: u8) -> Result<Vec<u8>, JfifError> {
+    let len = 3 * x_dimension as usize * y_dimension as usize;
+    let (_, data) =
+        nom::bytes::streaming::take::<usize, &[u8], nom::error::Error<&[u8]>>(len)(input)
+            .map_err(|x| JfifError::parse(": thumbnail invalid").with_nom_source(x))?;
+    Ok(data.to_vec())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -133,6 +157,29 @@ mod tests {
         assert_eq!(jfif.y_dimension, 0);
     }
 
+    #[test]
+    fn test_parse_jfif_thumbnail() {
+        let mut data = JFIF_DATA_1[4..16].to_vec();
+        data.extend_from_slice(&[0x02, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+        let jfif = Jfif::parse(&data).unwrap();
+        assert_eq!(jfif.thumbnail, Some(vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06]));
+        assert_eq!(
+            jfif.thumbnail(),
+            Some(JfifThumbnail::Rgb { width: 2, height: 1, data: data[14..].to_vec() })
+        );
+
+        // Truncated raster keeps the rest of the JFIF meta data
+        let jfif = Jfif::parse(&data[..19]).unwrap();
+        assert_eq!((jfif.x_density, jfif.x_dimension, jfif.y_dimension), (72, 2, 1));
+        assert_eq!(jfif.thumbnail(), None);
+
+        let err = parse_thumbnail(&data[14..19], 2, 1).unwrap_err();
+        assert_eq!(
+            err.all_to_string(),
+            "JFIF parse failed: thumbnail invalid ==> nom::Parsing requires 1 bytes/chars"
+        );
+    }
+
     #[test]
     fn test_parse_jfif_thumbnail_dimensions_not_enough_data() {
         let err = parse_thumbnail_dimensions(&[]).unwrap_err();
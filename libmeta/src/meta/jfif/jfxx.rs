@@ -0,0 +1,163 @@
+use nom::number::streaming as nom_nums;
+
+use crate::errors::JfifError;
+
+const JFXX_IDENTIFIER: [u8; 4] = [0x4A, 0x46, 0x58, 0x58];
+const JFXX_JPEG: u8 = 0x10; // Thumbnail coded using JPEG
+const JFXX_PALETTE: u8 = 0x11; // Thumbnail stored using 1 byte/pixel
+const JFXX_RGB: u8 = 0x13; // Thumbnail stored using 3 bytes/pixel
+const PALETTE_SIZE: usize = 256 * 3;
+
+/// JFIF thumbnail from either the JFIF APP0 segment or a JFXX extension APP0 segment
+#[derive(Debug, Clone, PartialEq)]
+pub enum JfifThumbnail {
+    /// Complete JPEG stream
+    Jpeg(Vec<u8>),
+    /// 1 byte/pixel indices into a 256 entry 24 bit RGB palette
+    Palette {
+        width: u8,
+        height: u8,
+        palette: Vec<u8>,
+        indices: Vec<u8>,
+    },
+    /// Uncompressed 24 bit RGB raster
+    Rgb {
+        width: u8,
+        height: u8,
+        data: Vec<u8>,
+    },
+}
+
+impl JfifThumbnail {
+    /// Parse the given JFXX extension data into a thumbnail
+    /// * **Field**        | **Bytes** | **Description**
+    /// * *Identifier*     | 5     | `0x4a 0x46 0x58 0x58 0x00` = `JFXX` in ASCII terminated by a null byte
+    /// * *Extension code* | 1     | `0x10` = JPEG, `0x11` = 1 byte/pixel palette, `0x13` = 3 bytes/pixel RGB
+    /// * *Extension data* | n     | JPEG stream or `Xthumbnail`, `Ythumbnail`, optional palette and pixels
+    pub(crate) fn parse(input: &[u8]) -> Result<Self, JfifError> {
+        let remain = parse_header(input)?;
+        let (remain, code) = nom_nums::u8::<&[u8], nom::error::Error<&[u8]>>(remain)
+            .map_err(|x| JfifError::parse(": extension code invalid").with_nom_source(x))?;
+
+        match code {
+            JFXX_JPEG => Ok(Self::Jpeg(remain.to_vec())),
+            JFXX_PALETTE => {
+                let (remain, width, height) = parse_dimensions(remain)?;
+                let (remain, palette) = take(remain, PALETTE_SIZE, ": palette invalid")?;
+                let (_, indices) =
+                    take(remain, width as usize * height as usize, ": pixels invalid")?;
+                Ok(Self::Palette { width, height, palette, indices })
+            }
+            JFXX_RGB => {
+                let (remain, width, height) = parse_dimensions(remain)?;
+                let (_, data) =
+                    take(remain, 3 * width as usize * height as usize, ": pixels invalid")?;
+                Ok(Self::Rgb { width, height, data })
+            }
+            _ => Err(JfifError::parse(": extension code unknown").with_data(&[code])),
+        }
+    }
+
+    /// Get the thumbnail as a 24 bit RGB raster expanding palette indices as needed
+    /// * JPEG thumbnails must be decoded by the caller so `None` is returned
+    pub fn to_rgb(&self) -> Option<Vec<u8>> {
+        match self {
+            Self::Jpeg(_) => None,
+            Self::Palette { palette, indices, .. } => Some(
+                indices
+                    .iter()
+                    .flat_map(|x| {
+                        palette[*x as usize * 3..*x as usize * 3 + 3]
+                            .iter()
+                            .copied()
+                    })
+                    .collect(),
+            ),
+            Self::Rgb { data, .. } => Some(data.clone()),
+        }
+    }
+}
+
+// Determine if the given APP0 data is a JFXX extension segment
+pub(crate) fn is_jfxx(data: &[u8]) -> bool {
+    data.starts_with(&JFXX_IDENTIFIER) && data.get(4) == Some(&0x00)
+}
+
+// Parse the JFXX identifier
+fn parse_header(input: &[u8]) -> Result<&[u8], JfifError> {
+    let (remain, _) = nom::sequence::terminated(
+        nom::bytes::streaming::tag::<[u8; 4], &[u8], nom::error::Error<&[u8]>>(JFXX_IDENTIFIER),
+        nom::bytes::streaming::tag::<[u8; 1], &[u8], nom::error::Error<&[u8]>>([0x00]),
+    )(input)
+    .map_err(|x| JfifError::parse(": extension identifier invalid").with_nom_source(x))?;
+    Ok(remain)
+}
+
+// Parse the JFXX thumbnail dimensions
+fn parse_dimensions(input: &[u8]) -> Result<(&[u8], u8, u8), JfifError> {
+    let (remain, (width, height)) = nom::sequence::tuple((nom_nums::u8, nom_nums::u8))(input)
+        .map_err(|x| JfifError::parse(": thumbnail dimensions invalid").with_nom_source(x))?;
+    Ok((remain, width, height))
+}
+
+// Take the given number of bytes
+fn take<'a>(input: &'a [u8], len: usize, msg: &str) -> Result<(&'a [u8], Vec<u8>), JfifError> {
+    let (remain, data) =
+        nom::bytes::streaming::take::<usize, &[u8], nom::error::Error<&[u8]>>(len)(input)
+            .map_err(|x| JfifError::parse(msg).with_nom_source(x))?;
+    Ok((remain, data.to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::BaseError;
+
+    #[test]
+    fn test_parse_jpeg() {
+        let data = [0x4a, 0x46, 0x58, 0x58, 0x00, 0x10, 0xff, 0xd8, 0xff, 0xd9];
+        assert!(is_jfxx(&data));
+        assert_eq!(
+            JfifThumbnail::parse(&data).unwrap(),
+            JfifThumbnail::Jpeg(vec![0xff, 0xd8, 0xff, 0xd9])
+        );
+    }
+
+    #[test]
+    fn test_parse_palette() {
+        let mut data = vec![0x4a, 0x46, 0x58, 0x58, 0x00, 0x11, 0x02, 0x01];
+        let mut palette = vec![0x00; PALETTE_SIZE];
+        palette[3..6].copy_from_slice(&[0x01, 0x02, 0x03]);
+        data.extend_from_slice(&palette);
+        data.extend_from_slice(&[0x01, 0x00]);
+
+        let thumbnail = JfifThumbnail::parse(&data).unwrap();
+        assert_eq!(thumbnail.to_rgb(), Some(vec![0x01, 0x02, 0x03, 0x00, 0x00, 0x00]));
+    }
+
+    #[test]
+    fn test_parse_rgb() {
+        let data = [
+            0x4a, 0x46, 0x58, 0x58, 0x00, 0x13, 0x01, 0x01, 0x0a, 0x0b, 0x0c,
+        ];
+        assert_eq!(
+            JfifThumbnail::parse(&data).unwrap(),
+            JfifThumbnail::Rgb { width: 1, height: 1, data: vec![0x0a, 0x0b, 0x0c] }
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let data = [0x4a, 0x46, 0x58, 0x58, 0x00, 0x12];
+        assert_eq!(
+            JfifThumbnail::parse(&data).unwrap_err().to_string(),
+            "JFIF parse failed: extension code unknown [12]"
+        );
+
+        let data = [0x4a, 0x46, 0x58, 0x58, 0x00, 0x13, 0x01, 0x01, 0x0a];
+        assert_eq!(
+            JfifThumbnail::parse(&data).unwrap_err().all_to_string(),
+            "JFIF parse failed: pixels invalid ==> nom::Parsing requires 2 bytes/chars"
+        );
+    }
+}
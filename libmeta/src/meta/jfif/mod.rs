@@ -1,5 +1,8 @@
 mod density;
 mod jfif;
+mod jfxx;
 
-pub(crate) use density::*;
+pub use density::DensityUnit;
 pub(crate) use jfif::*;
+pub(crate) use jfxx::is_jfxx;
+pub use jfxx::JfifThumbnail;
@@ -2,14 +2,18 @@ use std::{
     cell::RefCell,
     fmt::Display,
     io::{self, Read},
+    time::Duration,
 };
 
 use crate::{
-    container::{Container, Jpeg},
+    container::{Container, Frame, Gif, Heif, Jpeg, Png, Text, Tiff, WebP},
     errors::MetaError,
 };
 
-use super::{Tag, Exif, Field, Jfif};
+use super::{
+    xmp, CaptureTime, Exif, Field, GpsPosition, IccProfile, Iptc, IptcField, IptcTag, Jfif,
+    JfifThumbnail, Tag, Vendor, Xmp,
+};
 
 /// Simplify the Exif return type slightly
 pub type MetaResult<T> = Result<T, MetaError>;
@@ -22,45 +26,252 @@ pub struct Meta {
     container: Option<Container>,
     jfif: RefCell<Option<Jfif>>,
     exif: RefCell<Option<Exif>>,
+    xmp: RefCell<Option<Xmp>>,
+    iptc: RefCell<Option<Iptc>>,
 }
 
 impl Meta {
+    /// Get the typed value for the given tag if it exists
     pub fn get_field(&self, tag: Tag) -> Option<Field> {
-        Some(Field::ImageWidth(10u32))
+        self.exif
+            .borrow()
+            .as_ref()
+            .and_then(|exif| exif.get_field(tag))
+    }
+
+    /// Get the number of pages i.e. images in the IFD0 -> IFD1 -> ... chain
+    /// * For JPEG sources the second page is the Exif thumbnail
+    pub fn page_count(&self) -> usize {
+        self.exif
+            .borrow()
+            .as_ref()
+            .map(|exif| exif.page_count())
+            .unwrap_or(0)
+    }
+
+    /// Get the typed value for the given tag from the given page if it exists
+    /// * Sub IFDs e.g. Exif referenced by the page are searched as well
+    pub fn get_page_field(&self, page: usize, tag: Tag) -> Option<Field> {
+        self.exif
+            .borrow()
+            .as_ref()
+            .and_then(|exif| exif.get_page_field(page, tag))
+    }
+
+    /// Get the tags whose format or component count break the EXIF spec
+    /// * Useful for flagging files written by non-conforming software
+    pub fn nonconforming_tags(&self) -> Vec<Tag> {
+        self.exif
+            .borrow()
+            .as_ref()
+            .map(|exif| exif.nonconforming())
+            .unwrap_or_default()
+    }
+
+    /// Get the camera vendor whose MakerNote was parsed
+    /// * Vendor tags e.g. `Tag::NikonShutterCount` are then available via `get_field`
+    pub fn maker_note_vendor(&self) -> Option<Vendor> {
+        self.exif.borrow().as_ref().and_then(|exif| exif.vendor())
+    }
+
+    /// Get the image dimensions i.e. (width, height) if known
+    /// * Prefers the container's own record falling back on the Exif dimensions
+    pub fn dimensions(&self) -> Option<(u32, u32)> {
+        if let Some(dimensions) = self.container.as_ref().and_then(|x| x.dimensions()) {
+            return Some(dimensions);
+        }
+        let width = match self.get_page_field(0, Tag::ImageWidth) {
+            Some(Field::ImageWidth(width)) => width,
+            _ => match self.get_page_field(0, Tag::ExifImageWidth) {
+                Some(Field::ExifImageWidth(width)) => width,
+                _ => return None,
+            },
+        };
+        let height = match self.get_page_field(0, Tag::ImageHeight) {
+            Some(Field::ImageHeight(height)) => height,
+            _ => match self.get_page_field(0, Tag::ExifImageHeight) {
+                Some(Field::ExifImageHeight(height)) => height,
+                _ => return None,
+            },
+        };
+        Some((width, height))
+    }
+
+    /// Get the raw XMP packet if the container stores one directly e.g. the JPEG XMP APP1 segment,
+    /// the WebP `XMP ` chunk, the GIF `XMP DataXMP` application extension or the HEIF
+    /// `application/rdf+xml` item
+    pub fn xmp_packet(&self) -> Option<&[u8]> {
+        self.container.as_ref().and_then(|x| x.xmp_packet())
+    }
+
+    /// Get the XMP properties parsed from the XMP packet e.g. ratings, keywords and titles written
+    /// by Lightroom or darktable
+    pub fn xmp(&self) -> Option<Xmp> {
+        self.xmp.borrow().clone()
+    }
+
+    /// Get the typed IPTC value for the given tag if it exists
+    /// * Repeatable tags e.g. `Keywords` hold every occurrence
+    pub fn get_iptc_field(&self, tag: IptcTag) -> Option<IptcField> {
+        self.iptc
+            .borrow()
+            .as_ref()
+            .and_then(|iptc| iptc.get_field(tag))
+    }
+
+    /// Get the media container for format specific details e.g. WebP animation and alpha flags
+    pub fn container(&self) -> Option<&Container> {
+        self.container.as_ref()
+    }
+
+    /// Get the number of image frames for formats that record them e.g. GIF
+    pub fn frame_count(&self) -> Option<usize> {
+        match &self.container {
+            Some(Container::Gif(gif)) => Some(gif.frame_count()),
+            _ => None,
+        }
+    }
+
+    /// Get the total animation duration for formats that record frame delays e.g. GIF
+    pub fn duration(&self) -> Option<Duration> {
+        match &self.container {
+            Some(Container::Gif(gif)) => Some(gif.duration()),
+            _ => None,
+        }
+    }
+
+    /// Get the JPEG frame header e.g. encoding, precision, component count and sampling factors
+    pub fn frame_header(&self) -> Option<&Frame> {
+        match &self.container {
+            Some(Container::Jpeg(jpeg)) => jpeg.frame(),
+            _ => None,
+        }
+    }
+
+    /// Get the GPS position in signed decimal degrees with the altitude in metres if it exists
+    pub fn gps_position(&self) -> Option<GpsPosition> {
+        self.exif
+            .borrow()
+            .as_ref()
+            .and_then(|exif| exif.gps_position())
+    }
+
+    /// Get the capture time with the UTC offset when known and the tag it was read from
+    /// * Prefers DateTimeOriginal then DateTimeDigitized, DateTime and the GPS time stamp
+    pub fn capture_time(&self) -> Option<CaptureTime> {
+        self.exif
+            .borrow()
+            .as_ref()
+            .and_then(|exif| exif.capture_time())
+    }
+
+    /// Get the Exif IFD1 JPEG thumbnail bytes validated to start with `FFD8` and end with `FFD9`
+    pub fn thumbnail(&self) -> Option<Vec<u8>> {
+        self.exif
+            .borrow()
+            .as_ref()
+            .and_then(|exif| exif.thumbnail().map(|x| x.to_vec()))
+    }
+
+    /// Get the JFIF thumbnail preferring a JFXX extension over the JFIF RGB raster
+    /// * Palette thumbnails can be expanded with [`JfifThumbnail::to_rgb`]
+    pub fn jfif_thumbnail(&self) -> Option<JfifThumbnail> {
+        self.jfif
+            .borrow()
+            .as_ref()
+            .and_then(|jfif| jfif.thumbnail())
+    }
+
+    /// Get the text meta data e.g. PNG tEXt, zTXt and iTXt chunks or GIF comments
+    pub fn text(&self) -> &[Text] {
+        match &self.container {
+            Some(Container::Png(png)) => png.text(),
+            Some(Container::Gif(gif)) => gif.text(),
+            _ => &[],
+        }
+    }
+
+    /// Decode the embedded ICC profile if it exists
+    /// * Files without a profile are usually meant to be interpreted as sRGB
+    /// * Decoded on demand so that a damaged profile doesn't fail the rest of the meta data
+    pub fn icc_profile(&self) -> Option<MetaResult<IccProfile>> {
+        let profile = match self.container.as_ref()?.icc_profile()? {
+            Ok(data) => IccProfile::parse(&data).map_err(MetaError::from),
+            Err(e) => Err(e),
+        };
+        Some(profile)
+    }
+
+    /// Get the name of the embedded ICC profile if it exists
+    pub fn icc_profile_name(&self) -> Option<&str> {
+        match &self.container {
+            Some(Container::Png(png)) => png.icc_profile_name(),
+            _ => None,
+        }
     }
 
     /// Discover the media type and create a new instance based on that type
-    pub(crate) fn parse<T: io::BufRead + io::Seek>(mut reader: T) -> MetaResult<Self> {
+    pub(crate) fn parse<T: io::BufRead + io::Seek>(reader: T) -> MetaResult<Self> {
+        let meta = Self::parse_container(reader)?;
+
+        // TODO: run this only as needed
+        if let Some(Err(e)) = meta.cache_jfif() {
+            return Err(e);
+        }
+        if let Some(Err(e)) = meta.cache_exif() {
+            return Err(e);
+        }
+        // XMP and IPTC are optional so broken records are dropped leaving the Exif data intact
+        let _ = meta.cache_xmp();
+        let _ = meta.cache_iptc();
+
+        Ok(meta)
+    }
+
+    /// Discover the media type and parse only the container leaving the meta data uncached
+    /// * On demand meta data e.g. the ICC profile is then read regardless of damaged Exif
+    pub(crate) fn parse_container<T: io::BufRead + io::Seek>(mut reader: T) -> MetaResult<Self> {
         // TODO:
-        // * read some larger amount for magic file header testing
         // * try file extension if header is not recognized needed
         // * scan file for JPEG/TIFF markers?
         // * split out container types as separate features?
         let mut header = Vec::new();
-        reader.by_ref().take(2).read_to_end(&mut header)?;
+        reader.by_ref().take(12).read_to_end(&mut header)?;
 
         // Create a new instance based on the media type
         let mut meta = Self::default();
         if Jpeg::is_jpeg(&header) {
             meta.container = Some(Container::Jpeg(Jpeg::parse(header.chain(reader))?));
-
-            // TODO: run this only as needed
-            if let Some(Err(e)) = meta.cache_jfif() {
-                return Err(e);
-            }
-            if let Some(Err(e)) = meta.cache_exif() {
-                return Err(e);
-            }
-
-            Ok(meta)
+        } else if Tiff::is_tiff(&header) {
+            // IFDs are seeked to from the start of the file
+            reader.seek(io::SeekFrom::Current(-(header.len() as i64)))?;
+            meta.container = Some(Container::Tiff(Tiff::parse(reader)?));
+        } else if Png::is_png(&header) {
+            meta.container = Some(Container::Png(Png::parse(header.chain(reader))?));
+        } else if WebP::is_webp(&header) {
+            meta.container = Some(Container::WebP(WebP::parse(header.chain(reader))?));
+        } else if Gif::is_gif(&header) {
+            meta.container = Some(Container::Gif(Gif::parse(header.chain(reader))?));
+        } else if Heif::is_heif(&header) {
+            // Item data is seeked to from the start of the file
+            reader.seek(io::SeekFrom::Current(-(header.len() as i64)))?;
+            meta.container = Some(Container::Heif(Heif::parse(reader)?));
         } else {
-            Err(MetaError::unknown_header(&header))
+            return Err(MetaError::unknown_header(&header));
         }
+
+        Ok(meta)
     }
 
     /// Private default constructor
     fn default() -> Self {
-        Self { container: None, jfif: RefCell::new(None), exif: RefCell::new(None) }
+        Self {
+            container: None,
+            jfif: RefCell::new(None),
+            exif: RefCell::new(None),
+            xmp: RefCell::new(None),
+            iptc: RefCell::new(None),
+        }
     }
 
     /// Is the meta data type from a JPEG container
@@ -105,6 +316,39 @@ impl Meta {
             None
         }
     }
+
+    /// Get the XMP meta data if the container stores a packet and cache it
+    /// * JPEG extended XMP is merged in when its chunks reassemble into a packet matching the GUID
+    ///   otherwise the main packet is kept as is
+    fn cache_xmp(&self) -> Option<MetaResult<()>> {
+        let mut xmp = match Xmp::parse(self.xmp_packet()?) {
+            Ok(xmp) => xmp,
+            Err(e) => return Some(Err(e.into())),
+        };
+        if let (Some(guid), Some(Container::Jpeg(jpeg))) = (xmp.extended_guid(), &self.container) {
+            if let Ok(extension) =
+                xmp::reassemble(guid, jpeg.xmp_extension()).and_then(|x| Xmp::parse(&x))
+            {
+                xmp.merge(extension);
+            }
+        }
+        self.xmp.borrow_mut().replace(xmp);
+        Some(Ok(()))
+    }
+
+    /// Get the IPTC meta data if it exists from the JPEG source and cache it
+    fn cache_iptc(&self) -> Option<MetaResult<()>> {
+        match &self.container {
+            Some(Container::Jpeg(jpeg)) => match jpeg.iptc()? {
+                Ok(iptc) => {
+                    self.iptc.borrow_mut().replace(iptc);
+                    Some(Ok(()))
+                }
+                Err(e) => Some(Err(e.into())),
+            },
+            _ => None,
+        }
+    }
 }
 
 impl Display for Meta {
@@ -122,6 +366,15 @@ impl Display for Meta {
         if let Some(ref exif) = *self.exif.borrow() {
             writeln!(f, "{}", exif)?;
         }
+        if let Some(ref xmp) = *self.xmp.borrow() {
+            writeln!(f, "{}", xmp)?;
+        }
+        if let Some(ref iptc) = *self.iptc.borrow() {
+            writeln!(f, "{}", iptc)?;
+        }
+        if let Some(Ok(icc)) = self.icc_profile() {
+            writeln!(f, "{}", icc)?;
+        }
         Ok(())
     }
 }
@@ -131,24 +384,319 @@ mod tests {
     use core::panic;
 
     use super::*;
-    use crate::container::JPEG_TEST_DATA;
+    use crate::container::{
+        GIF_TEST_DATA, HEIF_TEST_DATA, JPEG_TEST_DATA, PNG_TEST_DATA, TIFF_TEST_DATA,
+        WEBP_TEST_DATA,
+    };
+    use crate::meta::{icc::ICC_V2_TEST_DATA, xmp::XMP_TEST_DATA};
+    use crate::{Rational, ResolutionUnit};
+
+    // JPEG with an XMP APP1 segment ahead of the Exif APP1 segment
+    fn jpeg_with_xmp(packet: &[u8]) -> Vec<u8> {
+        let mut payload = crate::meta::XMP_IDENTIFIER.to_vec();
+        payload.extend(packet);
+        let mut data = vec![0xFF, 0xD8, 0xFF, 0xE1];
+        data.extend((payload.len() as u16 + 2).to_be_bytes());
+        data.extend(payload);
+        data.extend(&JPEG_TEST_DATA[2..]);
+        data
+    }
+
+    // JPEG with a Photoshop APP13 segment holding the IPTC-IIM resource ahead of the Exif segment
+    fn jpeg_with_iptc(iim: &[u8]) -> Vec<u8> {
+        let mut payload = b"Photoshop 3.0\0".to_vec();
+        payload.extend(b"8BIM\x04\x04\x00\x00");
+        payload.extend((iim.len() as u32).to_be_bytes());
+        payload.extend(iim);
+        let mut data = vec![0xFF, 0xD8, 0xFF, 0xED];
+        data.extend((payload.len() as u16 + 2).to_be_bytes());
+        data.extend(payload);
+        data.extend(&JPEG_TEST_DATA[2..]);
+        data
+    }
 
     #[test]
     fn test_meta_get_field() {
         let mut data = io::Cursor::new(&JPEG_TEST_DATA);
         let meta = Meta::parse(&mut data).unwrap();
 
-        if let Some(field) = meta.get_field(Tag::ImageWidth) {
-            if let Field::ImageWidth(width) = field {
-                assert_eq!(width, 10u32);
+        if let Some(field) = meta.get_field(Tag::ExifImageWidth) {
+            if let Field::ExifImageWidth(width) = field {
+                assert_eq!(width, 15u32);
             } else {
-                panic!("Field is not ImageWidth");
+                panic!("Field is not ExifImageWidth");
             }
         } else {
             panic!("Field is None");
         }
+        assert_eq!(
+            meta.get_field(Tag::DateTime),
+            Some(Field::DateTime("2016:05:04 03:02:01".into()))
+        );
+        assert_eq!(
+            meta.get_field(Tag::XResolution),
+            Some(Field::XResolution(Rational::new(72, 1)))
+        );
+        assert_eq!(
+            meta.get_field(Tag::ResolutionUnit),
+            Some(Field::ResolutionUnit(ResolutionUnit::PixelsPerInch))
+        );
+        assert_eq!(meta.get_field(Tag::ThumbnailLength), Some(Field::ThumbnailLength(648)));
+        assert_eq!(meta.get_field(Tag::Make), None);
+    }
+
+    #[test]
+    fn test_meta_get_page_field() {
+        let mut data = io::Cursor::new(&JPEG_TEST_DATA);
+        let meta = Meta::parse(&mut data).unwrap();
+        assert_eq!(meta.page_count(), 2);
+        assert_eq!(meta.get_page_field(0, Tag::ExifImageHeight), Some(Field::ExifImageHeight(7)));
+        assert_eq!(meta.get_page_field(0, Tag::ThumbnailLength), None);
+        assert_eq!(meta.get_page_field(1, Tag::ThumbnailLength), Some(Field::ThumbnailLength(648)));
+    }
+
+    #[test]
+    fn test_meta_parse_header_is_valid_tiff() {
+        let mut data = io::Cursor::new(&TIFF_TEST_DATA);
+        let meta = Meta::parse(&mut data).unwrap();
+        assert!(matches!(meta.container(), Some(Container::Tiff(_))));
+        assert!(!meta.is_jpeg());
+        assert_eq!(meta.page_count(), 2);
+        assert_eq!(meta.get_field(Tag::StripOffsets), Some(Field::StripOffsets(vec![208])));
+        assert_eq!(meta.get_page_field(1, Tag::ImageWidth), Some(Field::ImageWidth(2)));
+        assert_eq!(
+            meta.get_page_field(1, Tag::TileOffsets),
+            Some(Field::TileOffsets(vec![212, 216]))
+        );
+    }
+
+    #[test]
+    fn test_meta_parse_png() {
+        let mut data = io::Cursor::new(&PNG_TEST_DATA);
+        let meta = Meta::parse(&mut data).unwrap();
+        assert_eq!(meta.dimensions(), Some((2, 1)));
+        assert_eq!(meta.icc_profile_name(), Some("ICC Profile"));
+        assert_eq!(meta.text().len(), 3);
+        assert_eq!(meta.text()[1].text, "Compressed comment");
+        assert_eq!(
+            meta.get_field(Tag::Orientation),
+            Some(Field::Orientation(crate::Orientation::Rotate90CW))
+        );
+    }
+
+    #[test]
+    fn test_meta_parse_jpeg_xmp() {
+        let mut data = io::Cursor::new(jpeg_with_xmp(XMP_TEST_DATA));
+        let meta = Meta::parse(&mut data).unwrap();
+        assert_eq!(meta.xmp_packet(), Some(XMP_TEST_DATA));
+        assert_eq!(
+            meta.get_field(Tag::DateTime),
+            Some(Field::DateTime("2016:05:04 03:02:01".into()))
+        );
+
+        let xmp = meta.xmp().unwrap();
+        assert_eq!(xmp.rating(), Some(4));
+        assert_eq!(xmp.keywords(), vec!["sunset", "beach"]);
+        assert_eq!(xmp.title(), Some("Evening & Tide"));
+
+        // No XMP packet in the plain JPEG
+        let meta = Meta::parse(&mut io::Cursor::new(&JPEG_TEST_DATA)).unwrap();
+        assert_eq!(meta.xmp(), None);
+    }
+
+    #[test]
+    fn test_meta_parse_jpeg_broken_xmp() {
+        let packet = &XMP_TEST_DATA[..XMP_TEST_DATA.len() / 2];
+        let meta = Meta::parse(&mut io::Cursor::new(jpeg_with_xmp(packet))).unwrap();
+        assert_eq!(meta.xmp_packet(), Some(packet));
+        assert_eq!(meta.xmp(), None);
+        assert_eq!(
+            meta.get_field(Tag::DateTime),
+            Some(Field::DateTime("2016:05:04 03:02:01".into()))
+        );
+    }
+
+    #[test]
+    fn test_meta_parse_jpeg_extended_xmp() {
+        use md5::{Digest, Md5};
+
+        let extension = br#"<x:xmpmeta xmlns:x="adobe:ns:meta/"><rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"><rdf:Description xmlns:GDepth="http://ns.google.com/photos/1.0/depth/" GDepth:Format="RangeInverse" GDepth:Data="iVBORw0KGgo="/></rdf:RDF></x:xmpmeta>"#;
+        let guid: String = Md5::digest(extension)
+            .iter()
+            .map(|x| format!("{:02X}", x))
+            .collect();
+        let main = format!(
+            r#"<x:xmpmeta xmlns:x="adobe:ns:meta/"><rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"><rdf:Description xmlns:xmpNote="http://ns.adobe.com/xmp/note/" xmpNote:HasExtendedXMP="{}"/></rdf:RDF></x:xmpmeta>"#,
+            guid
+        );
+
+        // APP1 segments with the extension chunks out of order after the main packet
+        let app1 = |identifier: &[u8], payload: &[u8]| {
+            let mut segment = vec![0xFF, 0xE1];
+            segment.extend((identifier.len() as u16 + payload.len() as u16 + 2).to_be_bytes());
+            segment.extend(identifier);
+            segment.extend(payload);
+            segment
+        };
+        let chunk = |offset: usize, data: &[u8]| {
+            let mut chunk = guid.as_bytes().to_vec();
+            chunk.extend((extension.len() as u32).to_be_bytes());
+            chunk.extend((offset as u32).to_be_bytes());
+            chunk.extend(data);
+            app1(crate::meta::XMP_EXTENSION_IDENTIFIER, &chunk)
+        };
+        let mut data = vec![0xFF, 0xD8];
+        data.extend(app1(crate::meta::XMP_IDENTIFIER, main.as_bytes()));
+        data.extend(chunk(100, &extension[100..]));
+        data.extend(chunk(0, &extension[..100]));
+        data.extend(&JPEG_TEST_DATA[2..]);
+
+        let meta = Meta::parse(&mut io::Cursor::new(&data)).unwrap();
+        let xmp = meta.xmp().unwrap();
+        assert_eq!(xmp.get("xmpNote:HasExtendedXMP").and_then(|x| x.as_str()), Some(guid.as_str()));
+        assert_eq!(
+            xmp.get_ns("http://ns.google.com/photos/1.0/depth/", "Format")
+                .and_then(|x| x.as_str()),
+            Some("RangeInverse")
+        );
+        assert!(xmp
+            .namespaces()
+            .contains(&("GDepth".into(), "http://ns.google.com/photos/1.0/depth/".into())));
+
+        // Missing chunk leaves the main packet alone
+        let mut data = vec![0xFF, 0xD8];
+        data.extend(app1(crate::meta::XMP_IDENTIFIER, main.as_bytes()));
+        data.extend(chunk(100, &extension[100..]));
+        data.extend(&JPEG_TEST_DATA[2..]);
+        let meta = Meta::parse(&mut io::Cursor::new(&data)).unwrap();
+        assert_eq!(meta.xmp().map(|x| x.properties().len()), Some(1));
+    }
+
+    #[test]
+    fn test_meta_parse_jpeg_iptc() {
+        let iim = [
+            &b"\x1C\x01\x5A\x00\x03\x1B%G"[..],
+            b"\x1C\x02\x19\x00\x05storm",
+            b"\x1C\x02\x19\x00\x06harbor",
+            b"\x1C\x02\x50\x00\x08Jane Doe",
+            b"\x1C\x02\x5A\x00\x07Z\xC3\xBCrich",
+            b"\x1C\x02\x78\x00\x15Storm over the harbor",
+        ]
+        .concat();
+        let meta = Meta::parse(io::Cursor::new(jpeg_with_iptc(&iim))).unwrap();
+        assert_eq!(
+            meta.get_iptc_field(IptcTag::Caption),
+            Some(IptcField::Caption("Storm over the harbor".into()))
+        );
+        assert_eq!(
+            meta.get_iptc_field(IptcTag::Byline),
+            Some(IptcField::Byline(vec!["Jane Doe".into()]))
+        );
+        assert_eq!(
+            meta.get_iptc_field(IptcTag::Keywords),
+            Some(IptcField::Keywords(vec!["storm".into(), "harbor".into()]))
+        );
+        assert_eq!(meta.get_iptc_field(IptcTag::City), Some(IptcField::City("Zürich".into())));
+        assert!(meta.to_string().contains("Caption-Abstract"));
+
+        let meta = Meta::parse(io::Cursor::new(&JPEG_TEST_DATA)).unwrap();
+        assert_eq!(meta.get_iptc_field(IptcTag::Caption), None);
+    }
+
+    #[test]
+    fn test_meta_parse_jpeg_broken_iptc() {
+        let iim = b"\x1C\x02\x50\x00\x08Jane Doe\x1C\x02\x78\x00\x15Storm";
+        let meta = Meta::parse(io::Cursor::new(jpeg_with_iptc(iim))).unwrap();
+        assert_eq!(meta.get_iptc_field(IptcTag::Byline), None);
+        assert_eq!(
+            meta.get_field(Tag::DateTime),
+            Some(Field::DateTime("2016:05:04 03:02:01".into()))
+        );
+    }
+
+    #[test]
+    fn test_meta_parse_jpeg_icc() {
+        // APP2 segments with the profile chunks out of order
+        let mut data = vec![0xFF, 0xD8];
+        for (i, chunk) in [(2, &ICC_V2_TEST_DATA[100..]), (1, &ICC_V2_TEST_DATA[..100])] {
+            data.extend([0xFF, 0xE2]);
+            data.extend((chunk.len() as u16 + 16).to_be_bytes());
+            data.extend(b"ICC_PROFILE\0");
+            data.extend([i, 2]);
+            data.extend(chunk);
+        }
+        data.extend(&JPEG_TEST_DATA[2..]);
+
+        let meta = Meta::parse(io::Cursor::new(&data)).unwrap();
+        let icc = meta.icc_profile().unwrap().unwrap();
+        assert_eq!(icc.version, (2, 1, 0));
+        assert_eq!(icc.class, crate::IccProfileClass::Display);
+        assert_eq!(icc.color_space, crate::IccColorSpace::Rgb);
+        assert_eq!(icc.description.as_deref(), Some("sRGB IEC61966-2.1"));
+        assert!(meta.to_string().contains("sRGB IEC61966-2.1"));
+
+        // Damaged Exif fails the meta data but not the profile read from the container alone
+        let exif = data.len() - JPEG_TEST_DATA.len() + 30;
+        data[exif..exif + 2].copy_from_slice(b"XX");
+        assert!(Meta::parse(io::Cursor::new(&data)).is_err());
+        let meta = Meta::parse_container(io::Cursor::new(&data)).unwrap();
+        let icc = meta.icc_profile().unwrap().unwrap();
+        assert_eq!(icc.description.as_deref(), Some("sRGB IEC61966-2.1"));
+
+        // Placeholder PNG profile is reported without failing the parse
+        let meta = Meta::parse(io::Cursor::new(&PNG_TEST_DATA)).unwrap();
+        let err = meta.icc_profile().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "Meta icc parse failed");
+        let meta = Meta::parse(io::Cursor::new(&JPEG_TEST_DATA)).unwrap();
+        assert!(meta.icc_profile().is_none());
     }
 
+    #[test]
+    fn test_meta_parse_webp() {
+        let mut data = io::Cursor::new(&WEBP_TEST_DATA);
+        let meta = Meta::parse(&mut data).unwrap();
+        assert_eq!(meta.dimensions(), Some((400, 300)));
+        assert_eq!(meta.xmp_packet().map(|x| x.len()), Some(37));
+        assert_eq!(meta.xmp().map(|x| x.properties().len()), Some(0));
+        assert!(matches!(meta.container(), Some(Container::WebP(webp)) if webp.has_alpha()));
+        assert_eq!(
+            meta.get_field(Tag::Orientation),
+            Some(Field::Orientation(crate::Orientation::Rotate180))
+        );
+    }
+
+    #[test]
+    fn test_meta_parse_gif() {
+        let mut data = io::Cursor::new(&GIF_TEST_DATA);
+        let meta = Meta::parse(&mut data).unwrap();
+        assert_eq!(meta.dimensions(), Some((3, 2)));
+        assert_eq!(meta.frame_count(), Some(2));
+        assert_eq!(meta.duration(), Some(Duration::from_millis(350)));
+        assert_eq!(meta.text()[0].text, "Hello GIF");
+        assert_eq!(meta.xmp_packet().map(|x| x.len()), Some(37));
+        assert_eq!(meta.get_field(Tag::Orientation), None);
+    }
+
+    #[test]
+    fn test_meta_parse_heif() {
+        let mut data = io::Cursor::new(&HEIF_TEST_DATA);
+        let meta = Meta::parse(&mut data).unwrap();
+        assert_eq!(meta.dimensions(), Some((4032, 3024)));
+        assert!(
+            matches!(meta.container(), Some(Container::Heif(heif)) if heif.rotation() == Some(90))
+        );
+        assert_eq!(meta.xmp_packet().map(|x| x.len()), Some(37));
+        assert_eq!(meta.get_field(Tag::Make), Some(Field::Make("Apple".into())));
+    }
+
+    #[test]
+    fn test_meta_dimensions_from_exif() {
+        let mut data = io::Cursor::new(&JPEG_TEST_DATA);
+        assert_eq!(Meta::parse(&mut data).unwrap().dimensions(), Some((15, 7)));
+
+        let mut data = io::Cursor::new(&TIFF_TEST_DATA);
+        assert_eq!(Meta::parse(&mut data).unwrap().dimensions(), Some((2, 2)));
+    }
 
     #[test]
     fn test_meta_parse_header_is_valid_jpeg() {
@@ -157,6 +705,58 @@ mod tests {
         assert_eq!(meta.is_jpeg(), true);
     }
 
+    #[test]
+    fn test_meta_frame_header() {
+        let mut data = io::Cursor::new(&JPEG_TEST_DATA);
+        let meta = Meta::parse(&mut data).unwrap();
+        let frame = meta.frame_header().unwrap();
+        assert_eq!(frame.encoding, crate::container::Encoding::Baseline);
+        assert_eq!((frame.width, frame.height, frame.components.len()), (15, 7, 3));
+
+        let mut data = io::Cursor::new(&PNG_TEST_DATA);
+        assert!(Meta::parse(&mut data).unwrap().frame_header().is_none());
+    }
+
+    #[test]
+    fn test_meta_gps_position() {
+        let mut data = io::Cursor::new(&crate::test_data::GPS_TEST_DATA);
+        let position = Meta::parse(&mut data).unwrap().gps_position().unwrap();
+        assert_eq!((position.latitude, position.altitude), (37.775, Some(-52.5)));
+
+        let mut data = io::Cursor::new(&JPEG_TEST_DATA);
+        assert_eq!(Meta::parse(&mut data).unwrap().gps_position(), None);
+    }
+
+    #[test]
+    fn test_meta_thumbnail() {
+        let mut data = io::Cursor::new(&JPEG_TEST_DATA);
+        let meta = Meta::parse(&mut data).unwrap();
+        assert_eq!(meta.thumbnail().map(|x| x.len()), Some(648));
+
+        let mut data = io::Cursor::new(&PNG_TEST_DATA);
+        assert_eq!(Meta::parse(&mut data).unwrap().thumbnail(), None);
+    }
+
+    #[test]
+    fn test_meta_jfif_thumbnail() {
+        let mut data = io::Cursor::new(&JPEG_TEST_DATA);
+        assert_eq!(Meta::parse(&mut data).unwrap().jfif_thumbnail(), None);
+
+        let mut data = JPEG_TEST_DATA[..2].to_vec();
+        data.extend_from_slice(&[
+            0xFF, 0xE0, 0x00, 0x16, 0x4A, 0x46, 0x49, 0x46, 0x00, 0x01, 0x01,
+        ]);
+        data.extend_from_slice(&[
+            0x01, 0x00, 0x48, 0x00, 0x48, 0x01, 0x02, 0x01, 0x02, 0x03, 0x04,
+        ]);
+        data.extend_from_slice(&[0x05, 0x06]);
+        let meta = Meta::parse(io::Cursor::new(&data)).unwrap();
+        assert_eq!(
+            meta.jfif_thumbnail().and_then(|x| x.to_rgb()),
+            Some(vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06])
+        );
+    }
+
     #[test]
     fn test_meta_parse_not_enough_data() {
         let mut header = io::Cursor::new(&[0xFF]);
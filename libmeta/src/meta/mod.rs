@@ -4,12 +4,22 @@ mod stream;
 // Surface module directly
 pub(crate) mod exif;
 pub(crate) mod file;
+pub(crate) mod icc;
+pub(crate) mod iptc;
 pub(crate) mod jfif;
 pub(crate) mod slice;
+pub(crate) mod xmp;
 
 // Surface types from modules directly in the meta module
-pub(crate) use exif::*;
+pub use exif::*;
 pub(crate) use file::File;
+pub(crate) use icc::*;
+pub use icc::{IccColorSpace, IccProfile, IccProfileClass, IccRenderingIntent};
+pub(crate) use iptc::*;
+pub use iptc::{IptcField, IptcTag};
 pub(crate) use jfif::*;
-pub(crate) use meta::*;
+pub use jfif::{DensityUnit, JfifThumbnail};
+pub use meta::*;
 pub(crate) use stream::*;
+pub(crate) use xmp::*;
+pub use xmp::{Xmp, XmpProperty, XmpValue};
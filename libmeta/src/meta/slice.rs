@@ -1,4 +1,4 @@
-use std::io;
+use std::io::{self, Read};
 
 // Read out 1 byte as a u8 value
 pub(crate) fn read_u8(reader: &mut impl io::Read) -> Result<u8, io::Error> {
@@ -12,11 +12,35 @@ pub(crate) fn read_be_u16(reader: &mut impl io::Read) -> Result<u16, io::Error>
     reader.read_exact(&mut buf).and(Ok(u16::from_be_bytes(buf)))
 }
 
+// Read out 4 bytes in Big Endian as a u32 value
+pub(crate) fn read_be_u32(reader: &mut impl io::Read) -> Result<u32, io::Error> {
+    let mut buf = [0u8; 4];
+    reader.read_exact(&mut buf).and(Ok(u32::from_be_bytes(buf)))
+}
+
+// Read out 4 bytes in Little Endian as a u32 value
+pub(crate) fn read_le_u32(reader: &mut impl io::Read) -> Result<u32, io::Error> {
+    let mut buf = [0u8; 4];
+    reader.read_exact(&mut buf).and(Ok(u32::from_le_bytes(buf)))
+}
+
 // Read out a variable number of bytes
+// * the buffer only grows as data is read so a bogus length can't force a huge allocation
 pub(crate) fn read_bytes(reader: &mut impl io::Read, len: usize) -> Result<Vec<u8>, io::Error> {
-    let mut buf = vec![0; len];
-    reader.read_exact(&mut buf[..])?;
-    Ok(buf)
+    let mut buf = Vec::new();
+    reader.by_ref().take(len as u64).read_to_end(&mut buf)?;
+    match buf.len() == len {
+        true => Ok(buf),
+        false => Err(io::Error::new(io::ErrorKind::UnexpectedEof, "failed to fill whole buffer")),
+    }
+}
+
+// Skip over a variable number of bytes without buffering them
+pub(crate) fn skip_bytes(reader: &mut impl io::Read, len: u64) -> Result<(), io::Error> {
+    match io::copy(&mut reader.by_ref().take(len), &mut io::sink())? == len {
+        true => Ok(()),
+        false => Err(io::Error::new(io::ErrorKind::UnexpectedEof, "failed to skip whole buffer")),
+    }
 }
 
 /// Skip bytes until a marker is found or EOF is reached
@@ -59,12 +83,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_read_be_u32() {
+        let data = [0x00, 0x00, 0x01, 0x02];
+        assert_eq!(read_be_u32(&mut &data[..]).unwrap(), 258);
+
+        let data = [0x02, 0x01, 0x00, 0x00];
+        assert_eq!(read_le_u32(&mut &data[..]).unwrap(), 258);
+
+        let data = [0x00, 0x01];
+        assert_eq!(
+            read_be_u32(&mut &data[..]).unwrap_err().to_string(),
+            "failed to fill whole buffer"
+        );
+    }
+
     #[test]
     fn test_read_len() {
         let data = [0x00, 0x01, 0x02, 0x03];
         assert_eq!(read_bytes(&mut &data[..], 2).unwrap(), vec![0x00, 0x01]);
         assert_eq!(read_bytes(&mut &data[..], 3).unwrap(), vec![0x00, 0x01, 0x02]);
         assert_eq!(read_bytes(&mut &data[..], 4).unwrap(), vec![0x00, 0x01, 0x02, 0x03]);
+        assert_eq!(
+            read_bytes(&mut &data[..], 0x7FFF_FFFF)
+                .unwrap_err()
+                .to_string(),
+            "failed to fill whole buffer"
+        );
+    }
+
+    #[test]
+    fn test_skip_bytes() {
+        let data = [0x00, 0x01, 0x02, 0x03];
+        let mut reader = &data[..];
+        skip_bytes(&mut reader, 3).unwrap();
+        assert_eq!(reader, &[0x03]);
+        assert_eq!(
+            skip_bytes(&mut reader, 0x7FFF_FFFF)
+                .unwrap_err()
+                .to_string(),
+            "failed to skip whole buffer"
+        );
     }
 
     #[test]
@@ -0,0 +1,156 @@
+use md5::{Digest, Md5};
+
+use super::XmpResult;
+use crate::errors::XmpError;
+
+/// Identifier prefixing each extended XMP chunk in a JPEG APP1 segment
+pub(crate) const XMP_EXTENSION_IDENTIFIER: &[u8] = b"http://ns.adobe.com/xmp/extension/\0";
+
+/// Size of the chunk header i.e. 32 byte GUID, 4 byte full length and 4 byte offset
+const HEADER_SIZE: usize = 40;
+
+/// Reassemble the extended XMP packet from the chunks of the given GUID
+/// * **guid** is the `xmpNote:HasExtendedXMP` value i.e. the MD5 of the full packet as 32 hex digits
+/// * **chunks** are the APP1 payloads following the extension identifier in any order
+/// * **Field**   | **Bytes** | **Description**
+/// * *GUID*      | 32        | MD5 of the full extended packet as uppercase hex digits
+/// * *Length*    | 4         | Big Endian length of the full extended packet
+/// * *Offset*    | 4         | Big Endian offset of this chunk in the full extended packet
+/// * *Data*      | n         | Chunk of the extended packet
+/// * Chunks for other GUIDs or too short for the header are skipped and repeated or overlapping
+///   data is only taken once while gaps, overruns and a digest mismatch fail
+pub(crate) fn reassemble<'a>(
+    guid: &str,
+    chunks: impl Iterator<Item = &'a [u8]>,
+) -> XmpResult<Vec<u8>> {
+    let mut length = None;
+    let mut parts = Vec::new();
+    for chunk in chunks {
+        if chunk.len() < HEADER_SIZE || !chunk[..32].eq_ignore_ascii_case(guid.as_bytes()) {
+            continue;
+        }
+        let full = u32::from_be_bytes(chunk[32..36].try_into().unwrap()) as usize;
+        if *length.get_or_insert(full) != full {
+            return Err(XmpError::parse(": extended XMP chunk lengths differ"));
+        }
+        let offset = u32::from_be_bytes(chunk[36..40].try_into().unwrap()) as usize;
+        parts.push((offset, &chunk[HEADER_SIZE..]));
+    }
+    let length = length.ok_or_else(|| XmpError::parse(": extended XMP chunks missing"))?;
+
+    // Stitch the chunks together in offset order, the claimed length isn't trusted for allocation
+    parts.sort_by_key(|(offset, _)| *offset);
+    let mut data = Vec::new();
+    for (offset, part) in parts {
+        if offset > data.len() {
+            return Err(XmpError::parse(": extended XMP chunk missing")
+                .with_data(&(data.len() as u32).to_be_bytes()));
+        }
+        if let Some(rest) = part.get(data.len() - offset..) {
+            data.extend_from_slice(rest);
+        }
+    }
+    if data.len() != length {
+        return Err(XmpError::parse(": extended XMP length mismatch")
+            .with_data(&(data.len() as u32).to_be_bytes()));
+    }
+
+    // GUID is the digest of the full packet
+    let digest: String = Md5::digest(&data)
+        .iter()
+        .map(|x| format!("{:02X}", x))
+        .collect();
+    if !digest.eq_ignore_ascii_case(guid) {
+        return Err(XmpError::parse(": extended XMP digest mismatch").with_data(digest.as_bytes()));
+    }
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PACKET: &[u8] = b"<x:xmpmeta xmlns:x=\"adobe:ns:meta/\"/>";
+    const GUID: &str = "90F998CE06609A9987B069B17ACA1F68";
+    const OTHER: &str = "0123456789ABCDEF0123456789ABCDEF";
+
+    // Packet split into 10 byte chunks behind the GUID, full length (37) and chunk offset
+    const CHUNKS: [&[u8]; 4] = [
+        b"90F998CE06609A9987B069B17ACA1F68\0\0\0\x25\0\0\0\x00<x:xmpmeta",
+        b"90F998CE06609A9987B069B17ACA1F68\0\0\0\x25\0\0\0\x0a xmlns:x=\"",
+        b"90F998CE06609A9987B069B17ACA1F68\0\0\0\x25\0\0\0\x14adobe:ns:m",
+        b"90F998CE06609A9987B069B17ACA1F68\0\0\0\x25\0\0\0\x1eeta/\"/>",
+    ];
+
+    #[test]
+    fn test_reassemble() {
+        // Out of order, repeated and other GUID chunks
+        let other = b"0123456789ABCDEF0123456789ABCDEF\0\0\0\x05\0\0\0\0other";
+        let chunks = [CHUNKS[3], CHUNKS[2], CHUNKS[2], CHUNKS[1], CHUNKS[0], other];
+        let data = reassemble(GUID, chunks.into_iter()).unwrap();
+        assert_eq!(data, PACKET);
+
+        // Undersized chunks are skipped
+        let chunks = [
+            &b"short"[..],
+            CHUNKS[0],
+            CHUNKS[1],
+            &CHUNKS[2][..39],
+            CHUNKS[2],
+            CHUNKS[3],
+        ];
+        let data = reassemble(GUID, chunks.into_iter()).unwrap();
+        assert_eq!(data, PACKET);
+    }
+
+    #[test]
+    fn test_reassemble_overlapping() {
+        // Chunk covering 5..20 only contributes the data beyond the first chunk
+        let overlap = [&CHUNKS[0][..36], b"\0\0\0\x05", &PACKET[5..20]].concat();
+        let chunks = [CHUNKS[0], &overlap, CHUNKS[2], CHUNKS[3]];
+        let data = reassemble(GUID, chunks.into_iter()).unwrap();
+        assert_eq!(data, PACKET);
+
+        // Chunk entirely within the stitched data
+        let inner = [&CHUNKS[0][..36], b"\0\0\0\x02", &PACKET[2..8]].concat();
+        let chunks = [CHUNKS[0], &inner, CHUNKS[1], CHUNKS[2], CHUNKS[3]];
+        let data = reassemble(GUID, chunks.into_iter()).unwrap();
+        assert_eq!(data, PACKET);
+    }
+
+    #[test]
+    fn test_reassemble_invalid() {
+        // Missing the middle or the end
+        let err = reassemble(GUID, [CHUNKS[0], CHUNKS[3]].into_iter()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "XMP parse failed: extended XMP chunk missing [00, 00, 00, 0a]"
+        );
+        let err = reassemble(GUID, CHUNKS[..3].iter().copied()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "XMP parse failed: extended XMP length mismatch [00, 00, 00, 1e]"
+        );
+
+        // Length claimed far beyond the received chunks
+        let huge = b"90F998CE06609A9987B069B17ACA1F68\xFF\xFF\xFF\xFF\0\0\0\x00<x:xmpmeta";
+        let err = reassemble(GUID, [&huge[..]].into_iter()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "XMP parse failed: extended XMP length mismatch [00, 00, 00, 0a]"
+        );
+
+        // Digest doesn't match the GUID
+        let chunks = CHUNKS.map(|x| [OTHER.as_bytes(), &x[32..]].concat());
+        let err = reassemble(OTHER, chunks.iter().map(|x| x.as_slice())).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("XMP parse failed: extended XMP digest mismatch"));
+
+        let err = reassemble(OTHER, [&b"short"[..]].into_iter()).unwrap_err();
+        assert_eq!(err.to_string(), "XMP parse failed: extended XMP chunks missing");
+        let err = reassemble(OTHER, std::iter::empty()).unwrap_err();
+        assert_eq!(err.to_string(), "XMP parse failed: extended XMP chunks missing");
+    }
+}
@@ -0,0 +1,14 @@
+mod extension;
+mod packet;
+mod test_data;
+mod value;
+
+// Surface types from modules directly in the meta module
+pub(crate) use extension::*;
+pub use packet::Xmp;
+pub(crate) use packet::*;
+pub use value::*;
+
+// Expose testing data to other modules
+#[cfg(test)]
+pub(crate) use test_data::XMP_TEST_DATA;
@@ -0,0 +1,418 @@
+use std::fmt::Display;
+
+use quick_xml::{
+    events::{BytesStart, Event},
+    name::{Namespace, PrefixDeclaration, ResolveResult},
+    NsReader,
+};
+
+use super::{XmpProperty, XmpValue};
+use crate::errors::XmpError;
+
+/// Simplify the XMP return type slightly
+pub type XmpResult<T> = Result<T, XmpError>;
+
+/// Identifier prefixing the XMP packet in a JPEG APP1 segment
+pub(crate) const XMP_IDENTIFIER: &[u8] = b"http://ns.adobe.com/xap/1.0/\0";
+
+// Namespaces the RDF structure is built from
+const RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const XML: &str = "http://www.w3.org/XML/1998/namespace";
+
+// Deepest element nesting accepted, real packets rarely go beyond a dozen levels
+const MAX_DEPTH: usize = 100;
+
+// Well known namespaces used for lookups by prefix when the packet doesn't declare them
+pub(crate) const DC: &str = "http://purl.org/dc/elements/1.1/";
+pub(crate) const XMP: &str = "http://ns.adobe.com/xap/1.0/";
+pub(crate) const XMP_NOTE: &str = "http://ns.adobe.com/xmp/note/";
+const PREFIXES: [(&str, &str); 12] = [
+    ("dc", DC),
+    ("xmp", XMP),
+    ("xmpMM", "http://ns.adobe.com/xap/1.0/mm/"),
+    ("xmpNote", XMP_NOTE),
+    ("xmpRights", "http://ns.adobe.com/xap/1.0/rights/"),
+    ("photoshop", "http://ns.adobe.com/photoshop/1.0/"),
+    ("lr", "http://ns.adobe.com/lightroom/1.0/"),
+    ("darktable", "http://darktable.sf.net/"),
+    ("tiff", "http://ns.adobe.com/tiff/1.0/"),
+    ("exif", "http://ns.adobe.com/exif/1.0/"),
+    ("crs", "http://ns.adobe.com/camera-raw-settings/1.0/"),
+    ("Iptc4xmpCore", "http://iptc.org/std/Iptc4xmpCore/1.0/xmlns/"),
+];
+
+/// XMP meta data parsed from the RDF/XML packet
+/// * **namespaces** are the (prefix, URI) pairs declared in the packet
+/// * **properties** are the top level properties of every `rdf:Description` in document order
+#[derive(Debug, Clone, PartialEq)]
+pub struct Xmp {
+    pub(crate) namespaces: Vec<(String, String)>,
+    pub(crate) properties: Vec<XmpProperty>,
+}
+
+impl Xmp {
+    /// Parse the given RDF/XML packet into the XMP property model
+    /// * The `<?xpacket` wrapper and `x:xmpmeta` element are optional
+    /// * Unknown RDF constructs are read as leniently as possible rather than failing
+    pub(crate) fn parse(input: &[u8]) -> XmpResult<Xmp> {
+        let mut namespaces = Vec::new();
+        let root = parse_element_tree(input, &mut namespaces)?;
+
+        // An empty packet e.g. a bare `x:xmpmeta` has no properties
+        let properties = root
+            .find(RDF, "RDF")
+            .map(|rdf| {
+                rdf.children
+                    .iter()
+                    .filter(|x| x.is(RDF, "Description"))
+                    .flat_map(parse_properties)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(Self { namespaces, properties })
+    }
+
+    /// Get the `xmpNote:HasExtendedXMP` GUID of the extended packet if the packet was split
+    pub(crate) fn extended_guid(&self) -> Option<&str> {
+        self.get_ns(XMP_NOTE, "HasExtendedXMP")?.as_str()
+    }
+
+    /// Merge the properties and namespaces of the extended packet into this one
+    pub(crate) fn merge(&mut self, extension: Xmp) {
+        for namespace in extension.namespaces {
+            if !self.namespaces.contains(&namespace) {
+                self.namespaces.push(namespace);
+            }
+        }
+        self.properties.extend(extension.properties);
+    }
+
+    /// Get the (prefix, URI) namespace pairs declared in the packet
+    pub fn namespaces(&self) -> &[(String, String)] {
+        &self.namespaces
+    }
+
+    /// Get the top level properties in document order
+    pub fn properties(&self) -> &[XmpProperty] {
+        &self.properties
+    }
+
+    /// Get the property value by its namespace URI and local name
+    pub fn get_ns(&self, namespace: &str, name: &str) -> Option<&XmpValue> {
+        self.properties
+            .iter()
+            .find(|x| x.namespace == namespace && x.name == name)
+            .map(|x| &x.value)
+    }
+
+    /// Get the property value by its qualified name e.g. `dc:title` or `xmp:Rating`
+    /// * Prefixes declared in the packet take precedence over the well known prefixes
+    pub fn get(&self, qname: &str) -> Option<&XmpValue> {
+        let (prefix, name) = qname.split_once(':')?;
+        let namespace = self
+            .namespaces
+            .iter()
+            .find(|(x, _)| x == prefix)
+            .map(|(_, x)| x.as_str())
+            .or_else(|| PREFIXES.iter().find(|(x, _)| *x == prefix).map(|(_, x)| *x))?;
+        self.get_ns(namespace, name)
+    }
+
+    /// Get the `xmp:Rating` where -1 marks a rejected image and 0 to 5 are stars
+    /// * Fractional ratings written by some tools are rounded
+    pub fn rating(&self) -> Option<i32> {
+        let value = self.get_ns(XMP, "Rating")?.as_str()?.trim();
+        value
+            .parse::<i32>()
+            .ok()
+            .or_else(|| value.parse::<f64>().ok().map(|x| x.round() as i32))
+    }
+
+    /// Get the `dc:subject` keywords
+    pub fn keywords(&self) -> Vec<&str> {
+        self.get_ns(DC, "subject")
+            .map(|x| x.items().into_iter().filter_map(|x| x.as_str()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Get the default language `dc:title`
+    pub fn title(&self) -> Option<&str> {
+        self.get_ns(DC, "title").and_then(|x| x.as_str())
+    }
+}
+
+impl Display for Xmp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for property in &self.properties {
+            let prefix = self
+                .namespaces
+                .iter()
+                .find(|(_, x)| *x == property.namespace)
+                .map(|(x, _)| x.as_str())
+                .unwrap_or_default();
+            let name = format!("{}:{}", prefix, property.name);
+            writeln!(f, "  {: <32}: {}", name, property.value)?;
+        }
+        Ok(())
+    }
+}
+
+// Namespace resolved XML element the RDF structure is interpreted from
+#[derive(Debug, Default)]
+struct Element {
+    namespace: String,
+    name: String,
+    attributes: Vec<(String, String, String)>, // (namespace, name, value)
+    children: Vec<Element>,
+    text: String,
+}
+
+impl Element {
+    // Is the element the given namespace and local name
+    fn is(&self, namespace: &str, name: &str) -> bool {
+        self.namespace == namespace && self.name == name
+    }
+
+    // Get the attribute value with the given namespace and local name
+    fn attribute(&self, namespace: &str, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(ns, x, _)| ns == namespace && x == name)
+            .map(|(_, _, x)| x.as_str())
+    }
+
+    // Depth first search for the given element
+    fn find(&self, namespace: &str, name: &str) -> Option<&Element> {
+        match self.is(namespace, name) {
+            true => Some(self),
+            false => self.children.iter().find_map(|x| x.find(namespace, name)),
+        }
+    }
+}
+
+/// Parse the XML into a tree of namespace resolved elements under an unnamed root
+/// * **namespaces** collects the (prefix, URI) declarations in document order
+/// * Nesting is limited to `MAX_DEPTH` so that walking the tree can't overflow the stack
+fn parse_element_tree(input: &[u8], namespaces: &mut Vec<(String, String)>) -> XmpResult<Element> {
+    let mut reader = NsReader::from_reader(input);
+    reader.config_mut().expand_empty_elements = true;
+
+    let mut stack = vec![Element::default()];
+    loop {
+        let (ns, event) = reader
+            .read_resolved_event()
+            .map_err(|e| XmpError::parse(": XML").with_xml_source(e))?;
+        match event {
+            Event::Start(start) => {
+                if stack.len() > MAX_DEPTH {
+                    return Err(XmpError::parse(": XML nesting too deep"));
+                }
+                let namespace = to_namespace(ns);
+                let element = parse_element(&reader, namespace, &start, namespaces)?;
+                stack.push(element);
+            }
+            Event::End(_) => {
+                let element = stack.pop().unwrap();
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(element),
+                    None => return Err(XmpError::parse(": unbalanced XML")),
+                }
+            }
+            Event::Text(text) => {
+                let text = text
+                    .unescape()
+                    .map_err(|e| XmpError::parse(": XML text").with_xml_source(e))?;
+                stack.last_mut().unwrap().text.push_str(&text);
+            }
+            Event::CData(data) => {
+                let data = data
+                    .decode()
+                    .map_err(|e| XmpError::parse(": XML CDATA").with_xml_source(e.into()))?;
+                stack.last_mut().unwrap().text.push_str(&data);
+            }
+            Event::Eof => break,
+            _ => (),
+        }
+    }
+
+    match stack.len() {
+        1 => Ok(stack.pop().unwrap()),
+        _ => Err(XmpError::parse(": unclosed XML element")),
+    }
+}
+
+/// Create the element from its start tag resolving attribute namespaces
+fn parse_element(
+    reader: &NsReader<&[u8]>,
+    namespace: String,
+    start: &BytesStart,
+    namespaces: &mut Vec<(String, String)>,
+) -> XmpResult<Element> {
+    let mut element = Element {
+        namespace,
+        name: String::from_utf8_lossy(start.local_name().as_ref()).into_owned(),
+        ..Default::default()
+    };
+
+    for attr in start.attributes() {
+        let attr =
+            attr.map_err(|e| XmpError::parse(": XML attribute").with_xml_source(e.into()))?;
+        let value = attr
+            .unescape_value()
+            .map_err(|e| XmpError::parse(": XML attribute value").with_xml_source(e))?
+            .into_owned();
+
+        // Namespace declarations are recorded rather than kept as attributes
+        if let Some(binding) = attr.key.as_namespace_binding() {
+            if let PrefixDeclaration::Named(prefix) = binding {
+                namespaces.push((String::from_utf8_lossy(prefix).into_owned(), value));
+            }
+            continue;
+        }
+
+        let (ns, name) = reader.resolve_attribute(attr.key);
+        let namespace = match attr.key.prefix() {
+            Some(x) if x.as_ref() == b"xml" => XML.to_string(),
+            _ => to_namespace(ns),
+        };
+        let name = String::from_utf8_lossy(name.as_ref()).into_owned();
+        element.attributes.push((namespace, name, value));
+    }
+
+    Ok(element)
+}
+
+// Unbound or unknown namespaces are kept as empty so they never match a known namespace
+fn to_namespace(ns: ResolveResult) -> String {
+    match ns {
+        ResolveResult::Bound(Namespace(x)) => String::from_utf8_lossy(x).into_owned(),
+        _ => String::new(),
+    }
+}
+
+/// Parse the properties of a `rdf:Description` or `rdf:parseType="Resource"` element
+/// * Non RDF attributes are shorthand for simple properties
+fn parse_properties(element: &Element) -> Vec<XmpProperty> {
+    let attributes = element
+        .attributes
+        .iter()
+        .filter(|(ns, _, _)| !ns.is_empty() && ns != RDF && ns != XML)
+        .map(|(ns, name, value)| XmpProperty::new(ns, name, XmpValue::Simple(value.clone())));
+    let children = element
+        .children
+        .iter()
+        .map(|x| XmpProperty::new(&x.namespace, &x.name, parse_value(x)));
+    attributes.chain(children).collect()
+}
+
+/// Parse the value of a property element
+fn parse_value(element: &Element) -> XmpValue {
+    if let Some(resource) = element.attribute(RDF, "resource") {
+        return XmpValue::Simple(resource.to_string());
+    }
+    if element.attribute(RDF, "parseType") == Some("Resource") {
+        return XmpValue::Struct(parse_properties(element));
+    }
+
+    match element.children.iter().find(|x| x.namespace == RDF) {
+        Some(x) if x.name == "Bag" => XmpValue::Bag(parse_items(x).map(|(_, x)| x).collect()),
+        Some(x) if x.name == "Seq" => XmpValue::Seq(parse_items(x).map(|(_, x)| x).collect()),
+        Some(x) if x.name == "Alt" => XmpValue::Alt(parse_items(x).collect()),
+        Some(x) if x.name == "Description" => XmpValue::Struct(parse_properties(x)),
+
+        // Qualified values keep the rdf:value dropping the qualifiers
+        Some(x) if x.name == "value" => parse_value(x),
+        _ => {
+            let properties = parse_properties(element);
+            match properties.is_empty() {
+                true => XmpValue::Simple(element.text.clone()),
+                false => XmpValue::Struct(properties),
+            }
+        }
+    }
+}
+
+/// Parse the `rdf:li` items of an array with their `xml:lang` qualifier
+fn parse_items(array: &Element) -> impl Iterator<Item = (Option<String>, XmpValue)> + '_ {
+    array
+        .children
+        .iter()
+        .filter(|x| x.is(RDF, "li"))
+        .map(|x| (x.attribute(XML, "lang").map(|x| x.to_string()), parse_value(x)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meta::xmp::test_data::XMP_TEST_DATA;
+
+    #[test]
+    fn test_parse() {
+        let xmp = Xmp::parse(XMP_TEST_DATA).unwrap();
+        assert!(xmp.namespaces().contains(&("dc".into(), DC.into())));
+        assert_eq!(xmp.rating(), Some(4));
+        assert_eq!(xmp.keywords(), vec!["sunset", "beach"]);
+        assert_eq!(xmp.title(), Some("Evening & Tide"));
+        assert_eq!(
+            xmp.get("dc:title")
+                .and_then(|x| x.get_lang("de-DE"))
+                .and_then(|x| x.as_str()),
+            Some("Abend")
+        );
+
+        // Shorthand attribute properties
+        assert_eq!(xmp.get("xmp:CreatorTool"), Some(&XmpValue::Simple("darktable 4.6.1".into())));
+        assert_eq!(xmp.get("darktable:xmp_version").and_then(|x| x.as_str()), Some("5"));
+
+        // Seq, struct and resource values
+        assert_eq!(
+            xmp.get("dc:creator"),
+            Some(&XmpValue::Seq(vec![XmpValue::Simple("Jane Doe".into())]))
+        );
+        let dimensions = xmp.get("xmpTPg:MaxPageSize").unwrap();
+        let stdim = "http://ns.adobe.com/xap/1.0/sType/Dimensions#";
+        assert_eq!(dimensions.get_field(stdim, "w").and_then(|x| x.as_str()), Some("640"));
+        assert_eq!(dimensions.get_field(stdim, "unit").and_then(|x| x.as_str()), Some("pixel"));
+        assert_eq!(
+            xmp.get("xmpRights:WebStatement").and_then(|x| x.as_str()),
+            Some("https://example.com")
+        );
+
+        // Lightroom hierarchical keywords
+        let keywords = xmp.get("lr:hierarchicalSubject").unwrap();
+        assert_eq!(keywords.items().len(), 2);
+        assert_eq!(keywords.to_string(), "places|beach, nature|sunset");
+    }
+
+    #[test]
+    fn test_parse_minimal() {
+        let xmp = Xmp::parse(br#"<x:xmpmeta xmlns:x="adobe:ns:meta/"/>"#).unwrap();
+        assert_eq!(xmp.namespaces(), &[("x".to_string(), "adobe:ns:meta/".to_string())]);
+        assert!(xmp.properties().is_empty());
+
+        let xmp = Xmp::parse(
+            br#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"><rdf:Description/></rdf:RDF>"#,
+        )
+        .unwrap();
+        assert!(xmp.properties().is_empty());
+        assert_eq!(xmp.rating(), None);
+        assert!(xmp.keywords().is_empty());
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        let err = Xmp::parse(b"<x:xmpmeta><rdf:RDF></x:xmpmeta>").unwrap_err();
+        assert!(err.to_string().starts_with("XMP parse failed: XML"));
+        let err = Xmp::parse(b"<a><b>").unwrap_err();
+        assert_eq!(err.to_string(), "XMP parse failed: unclosed XML element");
+    }
+
+    #[test]
+    fn test_parse_nesting_too_deep() {
+        let nested = |depth: usize| ["<a>".repeat(depth), "</a>".repeat(depth)].concat();
+        assert!(Xmp::parse(nested(MAX_DEPTH).as_bytes()).is_ok());
+        let err = Xmp::parse(nested(MAX_DEPTH + 1).as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "XMP parse failed: XML nesting too deep");
+    }
+}
@@ -0,0 +1,49 @@
+/// Lightroom/darktable style XMP packet covering simple, shorthand, struct and array properties
+#[cfg(test)]
+pub(crate) const XMP_TEST_DATA: &[u8] = br#"<?xpacket begin="" id="W5M0MpCehiHzreSzNTczkc9d"?>
+<x:xmpmeta xmlns:x="adobe:ns:meta/" x:xmptk="XMP Core 4.4.0-Exiv2">
+ <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+  <rdf:Description rdf:about=""
+    xmlns:xmp="http://ns.adobe.com/xap/1.0/"
+    xmlns:dc="http://purl.org/dc/elements/1.1/"
+    xmlns:lr="http://ns.adobe.com/lightroom/1.0/"
+    xmlns:darktable="http://darktable.sf.net/"
+    xmlns:xmpRights="http://ns.adobe.com/xap/1.0/rights/"
+    xmlns:xmpTPg="http://ns.adobe.com/xap/1.0/t/pg/"
+    xmlns:stDim="http://ns.adobe.com/xap/1.0/sType/Dimensions#"
+    xmp:CreatorTool="darktable 4.6.1"
+    darktable:xmp_version="5">
+   <xmp:Rating>4</xmp:Rating>
+   <dc:title>
+    <rdf:Alt>
+     <rdf:li xml:lang="x-default">Evening &amp; Tide</rdf:li>
+     <rdf:li xml:lang="de-DE">Abend</rdf:li>
+    </rdf:Alt>
+   </dc:title>
+   <dc:creator>
+    <rdf:Seq>
+     <rdf:li>Jane Doe</rdf:li>
+    </rdf:Seq>
+   </dc:creator>
+   <dc:subject>
+    <rdf:Bag>
+     <rdf:li>sunset</rdf:li>
+     <rdf:li>beach</rdf:li>
+    </rdf:Bag>
+   </dc:subject>
+   <lr:hierarchicalSubject>
+    <rdf:Bag>
+     <rdf:li>places|beach</rdf:li>
+     <rdf:li>nature|sunset</rdf:li>
+    </rdf:Bag>
+   </lr:hierarchicalSubject>
+   <xmpTPg:MaxPageSize rdf:parseType="Resource">
+    <stDim:w>640</stDim:w>
+    <stDim:h>480</stDim:h>
+    <stDim:unit>pixel</stDim:unit>
+   </xmpTPg:MaxPageSize>
+   <xmpRights:WebStatement rdf:resource="https://example.com"/>
+  </rdf:Description>
+ </rdf:RDF>
+</x:xmpmeta>
+<?xpacket end="w"?>"#;
@@ -0,0 +1,143 @@
+use std::fmt::Display;
+
+/// Default language of an `rdf:Alt` language alternative
+pub(crate) const X_DEFAULT: &str = "x-default";
+
+/// Named XMP property
+/// * **namespace** is the namespace URI e.g. `http://purl.org/dc/elements/1.1/`
+/// * **name** is the local name e.g. `title`
+#[derive(Debug, Clone, PartialEq)]
+pub struct XmpProperty {
+    pub namespace: String,
+    pub name: String,
+    pub value: XmpValue,
+}
+
+impl XmpProperty {
+    pub(crate) fn new<T: Into<String>, U: Into<String>>(
+        namespace: T,
+        name: U,
+        value: XmpValue,
+    ) -> Self {
+        Self { namespace: namespace.into(), name: name.into(), value }
+    }
+}
+
+/// Value of an XMP property
+/// * Simple values are text, structs are a set of named fields and arrays hold ordered `Seq`,
+///   unordered `Bag` or language alternative `Alt` items
+/// * `Alt` items carry their `xml:lang` qualifier if given
+#[derive(Debug, Clone, PartialEq)]
+pub enum XmpValue {
+    Simple(String),
+    Struct(Vec<XmpProperty>),
+    Bag(Vec<XmpValue>),
+    Seq(Vec<XmpValue>),
+    Alt(Vec<(Option<String>, XmpValue)>),
+}
+
+impl XmpValue {
+    /// Get the text of a simple value or the default item of a language alternative
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            XmpValue::Simple(x) => Some(x),
+            XmpValue::Alt(_) => self.get_lang(X_DEFAULT).and_then(|x| x.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Get the array items ignoring any language qualifiers
+    /// * Simple values and structs are treated as a single item
+    pub fn items(&self) -> Vec<&XmpValue> {
+        match self {
+            XmpValue::Bag(x) | XmpValue::Seq(x) => x.iter().collect(),
+            XmpValue::Alt(x) => x.iter().map(|(_, x)| x).collect(),
+            x => vec![x],
+        }
+    }
+
+    /// Get the language alternative item for the given language e.g. `en-US`
+    /// * Languages are matched case insensitively falling back on `x-default` then the first item
+    pub fn get_lang(&self, lang: &str) -> Option<&XmpValue> {
+        let XmpValue::Alt(items) = self else {
+            return None;
+        };
+        let find = |lang: &str| {
+            items
+                .iter()
+                .find(|(x, _)| x.as_deref().is_some_and(|x| x.eq_ignore_ascii_case(lang)))
+                .map(|(_, x)| x)
+        };
+        find(lang)
+            .or_else(|| find(X_DEFAULT))
+            .or_else(|| items.first().map(|(_, x)| x))
+    }
+
+    /// Get the struct field with the given namespace URI and local name
+    pub fn get_field(&self, namespace: &str, name: &str) -> Option<&XmpValue> {
+        match self {
+            XmpValue::Struct(fields) => fields
+                .iter()
+                .find(|x| x.namespace == namespace && x.name == name)
+                .map(|x| &x.value),
+            _ => None,
+        }
+    }
+}
+
+impl Display for XmpValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            XmpValue::Simple(x) => write!(f, "{}", x),
+            XmpValue::Struct(fields) => {
+                let fields: Vec<String> = fields
+                    .iter()
+                    .map(|x| format!("{}={}", x.name, x.value))
+                    .collect();
+                write!(f, "{{{}}}", fields.join(", "))
+            }
+            XmpValue::Alt(_) => match self.as_str() {
+                Some(x) => write!(f, "{}", x),
+                None => Ok(()),
+            },
+            XmpValue::Bag(_) | XmpValue::Seq(_) => {
+                let items: Vec<String> = self.items().iter().map(|x| x.to_string()).collect();
+                write!(f, "{}", items.join(", "))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alt() {
+        let value = XmpValue::Alt(vec![
+            (Some("x-default".into()), XmpValue::Simple("Sunset".into())),
+            (Some("de-DE".into()), XmpValue::Simple("Sonnenuntergang".into())),
+        ]);
+        assert_eq!(value.as_str(), Some("Sunset"));
+        assert_eq!(value.get_lang("de-de").and_then(|x| x.as_str()), Some("Sonnenuntergang"));
+        assert_eq!(value.get_lang("fr-FR").and_then(|x| x.as_str()), Some("Sunset"));
+        assert_eq!(value.items().len(), 2);
+        assert_eq!(value.to_string(), "Sunset");
+    }
+
+    #[test]
+    fn test_display() {
+        let value = XmpValue::Bag(vec![XmpValue::Simple("a".into()), XmpValue::Simple("b".into())]);
+        assert_eq!(value.to_string(), "a, b");
+        let value = XmpValue::Struct(vec![XmpProperty::new(
+            "http://ns.adobe.com/xap/1.0/sType/Dimensions#",
+            "w",
+            XmpValue::Simple("640".into()),
+        )]);
+        assert_eq!(value.to_string(), "{w=640}");
+        assert_eq!(
+            value.get_field("http://ns.adobe.com/xap/1.0/sType/Dimensions#", "w"),
+            Some(&XmpValue::Simple("640".into()))
+        );
+    }
+}
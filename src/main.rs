@@ -1,3 +1,9 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, BufReader, Write},
+    path::{Path, PathBuf},
+};
+
 use clap::{Parser, Subcommand};
 
 #[derive(Parser, Debug)]
@@ -14,9 +20,6 @@ struct Args {
 
 #[derive(Subcommand, Debug, Clone)]
 enum Commands {
-    #[command(about = "Run arbitrary command")]
-    Test,
-
     #[command(about = "Target to get", arg_required_else_help = true)]
     Get { target: String },
 
@@ -28,6 +31,32 @@ enum Commands {
         #[arg(help = "Value to set")]
         value: String,
     },
+
+    #[command(
+        about = "Extract embedded Exif thumbnails",
+        arg_required_else_help = true
+    )]
+    Thumbnail {
+        #[arg(help = "Image files to extract thumbnails from", required = true)]
+        files: Vec<PathBuf>,
+
+        #[arg(
+            short,
+            long,
+            default_value = ".",
+            help = "Directory to write thumbnails to"
+        )]
+        output: PathBuf,
+    },
+
+    #[command(
+        about = "Report embedded ICC color profiles",
+        arg_required_else_help = true
+    )]
+    Profile {
+        #[arg(help = "Image files to report color profiles for", required = true)]
+        files: Vec<PathBuf>,
+    },
 }
 
 fn get_target(target: String) {
@@ -38,8 +67,59 @@ fn set_something(key: String, value: String) {
     println!("Setting key: {}, value: {}", key, value,);
 }
 
-fn test() {
-    exif();
+fn extract_thumbnails(files: Vec<PathBuf>, output: PathBuf) {
+    for file in files {
+        match extract_thumbnail(&file, &output) {
+            Ok(Some(path)) => println!("Wrote thumbnail: {}", path.display()),
+            Ok(None) => println!("No thumbnail found: {}", file.display()),
+            Err(e) => eprintln!("Failed to extract thumbnail: {}: {}", file.display(), e),
+        }
+    }
+}
+
+// Write the thumbnail as `<output>/<file stem>.thumb.jpg` if one exists
+// * existing thumbnails are never overwritten whether from an earlier run or another input file
+//   sharing the same stem
+fn extract_thumbnail(
+    file: &Path,
+    output: &Path,
+) -> Result<Option<PathBuf>, Box<dyn std::error::Error>> {
+    let meta = libmeta::parse(BufReader::new(File::open(file)?))?;
+    let Some(thumbnail) = meta.thumbnail() else {
+        return Ok(None);
+    };
+    let stem = file.file_stem().unwrap_or_default().to_string_lossy();
+    let path = output.join(format!("{}.thumb.jpg", stem));
+    let mut out = match OpenOptions::new().write(true).create_new(true).open(&path) {
+        Ok(out) => out,
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+            return Err(format!("{} already exists", path.display()).into());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    out.write_all(&thumbnail)?;
+    Ok(Some(path))
+}
+
+fn report_profiles(files: Vec<PathBuf>) {
+    for file in files {
+        match report_profile(&file) {
+            Ok(Some(name)) => println!("{}: {}", file.display(), name),
+            Ok(None) => println!("{}: missing ICC profile", file.display()),
+            Err(e) => eprintln!("Failed to read ICC profile: {}: {}", file.display(), e),
+        }
+    }
+}
+
+// Describe the ICC profile e.g. `Display P3` falling back on its color space if unnamed
+// * only the profile is decoded so damaged Exif, XMP or IPTC data doesn't hide it
+fn report_profile(file: &Path) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let Some(profile) = libmeta::parse_icc_profile(BufReader::new(File::open(file)?))? else {
+        return Ok(None);
+    };
+    Ok(Some(profile.description.unwrap_or_else(|| {
+        format!("unnamed {} profile", profile.color_space)
+    })))
 }
 
 fn main() {
@@ -48,6 +128,7 @@ fn main() {
     match args.cmd {
         Commands::Get { target } => get_target(target),
         Commands::Set { key, value } => set_something(key, value),
-        Commands::Test => test(),
+        Commands::Thumbnail { files, output } => extract_thumbnails(files, output),
+        Commands::Profile { files } => report_profiles(files),
     }
 }